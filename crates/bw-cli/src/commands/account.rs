@@ -0,0 +1,90 @@
+use crate::AppContext;
+use crate::GlobalArgs;
+use crate::output::Response;
+use bw_core::services::storage::AccountManager;
+use clap::{Args, Subcommand};
+use serde::Serialize;
+
+#[derive(Subcommand)]
+pub enum AccountCommands {
+    /// List the accounts that have logged in on this device
+    List,
+    /// Make another logged in account the active one
+    Switch(AccountSwitchCommand),
+}
+
+#[derive(Args)]
+pub struct AccountSwitchCommand {
+    /// Email or user ID of the account
+    #[arg(value_name = "ACCOUNT")]
+    pub account: String,
+}
+
+/// Account as listed by `bw account list`
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AccountListItem {
+    id: String,
+    email: String,
+    name: Option<String>,
+    /// Whether the account has tokens, i.e. hasn't logged out
+    logged_in: bool,
+    /// Whether this is the active account
+    active: bool,
+}
+
+pub async fn execute_account(
+    cmd: AccountCommands,
+    _global_args: &GlobalArgs,
+    ctx: &AppContext,
+) -> anyhow::Result<Response> {
+    let account_manager = AccountManager::new(ctx.storage());
+
+    match cmd {
+        AccountCommands::List => {
+            // The stored active account, not one selected with --account
+            let active_user_id = ctx.storage().lock().await.stored_active_user_id()?;
+
+            let mut items = Vec::new();
+            for (user_id, account) in account_manager.get_all_accounts().await? {
+                items.push(AccountListItem {
+                    logged_in: account_manager.is_account_logged_in(&user_id).await?,
+                    active: active_user_id.as_deref() == Some(user_id.as_str()),
+                    id: user_id,
+                    email: account.email,
+                    name: account.name,
+                });
+            }
+            items.sort_by(|a, b| a.email.cmp(&b.email));
+
+            Ok(Response::success(items))
+        }
+
+        AccountCommands::Switch(switch_cmd) => {
+            // 1. Resolve the account by email or user ID
+            let Some(user_id) = account_manager.find_account(&switch_cmd.account).await? else {
+                return Ok(Response::error(format!(
+                    "Account '{}' not found. Run 'bw account list' to see your accounts.",
+                    switch_cmd.account
+                )));
+            };
+            let email = account_manager
+                .get_account(&user_id)
+                .await?
+                .map(|account| account.email)
+                .unwrap_or_else(|| user_id.clone());
+
+            // 2. Only accounts with tokens can become active
+            if !account_manager.is_account_logged_in(&user_id).await? {
+                return Ok(Response::error(format!(
+                    "{} is logged out. Run 'bw login' to log in again.",
+                    email
+                )));
+            }
+
+            // 3. Switch
+            account_manager.set_active_user_id(&user_id).await?;
+            Ok(Response::success_message(format!("Switched to {}.", email)))
+        }
+    }
+}
@@ -1,10 +1,20 @@
 use crate::AppContext;
 use crate::GlobalArgs;
-use crate::commands::auth::{LoginApiKeyCommand, LoginPasswordCommand, input, prompts};
+use crate::commands::auth::{
+    LoginApiKeyCommand, LoginPasswordCommand, LoginSsoCommand, input, prompts,
+};
 use crate::output::Response;
 use anyhow::Result;
 use bw_core::models::auth::TwoFactorMethod;
-use bw_core::services::auth::{AuthError, AuthService};
+use bw_core::services::api::ApiClient;
+use bw_core::services::auth::{
+    AuthError, AuthService, PkceParams, SsoCallbackListener, TwoFactorData, build_authorize_url,
+};
+use secrecy::Secret;
+use std::time::Duration;
+
+/// How long to wait for the browser to complete SSO
+const SSO_CALLBACK_TIMEOUT: Duration = Duration::from_secs(300);
 
 /// Execute password-based login
 pub async fn execute_password_login(
@@ -20,65 +30,224 @@ pub async fn execute_password_login(
     let password = input::require_password(cmd.password, global_args, None)?;
 
     // Build 2FA data if provided
-    let two_factor = cmd
-        .code
-        .as_ref()
-        .map(|code| bw_core::services::auth::TwoFactorData {
-            token: code.clone(),
-            provider: cmd.method.unwrap_or(TwoFactorMethod::Authenticator as u8),
-            remember: false,
-        });
-
-    // Execute login (first attempt without device verification OTP)
-    let result = auth_service
-        .login_with_password(&email, password.clone(), two_factor.clone(), None)
-        .await;
-
-    // Handle the result
-    match result {
-        Ok(login_result) => {
-            // Format output with session key
-            Ok(Response::success(format!(
-                "You are logged in!\n\n\
-                 To unlock your vault, set your session key to the BW_SESSION environment variable. ex:\n\
-                 $ export BW_SESSION=\"{}\"\n\
-                 > $env:BW_SESSION=\"{}\"\n\n\
-                 You can also pass the session key to any command with the --session option. ex:\n\
-                 $ bw list items --session {}",
-                login_result.session_key, login_result.session_key, login_result.session_key
-            )))
-        }
-        Err(AuthError::NewDeviceVerificationRequired) => {
-            // New device verification required - prompt for OTP
-            if global_args.nointeraction {
-                anyhow::bail!(
-                    "New device verification required. Check your email for the verification code \
-                     and provide it via the --code option, or disable --nointeraction to be prompted."
+    let mut two_factor = cmd.code.as_ref().map(|code| TwoFactorData {
+        token: code.clone(),
+        provider: cmd.method.unwrap_or(TwoFactorMethod::Authenticator as u8),
+        remember: cmd.remember,
+    });
+    let mut new_device_otp = None;
+
+    // Each challenge (2FA, new device verification) is answered at most once
+    loop {
+        let result = auth_service
+            .login_with_password(
+                &email,
+                password.clone(),
+                two_factor.clone(),
+                new_device_otp.clone(),
+            )
+            .await;
+
+        match result {
+            Ok(login_result) => return Ok(login_success_response(&login_result.session_key)),
+            Err(AuthError::TwoFactorRequired { providers }) if two_factor.is_none() => {
+                two_factor = Some(
+                    gather_two_factor(
+                        &auth_service,
+                        Some((&email, &password)),
+                        &providers,
+                        cmd.method,
+                        global_args,
+                    )
+                    .await?,
                 );
             }
+            Err(AuthError::NewDeviceVerificationRequired) if new_device_otp.is_none() => {
+                // New device verification required - prompt for OTP
+                if global_args.nointeraction {
+                    anyhow::bail!(
+                        "New device verification required. Check your email for the verification code \
+                         and provide it via the --code option, or disable --nointeraction to be prompted."
+                    );
+                }
 
-            // Prompt for OTP
-            let otp = prompts::prompt_device_verification_otp()?;
-
-            // Retry login with OTP
-            let retry_result = auth_service
-                .login_with_password(&email, password, two_factor, Some(otp))
-                .await?;
-
-            Ok(Response::success(format!(
-                "You are logged in!\n\n\
-                 To unlock your vault, set your session key to the BW_SESSION environment variable. ex:\n\
-                 $ export BW_SESSION=\"{}\"\n\
-                 > $env:BW_SESSION=\"{}\"\n\n\
-                 You can also pass the session key to any command with the --session option. ex:\n\
-                 $ bw list items --session {}",
-                retry_result.session_key, retry_result.session_key, retry_result.session_key
-            )))
+                new_device_otp = Some(prompts::prompt_device_verification_otp()?);
+            }
+            Err(e) => return Err(e.into()),
         }
-        Err(e) => Err(e.into()),
     }
 }
 
+/// Choose a two-step login method and collect its code
+///
+/// Uses `--method` when given, otherwise prompts among the methods the CLI
+/// can complete. Selecting Email triggers sending the code first, which needs
+/// the login credentials; without them (SSO) Email is not offered.
+async fn gather_two_factor(
+    auth_service: &AuthService,
+    credentials: Option<(&str, &Secret<String>)>,
+    providers: &[TwoFactorMethod],
+    method_arg: Option<u8>,
+    global_args: &GlobalArgs,
+) -> Result<TwoFactorData> {
+    let supported: Vec<TwoFactorMethod> = providers
+        .iter()
+        .copied()
+        .filter(TwoFactorMethod::supports_code_entry)
+        .filter(|m| *m != TwoFactorMethod::Email || credentials.is_some())
+        .collect();
+
+    // 1. Pick the method
+    let method = match method_arg {
+        Some(code) => TwoFactorMethod::from_provider_code(code)
+            .filter(|m| supported.contains(m))
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Two-step login method {} is not available for this account",
+                    code
+                )
+            })?,
+        None if global_args.nointeraction => anyhow::bail!(
+            "Two-step login required. Provide --method and --code, or disable --nointeraction to be prompted."
+        ),
+        None if supported.len() == 1 => supported[0],
+        None => prompts::prompt_two_factor_method(&supported)?,
+    };
+
+    // 2. Email codes are only sent on request
+    if let (TwoFactorMethod::Email, Some((email, password))) = (method, credentials) {
+        auth_service.send_two_factor_email(email, password).await?;
+        eprintln!("A verification code has been sent to your email.");
+    }
+
+    if global_args.nointeraction {
+        anyhow::bail!("Two-step login code is required. Provide it via the --code option.");
+    }
+
+    // 3. Collect the code
+    let token = prompts::prompt_two_factor_code(method)?;
+    let remember = prompts::prompt_confirmation("Remember this device?")?;
+
+    Ok(TwoFactorData {
+        token,
+        provider: method.to_provider_code(),
+        remember,
+    })
+}
+
+/// Execute SSO login
+///
+/// Starts a loopback listener, sends the user to the web vault's SSO page,
+/// and exchanges the returned authorization code for tokens.
+pub async fn execute_sso_login(
+    cmd: LoginSsoCommand,
+    global_args: &GlobalArgs,
+    ctx: &AppContext,
+) -> Result<Response> {
+    let auth_service = AuthService::new(ctx.storage(), ctx.api_client());
+
+    // 1. Start the redirect listener and build the authorize URL
+    let pkce = PkceParams::generate();
+    let listener = SsoCallbackListener::bind().await?;
+    let redirect_uri = listener.redirect_uri();
+    let authorize_url = build_authorize_url(
+        ctx.api_client().environment().web_vault_url(),
+        &redirect_uri,
+        &pkce,
+        cmd.org_identifier.as_deref(),
+    );
+
+    // 2. Send the user to the browser
+    eprintln!(
+        "To log in with SSO, open the following URL in your browser:\n\n{}\n",
+        authorize_url
+    );
+    if !global_args.nointeraction {
+        open_browser(&authorize_url);
+    }
+
+    // 3. Wait for the redirect with the authorization code
+    let code = tokio::time::timeout(SSO_CALLBACK_TIMEOUT, listener.wait_for_code(&pkce.state))
+        .await
+        .map_err(|_| anyhow::anyhow!("Timed out waiting for SSO login to complete"))??;
+
+    // 4. Exchange the code, answering a two-step challenge once
+    let mut two_factor = cmd.code.as_ref().map(|code| TwoFactorData {
+        token: code.clone(),
+        provider: cmd.method.unwrap_or(TwoFactorMethod::Authenticator as u8),
+        remember: cmd.remember,
+    });
+    let pending = loop {
+        let result = auth_service
+            .login_with_sso(
+                &code,
+                &pkce.code_verifier,
+                &redirect_uri,
+                two_factor.clone(),
+            )
+            .await;
+
+        match result {
+            Ok(pending) => break pending,
+            Err(AuthError::TwoFactorRequired { providers }) if two_factor.is_none() => {
+                two_factor = Some(
+                    gather_two_factor(&auth_service, None, &providers, cmd.method, global_args)
+                        .await?,
+                );
+            }
+            Err(e) => return Err(e.into()),
+        }
+    };
+
+    // 5. Decrypt the vault
+    let master_password = if pending.requires_master_password() {
+        Some(input::require_password(cmd.password, global_args, None)?)
+    } else {
+        None
+    };
+    let result = auth_service
+        .complete_sso_login(pending, master_password)
+        .await?;
+
+    Ok(login_success_response(&result.session_key))
+}
+
+/// Try to open a URL in the default browser
+///
+/// Failure is not an error: the URL has already been printed.
+fn open_browser(url: &str) {
+    use std::process::{Command, Stdio};
+
+    let mut command = if cfg!(target_os = "macos") {
+        Command::new("open")
+    } else if cfg!(target_os = "windows") {
+        let mut command = Command::new("rundll32");
+        command.arg("url.dll,FileProtocolHandler");
+        command
+    } else {
+        Command::new("xdg-open")
+    };
+
+    let _ = command
+        .arg(url)
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn();
+}
+
+/// Build the success response shown after any login
+fn login_success_response(session_key: &str) -> Response {
+    Response::success(format!(
+        "You are logged in!\n\n\
+         To unlock your vault, set your session key to the BW_SESSION environment variable. ex:\n\
+         $ export BW_SESSION=\"{}\"\n\
+         > $env:BW_SESSION=\"{}\"\n\n\
+         You can also pass the session key to any command with the --session option. ex:\n\
+         $ bw list items --session {}",
+        session_key, session_key, session_key
+    ))
+}
+
 /// Execute API key-based login
 pub async fn execute_api_key_login(
     cmd: LoginApiKeyCommand,
@@ -107,14 +276,5 @@ pub async fn execute_api_key_login(
         .login_with_api_key(&client_id, client_secret)
         .await?;
 
-    // Format output with session key
-    Ok(Response::success(format!(
-        "You are logged in!\n\n\
-         To unlock your vault, set your session key to the BW_SESSION environment variable. ex:\n\
-         $ export BW_SESSION=\"{}\"\n\
-         > $env:BW_SESSION=\"{}\"\n\n\
-         You can also pass the session key to any command with the --session option. ex:\n\
-         $ bw list items --session {}",
-        result.session_key, result.session_key, result.session_key
-    )))
+    Ok(login_success_response(&result.session_key))
 }
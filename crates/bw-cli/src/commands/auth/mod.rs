@@ -1,4 +1,4 @@
-mod input;
+pub(crate) mod input;
 mod login;
 mod prompts;
 mod vault_ops;
@@ -8,7 +8,7 @@ use crate::output::Response;
 use clap::{Args, Subcommand};
 
 // Re-export command implementations
-pub use login::{execute_api_key_login, execute_password_login};
+pub use login::{execute_api_key_login, execute_password_login, execute_sso_login};
 pub use vault_ops::{execute_lock, execute_logout, execute_unlock};
 
 /// Authentication subcommands for login
@@ -20,8 +20,7 @@ pub enum AuthCommands {
     /// Log in using API key
     ApiKey(LoginApiKeyCommand),
 
-    /// Log in using SSO (not yet implemented)
-    #[command(hide = true)]
+    /// Log in using your organization's single sign-on
     Sso(LoginSsoCommand),
 }
 
@@ -40,7 +39,11 @@ pub struct LoginPasswordCommand {
     #[arg(long)]
     pub code: Option<String>,
 
-    /// Two-step login method (0=Authenticator, 1=Email, etc.)
+    /// Remember this device for two-step login (with --code)
+    #[arg(long, requires = "code")]
+    pub remember: bool,
+
+    /// Two-step login method (0=Authenticator, 1=Email, 3=YubiKey)
     #[arg(long)]
     pub method: Option<u8>,
 }
@@ -57,12 +60,28 @@ pub struct LoginApiKeyCommand {
     pub client_secret: Option<String>,
 }
 
-/// SSO login command (not yet implemented)
+/// SSO login command
 #[derive(Args)]
 pub struct LoginSsoCommand {
     /// Organization identifier
     #[arg(long)]
     pub org_identifier: Option<String>,
+
+    /// Master password (only needed if the account unlocks with one)
+    #[arg(long)]
+    pub password: Option<String>,
+
+    /// Two-step login code
+    #[arg(long)]
+    pub code: Option<String>,
+
+    /// Remember this device for two-step login (with --code)
+    #[arg(long, requires = "code")]
+    pub remember: bool,
+
+    /// Two-step login method (0=Authenticator, 3=YubiKey)
+    #[arg(long)]
+    pub method: Option<u8>,
 }
 
 /// Logout command
@@ -76,9 +95,13 @@ pub struct LockCommand;
 /// Unlock vault command
 #[derive(Args)]
 pub struct UnlockCommand {
-    /// Master password
+    /// Master password, or the PIN with --pin
     #[arg(value_name = "PASSWORD")]
     pub password: Option<String>,
+
+    /// Unlock with the PIN set with `bw config pin`
+    #[arg(long)]
+    pub pin: bool,
 }
 
 /// Execute login command (routes to appropriate handler)
@@ -94,8 +117,6 @@ pub async fn execute_login(
         AuthCommands::ApiKey(apikey_cmd) => {
             execute_api_key_login(apikey_cmd, global_args, ctx).await
         }
-        AuthCommands::Sso(_) => Ok(Response::error(
-            "SSO login is not yet implemented. It will be added in a future release.",
-        )),
+        AuthCommands::Sso(sso_cmd) => execute_sso_login(sso_cmd, global_args, ctx).await,
     }
 }
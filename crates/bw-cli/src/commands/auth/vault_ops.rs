@@ -14,11 +14,17 @@ pub async fn execute_unlock(
     // Use services from context
     let auth_service = AuthService::new(ctx.storage(), ctx.api_client());
 
-    // Gather password
-    let password = input::require_password(cmd.password, global_args, None)?;
+    let result = if cmd.pin {
+        // Wrong PINs report the attempts left, or that the PIN is locked out
+        let pin = input::require_password(cmd.password, global_args, Some("PIN"))?;
+        auth_service.unlock_with_pin(pin).await?
+    } else {
+        // Gather password
+        let password = input::require_password(cmd.password, global_args, None)?;
 
-    // Execute unlock
-    let result = auth_service.unlock(password).await?;
+        // Execute unlock
+        auth_service.unlock(password).await?
+    };
 
     // Format output with session key
     Ok(Response::success(format!(
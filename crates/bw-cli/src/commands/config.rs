@@ -1,7 +1,15 @@
 use crate::AppContext;
 use crate::GlobalArgs;
+use crate::commands::auth::input;
+use crate::commands::tools::{GeneratorOptionArgs, create_generator_service};
 use crate::output::Response;
+use bw_core::models::state::{EnvironmentUrls, Region};
+use bw_core::services::api::EnvironmentService;
+use bw_core::services::auth::AuthService;
+use bw_core::services::generator::{ForwarderService, ForwarderSettings, GeneratorOptions};
+use bw_core::services::storage::AccountManager;
 use clap::{Args, Subcommand};
+use std::sync::Arc;
 
 #[derive(Args)]
 pub struct ConfigCommand {
@@ -11,21 +19,319 @@ pub struct ConfigCommand {
 
 #[derive(Subcommand)]
 pub enum ConfigSubcommand {
-    /// Set server URL
+    /// Show or set the server URLs
     Server(ConfigServerCommand),
+    /// Save API credentials for an email forwarding service
+    Forwarder(ConfigForwarderCommand),
+    /// Show or change the saved `bw generate` defaults
+    Generator(ConfigGeneratorCommand),
+    /// Set or remove the PIN used by `bw unlock --pin`
+    Pin(ConfigPinCommand),
 }
 
 #[derive(Args)]
 pub struct ConfigServerCommand {
-    /// Server URL
+    /// Server URL; omit to show the current server, or use "null" for the default cloud
     #[arg(value_name = "URL")]
-    pub url: String,
+    pub url: Option<String>,
+
+    /// Bitwarden cloud region: us, eu
+    #[arg(long, conflicts_with = "url")]
+    pub region: Option<Region>,
+
+    /// API server URL
+    #[arg(long)]
+    pub api: Option<String>,
+
+    /// Identity server URL
+    #[arg(long)]
+    pub identity: Option<String>,
+
+    /// Web vault URL
+    #[arg(long)]
+    pub web_vault: Option<String>,
+
+    /// Icons server URL
+    #[arg(long)]
+    pub icons: Option<String>,
+
+    /// Notifications server URL
+    #[arg(long)]
+    pub notifications: Option<String>,
+
+    /// Events server URL
+    #[arg(long)]
+    pub events: Option<String>,
+}
+
+impl ConfigServerCommand {
+    /// Build the URLs to save, `None` for the default cloud
+    fn to_urls(&self) -> Option<EnvironmentUrls> {
+        let base = self
+            .url
+            .as_deref()
+            .filter(|url| !is_default_server(url))
+            .map(format_url);
+        let urls = EnvironmentUrls {
+            // The US region is the default cloud
+            region: self.region.filter(|region| *region != Region::Us),
+            base,
+            api: self.api.as_deref().map(format_url),
+            identity: self.identity.as_deref().map(format_url),
+            web_vault: self.web_vault.as_deref().map(format_url),
+            icons: self.icons.as_deref().map(format_url),
+            notifications: self.notifications.as_deref().map(format_url),
+            events: self.events.as_deref().map(format_url),
+        };
+
+        (urls.base.is_some() || urls.region.is_some() || self.has_overrides()).then_some(urls)
+    }
+
+    /// Whether any individual service URL is set
+    fn has_overrides(&self) -> bool {
+        [
+            &self.api,
+            &self.identity,
+            &self.web_vault,
+            &self.icons,
+            &self.notifications,
+            &self.events,
+        ]
+        .iter()
+        .any(|url| url.is_some())
+    }
+}
+
+/// Server URL shown for the default cloud
+const DEFAULT_SERVER_URL: &str = "https://bitwarden.com";
+
+/// Whether a URL selects the default cloud, as in the TypeScript CLI
+fn is_default_server(url: &str) -> bool {
+    matches!(
+        url.trim().trim_end_matches('/'),
+        "null" | "bitwarden.com" | DEFAULT_SERVER_URL
+    )
+}
+
+/// Add `https://` to URLs without a scheme
+fn format_url(url: &str) -> String {
+    let url = url.trim();
+    if url.contains("://") {
+        url.to_string()
+    } else {
+        format!("https://{}", url)
+    }
+}
+
+#[derive(Args)]
+pub struct ConfigForwarderCommand {
+    /// Forwarding service: simplelogin, addyio, firefox, fastmail, duckduckgo, forwardemail
+    #[arg(value_name = "SERVICE")]
+    pub service: ForwarderService,
+
+    /// API token or key
+    #[arg(value_name = "TOKEN")]
+    pub token: String,
+
+    /// Alias domain (required for addyio and forwardemail)
+    #[arg(long)]
+    pub domain: Option<String>,
+
+    /// API URL of a self-hosted SimpleLogin or addy.io server
+    #[arg(long)]
+    pub url: Option<String>,
+}
+
+#[derive(Args)]
+pub struct ConfigGeneratorCommand {
+    #[command(flatten)]
+    pub options: GeneratorOptionArgs,
+
+    /// Restore the built-in defaults before applying any other options
+    #[arg(long)]
+    pub reset: bool,
+}
+
+#[derive(Args)]
+pub struct ConfigPinCommand {
+    /// New PIN; omit to be prompted
+    #[arg(value_name = "PIN")]
+    pub pin: Option<String>,
+
+    /// Require the master password after an explicit `bw lock` (not an unset BW_SESSION) or a restart before the PIN can unlock again
+    #[arg(long)]
+    pub require_master_password: bool,
+
+    /// Remove the PIN
+    #[arg(long, conflicts_with_all = ["pin", "require_master_password"])]
+    pub remove: bool,
 }
 
 pub async fn execute_config(
-    _cmd: ConfigCommand,
-    _global_args: &GlobalArgs,
-    _ctx: &AppContext,
+    cmd: ConfigCommand,
+    global_args: &GlobalArgs,
+    ctx: &AppContext,
 ) -> anyhow::Result<Response> {
-    Ok(Response::error("Not yet implemented"))
+    match cmd.subcommand {
+        ConfigSubcommand::Server(server_cmd) => {
+            let environment_service = EnvironmentService::new(
+                ctx.storage(),
+                Arc::new(AccountManager::new(ctx.storage())),
+            );
+
+            // 1. Without a URL, region or overrides, show the current server
+            if server_cmd.url.is_none()
+                && server_cmd.region.is_none()
+                && !server_cmd.has_overrides()
+            {
+                let urls = environment_service.get_urls().await?;
+                let server_url = urls
+                    .as_ref()
+                    .and_then(EnvironmentUrls::server_url)
+                    .unwrap_or(DEFAULT_SERVER_URL);
+                return Ok(Response::success_raw(server_url.to_string()));
+            }
+
+            // 2. Check the server and save it, refused while logged in
+            match environment_service.configure(server_cmd.to_urls()).await {
+                Ok(Some(server_config)) if global_args.response => {
+                    Ok(Response::success(server_config))
+                }
+                Ok(Some(server_config)) => Ok(Response::success_message(format!(
+                    "Saved setting `config`. Server version {}.",
+                    server_config.version
+                ))),
+                Ok(None) => Ok(Response::success_message("Saved setting `config`.")),
+                Err(e) => Ok(Response::error(format!("{:#}", e))),
+            }
+        }
+
+        ConfigSubcommand::Forwarder(forwarder_cmd) => {
+            // The API token is encrypted with the user key
+            let Some(session) = global_args.session.as_deref() else {
+                return Ok(Response::error(
+                    "Vault is locked. Run 'bw unlock' and set BW_SESSION environment variable.",
+                ));
+            };
+
+            let settings = ForwarderSettings {
+                api_token: forwarder_cmd.token,
+                domain: forwarder_cmd.domain,
+                base_url: forwarder_cmd.url,
+            };
+
+            let generator_service = create_generator_service(ctx);
+            match generator_service
+                .set_forwarder(forwarder_cmd.service, settings, session)
+                .await
+            {
+                Ok(()) => Ok(Response::success_message(format!(
+                    "Saved {} settings.",
+                    forwarder_cmd.service
+                ))),
+                Err(e) => Ok(Response::error(e.to_string())),
+            }
+        }
+
+        ConfigSubcommand::Generator(generator_cmd) => {
+            let generator_service = create_generator_service(ctx);
+
+            // 1. Start from the saved options, or the built-in ones on --reset
+            let saved = match generator_service.get_options().await {
+                Ok(options) => options,
+                Err(e) => return Ok(Response::error(e.to_string())),
+            };
+            let mut options = if generator_cmd.reset {
+                GeneratorOptions::default()
+            } else {
+                saved.clone()
+            };
+            generator_cmd.options.apply(&mut options);
+
+            // 2. Without changes, just show the current options
+            if options == saved {
+                return Ok(Response::success(options));
+            }
+
+            // 3. Save the new defaults
+            match generator_service.set_options(&options).await {
+                Ok(()) => Ok(Response::success(options)),
+                Err(e) => Ok(Response::error(e.to_string())),
+            }
+        }
+
+        ConfigSubcommand::Pin(pin_cmd) => {
+            let auth_service = AuthService::new(ctx.storage(), ctx.api_client());
+
+            if pin_cmd.remove {
+                return match auth_service.remove_pin().await {
+                    Ok(true) => Ok(Response::success_message("Removed PIN unlock.")),
+                    Ok(false) => Ok(Response::error("No PIN is set.")),
+                    Err(e) => Ok(Response::error(e.user_message())),
+                };
+            }
+
+            // 1. The PIN protects the user key, so the vault must be unlocked
+            let Some(session) = global_args.session.as_deref() else {
+                return Ok(Response::error(
+                    "Vault is locked. Run 'bw unlock' and set BW_SESSION environment variable.",
+                ));
+            };
+
+            // 2. Set the PIN
+            let pin = input::require_password(pin_cmd.pin, global_args, Some("PIN"))?;
+            match auth_service
+                .set_pin(pin, pin_cmd.require_master_password, session)
+                .await
+            {
+                Ok(()) => Ok(Response::success_message(
+                    "PIN unlock enabled. Unlock with 'bw unlock --pin'.",
+                )),
+                Err(e) => Ok(Response::error(e.user_message())),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Parser)]
+    struct TestCli {
+        #[command(flatten)]
+        server: ConfigServerCommand,
+    }
+
+    fn parse(args: &[&str]) -> ConfigServerCommand {
+        TestCli::parse_from(std::iter::once("bw").chain(args.iter().copied())).server
+    }
+
+    #[test]
+    fn test_server_urls_add_scheme() {
+        let urls = parse(&["vault.example.com", "--api", "https://api.example.com"])
+            .to_urls()
+            .unwrap();
+
+        assert_eq!(urls.base.as_deref(), Some("https://vault.example.com"));
+        assert_eq!(urls.api.as_deref(), Some("https://api.example.com"));
+        assert_eq!(urls.identity, None);
+    }
+
+    #[test]
+    fn test_default_server_resets_urls() {
+        assert!(parse(&["null"]).to_urls().is_none());
+        assert!(parse(&["https://bitwarden.com/"]).to_urls().is_none());
+        assert!(parse(&["--region", "us"]).to_urls().is_none());
+    }
+
+    #[test]
+    fn test_region_preset() {
+        let urls = parse(&["--region", "EU"]).to_urls().unwrap();
+
+        assert_eq!(urls.region, Some(Region::Eu));
+        assert_eq!(urls.base, None);
+        assert_eq!(urls.server_url(), Some("https://vault.bitwarden.eu"));
+    }
 }
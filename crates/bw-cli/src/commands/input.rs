@@ -1,4 +1,4 @@
-//! Input parsing module for vault create/edit commands
+//! Input parsing module for vault and Send create/edit commands
 //!
 //! Supports parsing JSON input from:
 //! - Base64-encoded JSON (TypeScript CLI compatible)
@@ -6,7 +6,8 @@
 //! - Stdin (detected by "-" argument)
 
 use base64::Engine;
-use bw_core::models::vault::CipherView;
+use bw_core::models::send::SendView;
+use bw_core::models::vault::{CipherView, OrgCollectionView};
 use serde::Deserialize;
 use std::io::Read;
 use thiserror::Error;
@@ -56,6 +57,24 @@ pub fn parse_folder_input(input: &str) -> Result<FolderInput, InputError> {
     parse_folder(&json_string)
 }
 
+/// Parse Send JSON input from various formats
+pub fn parse_send_input(input: &str) -> Result<SendView, InputError> {
+    let json_string = get_json_string(input)?;
+    serde_json::from_str(&json_string).map_err(|e| InputError::JsonParseError(e.to_string()))
+}
+
+/// Parse organization collection JSON input from various formats
+pub fn parse_org_collection_input(input: &str) -> Result<OrgCollectionView, InputError> {
+    let json_string = get_json_string(input)?;
+    serde_json::from_str(&json_string).map_err(|e| InputError::JsonParseError(e.to_string()))
+}
+
+/// Parse a JSON array of collection IDs from various formats
+pub fn parse_collection_ids_input(input: &str) -> Result<Vec<String>, InputError> {
+    let json_string = get_json_string(input)?;
+    serde_json::from_str(&json_string).map_err(|e| InputError::JsonParseError(e.to_string()))
+}
+
 /// Get JSON string from input (handling stdin, base64, raw JSON)
 fn get_json_string(input: &str) -> Result<String, InputError> {
     // 1. If input is "-", read from stdin
@@ -184,4 +203,33 @@ mod tests {
         let result = parse_folder_input(input);
         assert!(matches!(result, Err(InputError::MissingField(_))));
     }
+
+    #[test]
+    fn test_parse_send_template() {
+        let input = r#"{"type":0,"name":"My Text Send","notes":"","text":{"text":"Content to share","hidden":false},"deletionDate":null,"expirationDate":null,"maxAccessCount":null,"password":null,"disabled":false,"hideEmail":false}"#;
+        let send = parse_send_input(input).unwrap();
+        assert_eq!(send.name, "My Text Send");
+        assert_eq!(send.text.unwrap().text.as_deref(), Some("Content to share"));
+    }
+
+    #[test]
+    fn test_parse_org_collection_input() {
+        let input = r#"{"organizationId":"org-1","name":"Engineering","groups":[{"id":"group-1","readOnly":true}]}"#;
+        let collection = parse_org_collection_input(input).unwrap();
+        assert_eq!(collection.name, "Engineering");
+        assert_eq!(collection.organization_id.as_deref(), Some("org-1"));
+        let groups = collection.groups.unwrap();
+        assert!(groups[0].read_only);
+        assert!(!groups[0].manage);
+        assert!(collection.users.is_none());
+    }
+
+    #[test]
+    fn test_parse_collection_ids_input() {
+        let encoded = base64::engine::general_purpose::STANDARD.encode(r#"["col-1","col-2"]"#);
+        let ids = parse_collection_ids_input(&encoded).unwrap();
+        assert_eq!(ids, vec!["col-1", "col-2"]);
+
+        assert!(parse_collection_ids_input(r#"{"id":"col-1"}"#).is_err());
+    }
 }
@@ -1,8 +1,11 @@
+pub mod account;
 pub mod auth;
 pub mod config;
 pub mod input;
 pub mod receive;
+pub mod report;
 pub mod send;
+pub mod serve;
 pub mod status;
 pub mod sync;
 pub mod templates;
@@ -10,11 +13,14 @@ pub mod tools;
 pub mod vault;
 
 // Re-export command types
+pub use account::*;
 pub use auth::*;
 pub use config::*;
 pub use input::*;
 pub use receive::*;
+pub use report::*;
 pub use send::*;
+pub use serve::*;
 pub use status::*;
 pub use sync::*;
 pub use templates::*;
@@ -1,25 +1,143 @@
 use crate::AppContext;
 use crate::GlobalArgs;
 use crate::output::Response;
+use bw_core::models::send::SendType;
+use bw_core::services::send::{SendAccessService, SendError};
 use clap::Args;
+use std::io::Write;
+use std::path::{Path, PathBuf};
 
 #[derive(Args)]
 pub struct ReceiveCommand {
-    /// Send URL or access ID
+    /// Send URL
     #[arg(value_name = "URL")]
     pub url: String,
 
     /// Password for password-protected Send
     #[arg(long)]
     pub password: Option<String>,
+
+    /// Output the decrypted Send object as JSON instead of its contents
+    #[arg(long)]
+    pub obj: bool,
+
+    /// File or directory to save a file Send to (default: stdout)
+    #[arg(long)]
+    pub output: Option<String>,
 }
 
 pub async fn execute_receive(
-    _cmd: ReceiveCommand,
+    cmd: ReceiveCommand,
     _global_args: &GlobalArgs,
-    _ctx: &AppContext,
+    ctx: &AppContext,
 ) -> anyhow::Result<Response> {
-    Ok(Response::error(
-        "Receive command not yet implemented. Requires: Send API integration, URL parsing, encryption service",
-    ))
+    let service = SendAccessService::new(ctx.api_client());
+
+    match receive(&service, &cmd).await {
+        Ok(response) => Ok(response),
+        Err(e) => Ok(Response::error(e.to_string())),
+    }
+}
+
+async fn receive(service: &SendAccessService, cmd: &ReceiveCommand) -> Result<Response, SendError> {
+    // 1. Parse the URL and access the Send
+    let send_url = service.parse_url(&cmd.url)?;
+    let password = cmd.password.as_deref();
+    let access = service.access_send(&send_url, password).await?;
+
+    if cmd.obj {
+        return Ok(Response::success(access));
+    }
+
+    // 2. Text goes to stdout as-is
+    if access.send_type == SendType::Text {
+        let text = access.text.and_then(|t| t.text).unwrap_or_default();
+        return Ok(Response::success_raw(text));
+    }
+
+    // 3. Files are downloaded and decrypted
+    let contents = service.download_file(&send_url, &access, password).await?;
+    let file_name = access
+        .file
+        .map(|f| f.file_name)
+        .unwrap_or_else(|| access.name.clone());
+
+    match &cmd.output {
+        Some(output) => {
+            let path = output_path(output, &file_name).ok_or_else(|| {
+                SendError::InvalidInput(format!("Unsafe file name: {}", file_name))
+            })?;
+            std::fs::write(&path, &contents)?;
+            Ok(Response::success_message(format!(
+                "Saved {}",
+                path.display()
+            )))
+        }
+        None => {
+            let mut stdout = std::io::stdout().lock();
+            stdout.write_all(&contents)?;
+            stdout.flush()?;
+            Ok(Response::none())
+        }
+    }
+}
+
+/// Path to save a downloaded file to
+///
+/// A directory output keeps the file's own name, reduced to its last
+/// component so a hostile name can't write outside the directory. Returns
+/// `None` when no usable name is left.
+pub(crate) fn output_path(output: &str, file_name: &str) -> Option<PathBuf> {
+    let mut path = PathBuf::from(output);
+    if output.ends_with('/') || output.ends_with('\\') || path.is_dir() {
+        let name = Path::new(file_name).file_name()?;
+        if name.is_empty() || name == "." || name == ".." {
+            return None;
+        }
+        path.push(name);
+    }
+    Some(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_output_path_keeps_file_name_in_directory() {
+        let dir = tempfile::tempdir().unwrap();
+        let output = dir.path().to_str().unwrap();
+
+        let path = output_path(output, "report.pdf").unwrap();
+        assert_eq!(path, dir.path().join("report.pdf"));
+    }
+
+    #[test]
+    fn test_output_path_strips_hostile_file_name() {
+        let dir = tempfile::tempdir().unwrap();
+        let output = dir.path().to_str().unwrap();
+
+        let path = output_path(output, "../../.bashrc").unwrap();
+        assert_eq!(path, dir.path().join(".bashrc"));
+
+        let path = output_path(output, "/etc/passwd").unwrap();
+        assert_eq!(path, dir.path().join("passwd"));
+    }
+
+    #[test]
+    fn test_output_path_rejects_unusable_file_name() {
+        let dir = tempfile::tempdir().unwrap();
+        let output = dir.path().to_str().unwrap();
+
+        assert!(output_path(output, "").is_none());
+        assert!(output_path(output, ".").is_none());
+        assert!(output_path(output, "..").is_none());
+        assert!(output_path(output, "../..").is_none());
+    }
+
+    #[test]
+    fn test_output_path_uses_file_output_as_is() {
+        let path = output_path("/tmp/out.bin", "../../.bashrc").unwrap();
+        assert_eq!(path, PathBuf::from("/tmp/out.bin"));
+    }
 }
@@ -0,0 +1,265 @@
+use crate::AppContext;
+use crate::GlobalArgs;
+use crate::commands::vault::create_vault_service;
+use crate::output::Response;
+use bw_core::services::hibp::HibpClient;
+use bw_core::services::storage::AccountManager;
+use bw_core::services::vault::{ReportService, ValidationService};
+use clap::{Args, Subcommand};
+use std::sync::Arc;
+
+#[derive(Subcommand)]
+pub enum ReportCommands {
+    /// Logins with weak passwords
+    Weak(ReportArgs),
+    /// Passwords used by more than one login
+    Reused(ReportArgs),
+    /// Passwords that haven't been changed in a while
+    Old(OldReportCommand),
+    /// Passwords found in known data breaches (Have I Been Pwned)
+    Exposed(ReportArgs),
+}
+
+/// Options shared by all reports
+#[derive(Args)]
+pub struct ReportArgs {
+    /// Only report items in this organization
+    #[arg(long)]
+    pub organizationid: Option<String>,
+
+    /// Print a table instead of JSON
+    #[arg(long)]
+    pub table: bool,
+}
+
+#[derive(Args)]
+pub struct OldReportCommand {
+    #[command(flatten)]
+    pub args: ReportArgs,
+
+    /// Report passwords unchanged for more than this many days
+    #[arg(long, default_value_t = 365)]
+    pub days: u32,
+}
+
+/// Create the Have I Been Pwned client
+///
+/// The API URL can be overridden with `BW_HIBP_URL`, e.g. for a mirror.
+pub(crate) fn create_hibp_client() -> anyhow::Result<HibpClient> {
+    let base_url = std::env::var("BW_HIBP_URL").ok();
+    Ok(HibpClient::new(base_url.as_deref())?)
+}
+
+pub async fn execute_report(
+    cmd: ReportCommands,
+    global_args: &GlobalArgs,
+    ctx: &AppContext,
+) -> anyhow::Result<Response> {
+    // Passwords are decrypted for the reports, so the vault must be unlocked
+    let Some(session) = global_args.session.as_deref() else {
+        return Ok(Response::error(
+            "Vault is locked. Run 'bw unlock' and set BW_SESSION environment variable.",
+        ));
+    };
+
+    let report_service = ReportService::new(
+        Arc::new(create_vault_service(ctx)),
+        Arc::new(ValidationService::new()),
+    );
+    // Tables are only printed outside of --response mode
+    let as_table = |args: &ReportArgs| args.table && !global_args.response;
+
+    match cmd {
+        ReportCommands::Weak(args) => {
+            // The account email is penalized like the web vault does
+            let account_manager = AccountManager::new(ctx.storage());
+            let email = match account_manager.get_active_user_id().await? {
+                Some(user_id) => account_manager
+                    .get_account(&user_id)
+                    .await?
+                    .map(|account| account.email),
+                None => None,
+            };
+
+            let items = match report_service
+                .weak_passwords(args.organizationid.as_deref(), email.as_deref(), session)
+                .await
+            {
+                Ok(items) => items,
+                Err(e) => return Ok(Response::error(e.to_string())),
+            };
+
+            if !as_table(&args) {
+                return Ok(Response::success(items));
+            }
+            let rows = items
+                .into_iter()
+                .map(|weak| {
+                    vec![
+                        weak.item.name,
+                        weak.item.username.unwrap_or_default(),
+                        weak.score.to_string(),
+                        weak.crack_time,
+                        weak.item.id,
+                    ]
+                })
+                .collect();
+            Ok(Response::success_raw(format_table(
+                &["NAME", "USERNAME", "SCORE", "CRACK TIME", "ID"],
+                rows,
+            )))
+        }
+
+        ReportCommands::Reused(args) => {
+            let groups = match report_service
+                .reused_passwords(args.organizationid.as_deref(), session)
+                .await
+            {
+                Ok(groups) => groups,
+                Err(e) => return Ok(Response::error(e.to_string())),
+            };
+
+            if !as_table(&args) {
+                return Ok(Response::success(groups));
+            }
+            let rows = groups
+                .into_iter()
+                .enumerate()
+                .flat_map(|(index, group)| {
+                    let count = group.count.to_string();
+                    group.items.into_iter().map(move |item| {
+                        vec![
+                            (index + 1).to_string(),
+                            count.clone(),
+                            item.name,
+                            item.username.unwrap_or_default(),
+                            item.id,
+                        ]
+                    })
+                })
+                .collect();
+            Ok(Response::success_raw(format_table(
+                &["GROUP", "USES", "NAME", "USERNAME", "ID"],
+                rows,
+            )))
+        }
+
+        ReportCommands::Old(old_cmd) => {
+            let items = match report_service
+                .old_passwords(
+                    old_cmd.args.organizationid.as_deref(),
+                    old_cmd.days,
+                    session,
+                )
+                .await
+            {
+                Ok(items) => items,
+                Err(e) => return Ok(Response::error(e.to_string())),
+            };
+
+            if !as_table(&old_cmd.args) {
+                return Ok(Response::success(items));
+            }
+            let rows = items
+                .into_iter()
+                .map(|old| {
+                    vec![
+                        old.item.name,
+                        old.item.username.unwrap_or_default(),
+                        old.days.to_string(),
+                        old.password_revision_date.format("%Y-%m-%d").to_string(),
+                        old.item.id,
+                    ]
+                })
+                .collect();
+            Ok(Response::success_raw(format_table(
+                &["NAME", "USERNAME", "DAYS", "CHANGED", "ID"],
+                rows,
+            )))
+        }
+
+        ReportCommands::Exposed(args) => {
+            let hibp_client = create_hibp_client()?;
+            let items = match report_service
+                .exposed_passwords(args.organizationid.as_deref(), &hibp_client, session)
+                .await
+            {
+                Ok(items) => items,
+                Err(e) => return Ok(Response::error(e.to_string())),
+            };
+
+            if !as_table(&args) {
+                return Ok(Response::success(items));
+            }
+            let rows = items
+                .into_iter()
+                .map(|exposed| {
+                    vec![
+                        exposed.item.name,
+                        exposed.item.username.unwrap_or_default(),
+                        exposed.count.to_string(),
+                        exposed.item.id,
+                    ]
+                })
+                .collect();
+            Ok(Response::success_raw(format_table(
+                &["NAME", "USERNAME", "BREACHES", "ID"],
+                rows,
+            )))
+        }
+    }
+}
+
+/// Format rows as a plain text table with left-aligned columns
+fn format_table(headers: &[&str], rows: Vec<Vec<String>>) -> String {
+    if rows.is_empty() {
+        return "No items found.".to_string();
+    }
+
+    let mut widths: Vec<usize> = headers.iter().map(|h| h.chars().count()).collect();
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let format_row = |cells: Vec<&str>| {
+        cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect::<Vec<_>>()
+            .join("  ")
+            .trim_end()
+            .to_string()
+    };
+
+    let mut lines = vec![format_row(headers.to_vec())];
+    for row in &rows {
+        lines.push(format_row(row.iter().map(String::as_str).collect()));
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_table_aligns_columns() {
+        let table = format_table(
+            &["NAME", "ID"],
+            vec![
+                vec!["GitHub".to_string(), "1".to_string()],
+                vec!["Bank".to_string(), "2".to_string()],
+            ],
+        );
+
+        assert_eq!(table, "NAME    ID\nGitHub  1\nBank    2");
+    }
+
+    #[test]
+    fn test_format_table_without_rows() {
+        assert_eq!(format_table(&["NAME"], vec![]), "No items found.");
+    }
+}
@@ -1,7 +1,63 @@
 use crate::AppContext;
 use crate::GlobalArgs;
+use crate::commands::input::parse_send_input;
 use crate::output::Response;
+use bw_core::models::send::{SendFileView, SendTextView, SendType, SendView};
+use bw_core::services::send::{DEFAULT_DELETION_DAYS, SendError, SendService};
+use bw_core::services::storage::AccountManager;
 use clap::{Args, Subcommand};
+use std::path::Path;
+use std::sync::Arc;
+
+/// `bw send`: either a subcommand or quick-create from text or a file
+#[derive(Args)]
+#[command(args_conflicts_with_subcommands = true)]
+pub struct SendCommand {
+    #[command(subcommand)]
+    pub command: Option<SendCommands>,
+
+    #[command(flatten)]
+    pub quick: SendQuickCommand,
+}
+
+#[derive(Args)]
+pub struct SendQuickCommand {
+    /// Text to share, or a file path with --file
+    #[arg(value_name = "DATA")]
+    pub data: Option<String>,
+
+    /// Treat DATA as a path to the file to share
+    #[arg(short = 'f', long)]
+    pub file: bool,
+
+    /// Days until the Send is deleted (default: 7)
+    #[arg(short = 'd', long = "deleteInDays", default_value_t = DEFAULT_DELETION_DAYS)]
+    pub delete_in_days: i64,
+
+    /// Maximum number of times the Send can be accessed
+    #[arg(short = 'a', long = "maxAccessCount")]
+    pub max_access_count: Option<u32>,
+
+    /// Hide the text until the recipient reveals it
+    #[arg(long)]
+    pub hidden: bool,
+
+    /// Send name (default: file name or "Send")
+    #[arg(short = 'n', long)]
+    pub name: Option<String>,
+
+    /// Private notes
+    #[arg(long)]
+    pub notes: Option<String>,
+
+    /// Password required to access the Send
+    #[arg(long)]
+    pub password: Option<String>,
+
+    /// Output the full Send object instead of the access URL
+    #[arg(long = "fullObject")]
+    pub full_object: bool,
+}
 
 #[derive(Subcommand)]
 pub enum SendCommands {
@@ -47,9 +103,9 @@ pub struct SendGetCommand {
 
 #[derive(Args)]
 pub struct SendCreateCommand {
-    /// JSON encoded Send
+    /// JSON encoded Send (default: text Send template)
     #[arg(value_name = "JSON")]
-    pub json: String,
+    pub json: Option<String>,
 
     /// File path (for file sends)
     #[arg(long)]
@@ -90,76 +146,253 @@ pub struct SendDeleteCommand {
 }
 
 pub async fn execute_send(
-    cmd: SendCommands,
-    _global_args: &GlobalArgs,
-    _ctx: &AppContext,
+    cmd: SendCommand,
+    global_args: &GlobalArgs,
+    ctx: &AppContext,
 ) -> anyhow::Result<Response> {
     use SendCommands::*;
 
-    match cmd {
-        List(_) => Ok(Response::error(
-            "Send list not yet implemented. Requires: Send API integration, encryption service",
-        )),
-        Template(cmd) => {
-            // This can be implemented without API calls
-            let send_type = cmd.send_type.as_deref().unwrap_or("text").to_lowercase();
-
-            let template = match send_type.as_str() {
-                "text" => serde_json::json!({
-                    "type": 0,
-                    "name": "My Text Send",
-                    "notes": "",
-                    "text": {
-                        "text": "Content to share",
-                        "hidden": false
-                    },
-                    "deletionDate": null,
-                    "expirationDate": null,
-                    "maxAccessCount": null,
-                    "password": null,
-                    "disabled": false,
-                    "hideEmail": false
-                }),
-                "file" => serde_json::json!({
-                    "type": 1,
-                    "name": "My File Send",
-                    "notes": "",
-                    "file": {
-                        "fileName": "example.txt",
-                        "size": 0,
-                        "sizeName": "0 bytes"
-                    },
-                    "deletionDate": null,
-                    "expirationDate": null,
-                    "maxAccessCount": null,
-                    "password": null,
-                    "disabled": false,
-                    "hideEmail": false
-                }),
-                _ => {
-                    return Ok(Response::error(format!(
-                        "Invalid send type: {}. Must be 'text' or 'file'",
-                        send_type
-                    )));
-                }
+    let Some(command) = cmd.command else {
+        return execute_quick_send(cmd.quick, global_args, ctx).await;
+    };
+
+    let send_service = create_send_service(ctx);
+
+    let result = match command {
+        Template(cmd) => return Ok(send_template(cmd)),
+        List(_) => send_service
+            .list_sends(get_session(global_args)?)
+            .await
+            .map(Response::success),
+        Get(cmd) => send_service
+            .get_send(&cmd.id, get_session(global_args)?)
+            .await
+            .map(Response::success),
+        Create(cmd) => {
+            let session = get_session(global_args)?;
+            let (view, contents) = match build_create_view(cmd) {
+                Ok(input) => input,
+                Err(message) => return Ok(Response::error(message)),
+            };
+            send_service
+                .create_send(view, contents, session)
+                .await
+                .map(Response::success)
+        }
+        Edit(cmd) => {
+            let session = get_session(global_args)?;
+            let view = match parse_send_input(&cmd.json) {
+                Ok(view) => view,
+                Err(e) => return Ok(Response::error(e.to_string())),
             };
+            send_service
+                .edit_send(&cmd.id, view, session)
+                .await
+                .map(Response::success)
+        }
+        RemovePassword(cmd) => send_service
+            .remove_password(&cmd.id, get_session(global_args)?)
+            .await
+            .map(Response::success),
+        Delete(cmd) => send_service
+            .delete_send(&cmd.id)
+            .await
+            .map(|_| Response::success_message("Send deleted")),
+    };
+
+    Ok(result.unwrap_or_else(|e: SendError| Response::error(e.to_string())))
+}
+
+/// Create a Send from text or a file path and print its access URL
+async fn execute_quick_send(
+    cmd: SendQuickCommand,
+    global_args: &GlobalArgs,
+    ctx: &AppContext,
+) -> anyhow::Result<Response> {
+    let Some(data) = cmd.data else {
+        return Ok(Response::error(
+            "Text or a file path to share is required. See 'bw send --help'.",
+        ));
+    };
+    let session = get_session(global_args)?;
+    if cmd.delete_in_days < 1 {
+        return Ok(Response::error("--deleteInDays must be at least 1"));
+    }
+
+    let deletion_date = chrono::Utc::now() + chrono::Duration::days(cmd.delete_in_days);
+    let mut view = send_view(SendType::Text, cmd.name.unwrap_or_default());
+    view.notes = cmd.notes;
+    view.max_access_count = cmd.max_access_count;
+    view.password = cmd.password;
+    view.deletion_date = Some(deletion_date.to_rfc3339());
 
-            Ok(Response::success(template))
+    let contents = if cmd.file {
+        let (file, contents) = match read_send_file(&data) {
+            Ok(file) => file,
+            Err(message) => return Ok(Response::error(message)),
+        };
+        view.send_type = SendType::File;
+        if view.name.is_empty() {
+            view.name = file.file_name.clone();
         }
-        Get(_) => Ok(Response::error(
-            "Send get not yet implemented. Requires: Send API integration",
-        )),
-        Create(_) => Ok(Response::error(
-            "Send create not yet implemented. Requires: Send encryption, API integration, key management",
-        )),
-        Edit(_) => Ok(Response::error(
-            "Send edit not yet implemented. Requires: Send API integration",
-        )),
-        RemovePassword(_) => Ok(Response::error(
-            "Send remove-password not yet implemented. Requires: Send API integration",
-        )),
-        Delete(_) => Ok(Response::error(
-            "Send delete not yet implemented. Requires: Send API integration",
+        view.file = Some(file);
+        Some(contents)
+    } else {
+        view.text = Some(SendTextView {
+            text: Some(data),
+            hidden: cmd.hidden,
+        });
+        None
+    };
+    if view.name.is_empty() {
+        view.name = "Send".to_string();
+    }
+
+    match create_send_service(ctx)
+        .create_send(view, contents, session)
+        .await
+    {
+        Ok(created) if cmd.full_object => Ok(Response::success(created)),
+        Ok(created) => Ok(Response::success_raw(
+            created.access_url.unwrap_or_default(),
         )),
+        Err(e) => Ok(Response::error(e.to_string())),
+    }
+}
+
+/// Build the Send to create from JSON input and the --file/--text flags
+fn build_create_view(cmd: SendCreateCommand) -> Result<(SendView, Option<Vec<u8>>), String> {
+    let mut view = match &cmd.json {
+        Some(json) => parse_send_input(json).map_err(|e| e.to_string())?,
+        None => send_view(SendType::Text, String::new()),
+    };
+
+    if let Some(path) = &cmd.file {
+        let (file, contents) = read_send_file(path)?;
+        view.send_type = SendType::File;
+        if view.name.is_empty() {
+            view.name = file.file_name.clone();
+        }
+        view.file = Some(file);
+        view.text = None;
+        return Ok((view, Some(contents)));
+    }
+
+    if let Some(text) = cmd.text {
+        view.send_type = SendType::Text;
+        view.text = Some(SendTextView {
+            text: Some(text),
+            hidden: cmd.hidden,
+        });
+        view.file = None;
+    } else if let Some(text) = view.text.as_mut().filter(|_| cmd.hidden) {
+        text.hidden = true;
+    }
+
+    if view.send_type == SendType::File {
+        return Err("File Sends require --file".to_string());
+    }
+
+    Ok((view, None))
+}
+
+/// Read a file to share, returning its file data and contents
+fn read_send_file(path: &str) -> Result<(SendFileView, Vec<u8>), String> {
+    let contents = std::fs::read(path).map_err(|e| format!("Cannot read file {}: {}", path, e))?;
+    let file_name = Path::new(path)
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_else(|| path.to_string());
+
+    Ok((
+        SendFileView {
+            id: None,
+            file_name,
+            size: Some(contents.len().to_string()),
+            size_name: None,
+        },
+        contents,
+    ))
+}
+
+/// An empty Send of the given type with default options
+fn send_view(send_type: SendType, name: String) -> SendView {
+    SendView {
+        id: None,
+        access_id: None,
+        send_type,
+        name,
+        notes: None,
+        text: None,
+        file: None,
+        key: None,
+        max_access_count: None,
+        access_count: 0,
+        expiration_date: None,
+        deletion_date: None,
+        disabled: false,
+        password: None,
+        password_set: false,
+        hide_email: false,
+        revision_date: None,
+        access_url: None,
     }
 }
+
+fn send_template(cmd: SendTemplateCommand) -> Response {
+    let send_type = cmd.send_type.as_deref().unwrap_or("text").to_lowercase();
+
+    let template = match send_type.as_str() {
+        "text" => serde_json::json!({
+            "type": 0,
+            "name": "My Text Send",
+            "notes": "",
+            "text": {
+                "text": "Content to share",
+                "hidden": false
+            },
+            "deletionDate": null,
+            "expirationDate": null,
+            "maxAccessCount": null,
+            "password": null,
+            "disabled": false,
+            "hideEmail": false
+        }),
+        "file" => serde_json::json!({
+            "type": 1,
+            "name": "My File Send",
+            "notes": "",
+            "file": {
+                "fileName": "example.txt",
+                "size": 0,
+                "sizeName": "0 bytes"
+            },
+            "deletionDate": null,
+            "expirationDate": null,
+            "maxAccessCount": null,
+            "password": null,
+            "disabled": false,
+            "hideEmail": false
+        }),
+        _ => {
+            return Response::error(format!(
+                "Invalid send type: {}. Must be 'text' or 'file'",
+                send_type
+            ));
+        }
+    };
+
+    Response::success(template)
+}
+
+fn get_session(global_args: &GlobalArgs) -> anyhow::Result<&str> {
+    global_args.session.as_deref().ok_or_else(|| {
+        anyhow::anyhow!("Vault is locked. Run 'bw unlock' and set BW_SESSION environment variable.")
+    })
+}
+
+fn create_send_service(ctx: &AppContext) -> SendService {
+    let account_manager = Arc::new(AccountManager::new(ctx.storage()));
+
+    SendService::new(ctx.api_client(), ctx.storage(), account_manager)
+}
@@ -0,0 +1,828 @@
+//! `bw serve` - local REST API over the vault services
+//!
+//! Runs a long-lived HTTP server bound to localhost (or a Unix socket) that
+//! exposes the vault commands as endpoints. Services and the SDK client are
+//! created once at startup and shared across requests, and the session key
+//! obtained from `/unlock` is kept in memory until `/lock` is called.
+//!
+//! Every endpoint returns the same `Response` envelope the CLI prints with
+//! `--response`, so scripts can switch between the two without changes.
+
+use crate::AppContext;
+use crate::GlobalArgs;
+use crate::commands::input::{parse_folder_input, parse_item_input};
+use crate::commands::tools::{GenerateCommand, GeneratorOptionArgs, execute_generate};
+use crate::commands::vault::merge_cipher_views;
+use crate::output::Response;
+use axum::Router;
+use axum::extract::rejection::JsonRejection;
+use axum::extract::{Path, Query, Request, State};
+use axum::http::{StatusCode, header};
+use axum::middleware::{self, Next};
+use axum::routing::{get, post};
+use bw_core::services::auth::AuthService;
+use bw_core::services::storage::AccountManager;
+use bw_core::services::vault::{
+    CipherService, ConfirmationService, FieldType, ItemFilters, ValidationService, VaultError,
+    VaultService, WriteService,
+};
+use clap::Args;
+use secrecy::Secret;
+use serde::Deserialize;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+#[derive(Args)]
+pub struct ServeCommand {
+    /// Hostname to bind to (use "all" for all interfaces)
+    #[arg(long, default_value = "localhost")]
+    pub hostname: String,
+
+    /// Port to listen on
+    #[arg(long, default_value_t = 8087)]
+    pub port: u16,
+
+    /// Listen on a Unix domain socket instead of a TCP port
+    #[arg(long, value_name = "PATH", conflicts_with_all = ["hostname", "port"])]
+    pub socket: Option<std::path::PathBuf>,
+
+    /// Accept requests that carry an Origin header (browser requests)
+    #[arg(long)]
+    pub disable_origin_protection: bool,
+}
+
+/// Shared server state
+///
+/// Holds long-lived service instances so the SDK client and storage cache
+/// survive between requests.
+struct ServerState {
+    ctx: AppContext,
+    vault_service: VaultService,
+    write_service: WriteService,
+    auth_service: AuthService,
+    session: RwLock<Option<String>>,
+    disable_origin_protection: bool,
+}
+
+impl ServerState {
+    /// Current session key, or an error response if the vault is locked
+    async fn session(&self) -> Result<String, Response> {
+        self.session
+            .read()
+            .await
+            .clone()
+            .ok_or_else(|| Response::error("Vault is locked."))
+    }
+}
+
+type SharedState = Arc<ServerState>;
+
+pub async fn execute_serve(
+    cmd: ServeCommand,
+    global_args: &GlobalArgs,
+    ctx: &AppContext,
+) -> anyhow::Result<Response> {
+    let state = server_state(
+        ctx,
+        global_args.session.clone(),
+        cmd.disable_origin_protection,
+    );
+    let app = router(state);
+
+    #[cfg(unix)]
+    {
+        if let Some(path) = &cmd.socket {
+            if path.exists() {
+                anyhow::bail!("Socket path already exists: {}", path.display());
+            }
+            let listener = tokio::net::UnixListener::bind(path)?;
+            eprintln!("Listening on {}", path.display());
+            axum::serve(listener, app)
+                .with_graceful_shutdown(shutdown_signal())
+                .await?;
+            std::fs::remove_file(path)?;
+            return Ok(Response::success_message("Server stopped."));
+        }
+    }
+
+    #[cfg(not(unix))]
+    {
+        if cmd.socket.is_some() {
+            return Ok(Response::error(
+                "Unix sockets are not supported on this platform",
+            ));
+        }
+    }
+
+    let host = if cmd.hostname == "all" {
+        "0.0.0.0"
+    } else {
+        cmd.hostname.as_str()
+    };
+    let listener = tokio::net::TcpListener::bind((host, cmd.port)).await?;
+    eprintln!("Listening on {}", listener.local_addr()?);
+    axum::serve(listener, app)
+        .with_graceful_shutdown(shutdown_signal())
+        .await?;
+
+    Ok(Response::success_message("Server stopped."))
+}
+
+/// Create the services shared across requests
+fn server_state(
+    ctx: &AppContext,
+    session: Option<String>,
+    disable_origin_protection: bool,
+) -> SharedState {
+    let account_manager = Arc::new(AccountManager::new(ctx.storage()));
+    let sdk = Arc::new(ctx.sdk().clone());
+
+    let vault_service = VaultService::new(
+        ctx.api_client(),
+        ctx.storage(),
+        Arc::clone(&sdk),
+        Arc::clone(&account_manager),
+    );
+
+    // Requests can't answer interactive prompts, so confirmations are skipped
+    let write_service = WriteService::new(
+        ctx.api_client(),
+        ctx.storage(),
+        Arc::new(CipherService::new(sdk)),
+        Arc::new(ValidationService::new()),
+        Arc::new(ConfirmationService::new(true)),
+        account_manager,
+    );
+
+    Arc::new(ServerState {
+        ctx: ctx.clone(),
+        vault_service,
+        write_service,
+        auth_service: AuthService::new(ctx.storage(), ctx.api_client()),
+        session: RwLock::new(session),
+        disable_origin_protection,
+    })
+}
+
+fn router(state: SharedState) -> Router {
+    Router::new()
+        .route("/sync", post(sync))
+        .route("/lock", post(lock))
+        .route("/unlock", post(unlock))
+        .route("/generate", get(generate))
+        .route("/list/object/{object}", get(list_object))
+        .route("/object/{object}", post(create_object))
+        .route(
+            "/object/{object}/{id}",
+            get(get_object).put(edit_object).delete(delete_object),
+        )
+        .layer(middleware::from_fn_with_state(
+            Arc::clone(&state),
+            origin_protection,
+        ))
+        .with_state(state)
+}
+
+async fn shutdown_signal() {
+    let _ = tokio::signal::ctrl_c().await;
+}
+
+/// Reject browser requests unless origin protection is disabled
+///
+/// Browsers always send an Origin header on cross-site requests; scripts and
+/// CLI tools don't. This keeps web pages from reaching the unlocked vault.
+async fn origin_protection(
+    State(state): State<SharedState>,
+    request: Request,
+    next: Next,
+) -> axum::response::Response {
+    if !state.disable_origin_protection && request.headers().contains_key(header::ORIGIN) {
+        return reply(
+            StatusCode::FORBIDDEN,
+            Response::error("Origin header is not allowed."),
+        );
+    }
+    next.run(request).await
+}
+
+/// Convert a Response envelope into an HTTP response
+fn reply(status: StatusCode, response: Response) -> axum::response::Response {
+    use axum::response::IntoResponse;
+
+    (status, axum::Json(response)).into_response()
+}
+
+/// Map a Response to HTTP: 200 for success, 400 for errors
+fn respond(response: Response) -> axum::response::Response {
+    if response.is_success() {
+        reply(StatusCode::OK, response)
+    } else {
+        reply(StatusCode::BAD_REQUEST, response)
+    }
+}
+
+/// Map a vault error to a Response, using the same messages as the CLI
+fn vault_error(e: VaultError, id: &str) -> Response {
+    match e {
+        VaultError::ItemNotFound => Response::error(format!("Item not found: {}", id)),
+        VaultError::FolderNotFound => Response::error(format!("Folder not found: {}", id)),
+        e => Response::error(e.to_string()),
+    }
+}
+
+// ========== Auth ==========
+
+#[derive(Deserialize)]
+struct UnlockBody {
+    password: String,
+}
+
+async fn unlock(
+    State(state): State<SharedState>,
+    body: Result<axum::Json<UnlockBody>, JsonRejection>,
+) -> axum::response::Response {
+    // Malformed bodies get the same envelope as every other error
+    let body = match body {
+        Ok(axum::Json(body)) => body,
+        Err(rejection) => return reply(rejection.status(), Response::error(rejection.body_text())),
+    };
+
+    match state.auth_service.unlock(Secret::new(body.password)).await {
+        Ok(result) => {
+            *state.session.write().await = Some(result.session_key.clone());
+            respond(Response::success(serde_json::json!({
+                "title": "Your vault is now unlocked!",
+                "raw": result.session_key,
+            })))
+        }
+        Err(e) => respond(Response::error(e.user_message())),
+    }
+}
+
+async fn lock(State(state): State<SharedState>) -> axum::response::Response {
+    let result = state.auth_service.lock().await;
+    *state.session.write().await = None;
+
+    match result {
+        Ok(()) => respond(Response::success_message("Your vault is locked.")),
+        Err(e) => respond(Response::error(e.user_message())),
+    }
+}
+
+// ========== Sync ==========
+
+#[derive(Deserialize)]
+struct SyncQuery {
+    #[serde(default)]
+    force: bool,
+}
+
+async fn sync(
+    State(state): State<SharedState>,
+    Query(query): Query<SyncQuery>,
+) -> axum::response::Response {
+    match state.vault_service.sync(query.force).await {
+        Ok(timestamp) => respond(Response::success_message(format!(
+            "Syncing complete. Last sync: {}",
+            timestamp
+        ))),
+        Err(e) => respond(Response::error(e.to_string())),
+    }
+}
+
+// ========== Generate ==========
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GenerateQuery {
+    #[serde(default)]
+    passphrase: bool,
+    length: Option<usize>,
+    lowercase: Option<usize>,
+    uppercase: Option<usize>,
+    number: Option<usize>,
+    special: Option<usize>,
+    words: Option<usize>,
+    separator: Option<String>,
+    #[serde(default)]
+    capitalize: bool,
+    #[serde(default)]
+    include_number: bool,
+}
+
+async fn generate(
+    State(state): State<SharedState>,
+    Query(query): Query<GenerateQuery>,
+) -> axum::response::Response {
+    // Options that aren't given keep the saved defaults, as on the command line
+    let cmd = GenerateCommand {
+        options: GeneratorOptionArgs {
+            passphrase: query.passphrase,
+            password: false,
+            length: to_u8(query.length),
+            lowercase: to_u8(query.lowercase),
+            uppercase: to_u8(query.uppercase),
+            number: to_u8(query.number),
+            special: to_u8(query.special),
+            ambiguous: None,
+            words: to_u8(query.words),
+            separator: query.separator,
+            capitalize: query.capitalize.then_some(true),
+            include_number: query.include_number.then_some(true),
+        },
+        username: false,
+        email: None,
+        domain: None,
+        forwarder: None,
+        website: None,
+    };
+
+    // The server's session, so the handler sees the same lock state as other routes
+    let session = state.session.read().await.clone();
+    match execute_generate(cmd, &server_global_args(session), &state.ctx).await {
+        Ok(response) => respond(response),
+        Err(e) => respond(Response::error(e.to_string())),
+    }
+}
+
+/// Convert a query count to a generator option, saturating at `u8::MAX`
+fn to_u8(value: Option<usize>) -> Option<u8> {
+    value.map(|value| u8::try_from(value).unwrap_or(u8::MAX))
+}
+
+/// Global args for reusing CLI handlers from the server
+fn server_global_args(session: Option<String>) -> GlobalArgs {
+    GlobalArgs {
+        session,
+        quiet: false,
+        response: false,
+        raw: false,
+        pretty: false,
+        nointeraction: true,
+        cleanexit: false,
+        // The context is already scoped to the server's account
+        account: None,
+    }
+}
+
+// ========== List ==========
+
+#[derive(Deserialize)]
+struct ListQuery {
+    organizationid: Option<String>,
+    collectionid: Option<String>,
+    folderid: Option<String>,
+    search: Option<String>,
+    url: Option<String>,
+    #[serde(default)]
+    trash: bool,
+}
+
+async fn list_object(
+    State(state): State<SharedState>,
+    Path(object): Path<String>,
+    Query(query): Query<ListQuery>,
+) -> axum::response::Response {
+    if object == "organizations" {
+        return match state.vault_service.list_organizations().await {
+            Ok(orgs) => respond(Response::success(orgs)),
+            Err(e) => respond(Response::error(e.to_string())),
+        };
+    }
+
+    let session = match state.session().await {
+        Ok(session) => session,
+        Err(response) => return respond(response),
+    };
+
+    let response = match object.as_str() {
+        "items" => {
+            let filters = ItemFilters {
+                organization_id: query.organizationid,
+                collection_id: query.collectionid,
+                folder_id: query.folderid,
+                search: query.search,
+                url: query.url,
+                trash: query.trash,
+            };
+            match state.vault_service.list_items(&filters, &session).await {
+                Ok(items) => Response::success(items),
+                Err(e) => Response::error(e.to_string()),
+            }
+        }
+        "folders" => match state
+            .vault_service
+            .list_folders(query.search.as_deref(), &session)
+            .await
+        {
+            Ok(folders) => Response::success(folders),
+            Err(e) => Response::error(e.to_string()),
+        },
+        "collections" => match state
+            .vault_service
+            .list_collections(
+                query.organizationid.as_deref(),
+                query.search.as_deref(),
+                &session,
+            )
+            .await
+        {
+            Ok(collections) => Response::success(collections),
+            Err(e) => Response::error(e.to_string()),
+        },
+        other => Response::error(format!("Unknown object: {}", other)),
+    };
+
+    respond(response)
+}
+
+// ========== Get ==========
+
+async fn get_object(
+    State(state): State<SharedState>,
+    Path((object, id)): Path<(String, String)>,
+) -> axum::response::Response {
+    let session = match state.session().await {
+        Ok(session) => session,
+        Err(response) => return respond(response),
+    };
+
+    let vault = &state.vault_service;
+    let response = match object.as_str() {
+        "item" => match vault.get_item(&id, &session).await {
+            Ok(item) => Response::success(item),
+            Err(e) => vault_error(e, &id),
+        },
+        "username" | "password" | "uri" | "notes" => {
+            let field = match object.as_str() {
+                "username" => FieldType::Username,
+                "password" => FieldType::Password,
+                "uri" => FieldType::Uri,
+                _ => FieldType::Notes,
+            };
+            match vault.get_field(&id, field, &session).await {
+                Ok(value) => Response::success(value),
+                Err(e) => vault_error(e, &id),
+            }
+        }
+        "totp" => match vault.get_totp(&id, &session).await {
+            Ok(code) => Response::success(code),
+            Err(e) => vault_error(e, &id),
+        },
+        "folder" => match vault.list_folders(None, &session).await {
+            Ok(folders) => match folders
+                .into_iter()
+                .find(|f| f.id.as_ref().map(|fid| fid.to_string()) == Some(id.clone()))
+            {
+                Some(folder) => Response::success(folder),
+                None => Response::error(format!("Folder not found: {}", id)),
+            },
+            Err(e) => Response::error(e.to_string()),
+        },
+        other => Response::error(format!("Unknown object: {}", other)),
+    };
+
+    respond(response)
+}
+
+// ========== Create ==========
+
+async fn create_object(
+    State(state): State<SharedState>,
+    Path(object): Path<String>,
+    body: String,
+) -> axum::response::Response {
+    let session = match state.session().await {
+        Ok(session) => session,
+        Err(response) => return respond(response),
+    };
+
+    let response = match object.as_str() {
+        "item" => {
+            let cipher_view = match parse_item_input(&body) {
+                Ok(view) => view,
+                Err(e) => return respond(Response::error(format!("Invalid input: {}", e))),
+            };
+            match state
+                .write_service
+                .create_cipher(cipher_view, &session)
+                .await
+            {
+                Ok(created) => {
+                    let id = created.id.map(|id| id.to_string()).unwrap_or_default();
+                    match state.vault_service.get_item(&id, &session).await {
+                        Ok(item) => Response::success(item),
+                        Err(e) => Response::error(e.to_string()),
+                    }
+                }
+                Err(e) => Response::error(e.to_string()),
+            }
+        }
+        "folder" => {
+            let folder_input = match parse_folder_input(&body) {
+                Ok(input) => input,
+                Err(e) => return respond(Response::error(format!("Invalid input: {}", e))),
+            };
+            match state
+                .write_service
+                .create_folder(folder_input.name, &session)
+                .await
+            {
+                Ok(created) => find_folder_response(&state, created.id, &session).await,
+                Err(e) => Response::error(e.to_string()),
+            }
+        }
+        other => Response::error(format!("Unknown object: {}", other)),
+    };
+
+    respond(response)
+}
+
+// ========== Edit ==========
+
+async fn edit_object(
+    State(state): State<SharedState>,
+    Path((object, id)): Path<(String, String)>,
+    body: String,
+) -> axum::response::Response {
+    let session = match state.session().await {
+        Ok(session) => session,
+        Err(response) => return respond(response),
+    };
+
+    let response = match object.as_str() {
+        "item" => {
+            let existing = match state.vault_service.get_item(&id, &session).await {
+                Ok(item) => item,
+                Err(e) => return respond(vault_error(e, &id)),
+            };
+            if existing.deleted_date.is_some() {
+                return respond(Response::error(
+                    "Cannot edit items in trash. Restore the item first.",
+                ));
+            }
+            let updates = match parse_item_input(&body) {
+                Ok(view) => view,
+                Err(e) => return respond(Response::error(format!("Invalid input: {}", e))),
+            };
+            let merged = merge_cipher_views(existing, updates);
+
+            match state
+                .write_service
+                .update_cipher(&id, merged, &session)
+                .await
+            {
+                Ok(_) => match state.vault_service.get_item(&id, &session).await {
+                    Ok(item) => Response::success(item),
+                    Err(e) => Response::error(e.to_string()),
+                },
+                Err(e) => vault_error(e, &id),
+            }
+        }
+        "folder" => {
+            let folder_input = match parse_folder_input(&body) {
+                Ok(input) => input,
+                Err(e) => return respond(Response::error(format!("Invalid input: {}", e))),
+            };
+            match state
+                .write_service
+                .update_folder(&id, folder_input.name, &session)
+                .await
+            {
+                Ok(updated) => find_folder_response(&state, updated.id, &session).await,
+                Err(e) => vault_error(e, &id),
+            }
+        }
+        other => Response::error(format!("Unknown object: {}", other)),
+    };
+
+    respond(response)
+}
+
+/// Look up a decrypted folder by ID after a write
+async fn find_folder_response(
+    state: &ServerState,
+    folder_id: Option<bw_core::models::vault::FolderId>,
+    session: &str,
+) -> Response {
+    match state.vault_service.list_folders(None, session).await {
+        Ok(folders) => match folders.into_iter().find(|f| f.id == folder_id) {
+            Some(folder) => Response::success(folder),
+            None => Response::error("Folder saved but not found in cache"),
+        },
+        Err(e) => Response::error(e.to_string()),
+    }
+}
+
+// ========== Delete ==========
+
+#[derive(Deserialize)]
+struct DeleteQuery {
+    #[serde(default)]
+    permanent: bool,
+}
+
+async fn delete_object(
+    State(state): State<SharedState>,
+    Path((object, id)): Path<(String, String)>,
+    Query(query): Query<DeleteQuery>,
+) -> axum::response::Response {
+    if let Err(response) = state.session().await {
+        return respond(response);
+    }
+
+    let response = match object.as_str() {
+        "item" => match state
+            .write_service
+            .delete_cipher(&id, query.permanent, true)
+            .await
+        {
+            Ok(()) if query.permanent => Response::success_message("Item permanently deleted"),
+            Ok(()) => Response::success_message("Item moved to trash"),
+            Err(e) => vault_error(e, &id),
+        },
+        "folder" => match state.write_service.delete_folder(&id).await {
+            Ok(()) => Response::success_message("Folder deleted"),
+            Err(e) => vault_error(e, &id),
+        },
+        other => Response::error(format!("Unknown object: {}", other)),
+    };
+
+    respond(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+    use bitwarden_crypto::{Kdf, MasterKey};
+    use serde_json::{Value, json};
+    use std::num::NonZeroU32;
+    use tower::ServiceExt;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    /// Test credentials - these are only used in tests, not real credentials
+    const TEST_EMAIL: &str = "test@example.com";
+    const TEST_PASSWORD: &str = "test_password";
+    const TEST_KDF_ITERATIONS: u32 = 600000;
+
+    /// Context backed by temp storage, calling the API at `base_url`
+    fn test_context(base_url: &str) -> (AppContext, tempfile::TempDir) {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let ctx = AppContext::with_config(
+            Some(base_url.to_string()),
+            None,
+            Some(temp_dir.path().to_path_buf()),
+            None,
+        )
+        .unwrap();
+        (ctx, temp_dir)
+    }
+
+    /// Mock the endpoints a password login calls
+    async fn setup_login_mocks(mock_server: &MockServer) {
+        let kdf = Kdf::PBKDF2 {
+            iterations: NonZeroU32::new(TEST_KDF_ITERATIONS).unwrap(),
+        };
+        let master_key = MasterKey::derive(TEST_PASSWORD, TEST_EMAIL, &kdf).unwrap();
+        let (_user_key, encrypted_user_key) = master_key.make_user_key().unwrap();
+
+        Mock::given(method("POST"))
+            .and(path("/identity/accounts/prelogin"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "kdf": 0,
+                "kdfIterations": TEST_KDF_ITERATIONS,
+            })))
+            .mount(mock_server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/identity/connect/token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "access_token": "test_access_token",
+                "expires_in": 3600,
+                "token_type": "Bearer",
+                "refresh_token": "test_refresh_token",
+                "Key": encrypted_user_key.to_string(),
+                "Kdf": 0,
+                "KdfIterations": TEST_KDF_ITERATIONS,
+                "ResetMasterPassword": false,
+            })))
+            .mount(mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/accounts/profile"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "id": "user_id_123",
+                "name": "Test User",
+                "email": TEST_EMAIL,
+                "emailVerified": true,
+                "premium": false,
+                "securityStamp": "security_stamp_123",
+            })))
+            .mount(mock_server)
+            .await;
+    }
+
+    /// Send a request through the router and parse the envelope
+    async fn send(state: &SharedState, request: axum::http::Request<Body>) -> (StatusCode, Value) {
+        let response = router(Arc::clone(state)).oneshot(request).await.unwrap();
+        let status = response.status();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    fn get(uri: &str) -> axum::http::Request<Body> {
+        axum::http::Request::get(uri).body(Body::empty()).unwrap()
+    }
+
+    fn post_json(uri: &str, body: &str) -> axum::http::Request<Body> {
+        axum::http::Request::post(uri)
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_rejects_foreign_origin() {
+        let (ctx, _temp_dir) = test_context("http://localhost");
+        let state = server_state(&ctx, Some("session".to_string()), false);
+
+        let request = axum::http::Request::get("/list/object/items")
+            .header(header::ORIGIN, "https://evil.example")
+            .body(Body::empty())
+            .unwrap();
+        let (status, body) = send(&state, request).await;
+
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        assert_eq!(body["success"], false);
+        assert_eq!(body["message"], "Origin header is not allowed.");
+    }
+
+    #[tokio::test]
+    async fn test_list_items_while_locked() {
+        let (ctx, _temp_dir) = test_context("http://localhost");
+        let state = server_state(&ctx, None, false);
+
+        let (status, body) = send(&state, get("/list/object/items")).await;
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["success"], false);
+        assert_eq!(body["message"], "Vault is locked.");
+    }
+
+    #[tokio::test]
+    async fn test_unlock_rejects_malformed_body_with_envelope() {
+        let (ctx, _temp_dir) = test_context("http://localhost");
+        let state = server_state(&ctx, None, false);
+
+        let (status, body) = send(&state, post_json("/unlock", r#"{"pass": 1}"#)).await;
+
+        assert!(status.is_client_error());
+        assert_eq!(body["success"], false);
+        assert!(body["message"].is_string());
+    }
+
+    #[tokio::test]
+    async fn test_unlock_then_lock_round_trip() {
+        let mock_server = MockServer::start().await;
+        setup_login_mocks(&mock_server).await;
+
+        let (ctx, _temp_dir) = test_context(&mock_server.uri());
+        AuthService::new(ctx.storage(), ctx.api_client())
+            .login_with_password(
+                TEST_EMAIL,
+                Secret::new(TEST_PASSWORD.to_string()),
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+        let state = server_state(&ctx, None, false);
+
+        // 1. Unlocking keeps the session for later requests
+        let body = json!({ "password": TEST_PASSWORD }).to_string();
+        let (status, body) = send(&state, post_json("/unlock", &body)).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["success"], true);
+        let session = body["data"]["raw"].as_str().unwrap().to_string();
+        assert_eq!(
+            state.session.read().await.as_deref(),
+            Some(session.as_str())
+        );
+
+        // 2. Locking drops it again
+        let (status, body) = send(&state, post_json("/lock", "")).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["message"], "Your vault is locked.");
+        assert!(state.session.read().await.is_none());
+
+        let (status, body) = send(&state, get("/list/object/items")).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["message"], "Vault is locked.");
+    }
+}
@@ -1,6 +1,7 @@
 use crate::AppContext;
 use crate::GlobalArgs;
 use crate::output::Response;
+use bw_core::services::api::EnvironmentService;
 use bw_core::services::storage::{AccountManager, Storage, StorageKey};
 use bw_core::services::vault::VaultService;
 use clap::Args;
@@ -90,10 +91,15 @@ pub async fn execute_status(
         }
     };
 
-    // Server URL - null for default cloud, otherwise the custom URL
-    // For now, we return null since we're using default cloud
-    // TODO: Read server URL from user environment settings if available
-    let server_url: Option<String> = None;
+    // Server URL - null for default cloud, otherwise the configured URL
+    let environment_service = EnvironmentService::new(
+        Arc::clone(&storage),
+        Arc::new(AccountManager::new(Arc::clone(&storage))),
+    );
+    let server_url = environment_service
+        .get_urls()
+        .await?
+        .and_then(|urls| urls.server_url().map(str::to_string));
 
     let status_data = StatusData {
         server_url,
@@ -10,7 +10,7 @@ use thiserror::Error;
 #[derive(Error, Debug)]
 pub enum TemplateError {
     #[error(
-        "Unknown template type: {0}. Valid types: item, item.login, item.secureNote, item.card, item.identity, folder, item.field, item.login.uri"
+        "Unknown template type: {0}. Valid types: item, item.login, item.secureNote, item.card, item.identity, folder, item.field, item.login.uri, org-collection"
     )]
     UnknownTemplate(String),
 }
@@ -28,6 +28,7 @@ pub enum TemplateError {
 /// - `folder` - Folder template
 /// - `item.field` - Custom field template
 /// - `item.login.uri` - Login URI template
+/// - `org-collection` - Organization collection template
 pub fn get_item_template(template_type: &str) -> Result<Value, TemplateError> {
     match template_type.to_lowercase().as_str() {
         "item" | "item.login" => Ok(login_template()),
@@ -37,6 +38,7 @@ pub fn get_item_template(template_type: &str) -> Result<Value, TemplateError> {
         "folder" => Ok(folder_template()),
         "item.field" => Ok(field_template()),
         "item.login.uri" => Ok(uri_template()),
+        "org-collection" => Ok(org_collection_template()),
         _ => Err(TemplateError::UnknownTemplate(template_type.to_string())),
     }
 }
@@ -179,6 +181,24 @@ fn uri_template() -> Value {
     })
 }
 
+/// Organization collection template
+fn org_collection_template() -> Value {
+    json!({
+        "organizationId": "00000000-0000-0000-0000-000000000000",
+        "name": "Collection name",
+        "externalId": null,
+        "groups": [
+            {
+                "id": "00000000-0000-0000-0000-000000000000",
+                "readOnly": false,
+                "hidePasswords": false,
+                "manage": false
+            }
+        ],
+        "users": []
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -235,6 +255,14 @@ mod tests {
         assert!(template["uri"].is_string());
     }
 
+    #[test]
+    fn test_org_collection_template_structure() {
+        let template = get_item_template("org-collection").unwrap();
+        assert!(template["name"].is_string());
+        assert!(template["groups"][0]["readOnly"].is_boolean());
+        assert!(template["users"].is_array());
+    }
+
     #[test]
     fn test_unknown_template() {
         let result = get_item_template("unknown");
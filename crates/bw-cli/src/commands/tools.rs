@@ -1,49 +1,205 @@
 use crate::AppContext;
 use crate::GlobalArgs;
+use crate::commands::auth::input;
 use crate::output::Response;
-use clap::Args;
+use bw_core::services::auth::AuthService;
+use bw_core::services::generator::{
+    ForwarderService, GeneratorOptions, GeneratorService, GeneratorType, UsernameRequest,
+};
+use bw_core::services::import_export::errors::ImportError;
+use bw_core::services::import_export::{
+    ExportData, ExportOptions, ExportService, ImportOptions, ImportService,
+};
+use bw_core::services::storage::AccountManager;
+use bw_core::services::vault::{CipherService, VaultService};
+use bw_core::services::{KeyService, estimate_strength};
+use clap::{Args, Subcommand};
+use secrecy::Secret;
+use std::sync::Arc;
 
 #[derive(Args)]
 pub struct GenerateCommand {
+    #[command(flatten)]
+    pub options: GeneratorOptionArgs,
+
+    /// Generate a username instead of password
+    #[arg(long, conflicts_with_all = ["passphrase", "password"])]
+    pub username: bool,
+
+    /// Email address to plus-address (username)
+    #[arg(long, requires = "username", conflicts_with_all = ["domain", "forwarder"])]
+    pub email: Option<String>,
+
+    /// Catch-all domain (username)
+    #[arg(long, requires = "username", conflicts_with = "forwarder")]
+    pub domain: Option<String>,
+
+    /// Create an alias with an email forwarding service (username):
+    /// simplelogin, addyio, firefox, fastmail, duckduckgo, forwardemail
+    #[arg(long, value_name = "SERVICE", requires = "username")]
+    pub forwarder: Option<ForwarderService>,
+
+    /// Website the username is for (username)
+    #[arg(long, requires = "username")]
+    pub website: Option<String>,
+}
+
+/// Password and passphrase options, shared by `bw generate` and
+/// `bw config generator`
+///
+/// Options that aren't given keep the saved default.
+#[derive(Args)]
+pub struct GeneratorOptionArgs {
     /// Generate a passphrase instead of password
-    #[arg(long)]
+    #[arg(long, conflicts_with = "password")]
     pub passphrase: bool,
 
+    /// Generate a password, even if passphrases are the saved default
+    #[arg(long)]
+    pub password: bool,
+
     /// Password length (default: 16)
     #[arg(long)]
-    pub length: Option<usize>,
+    pub length: Option<u8>,
 
-    /// Minimum number of lowercase characters
+    /// Minimum number of lowercase characters (0 disables them)
     #[arg(long)]
-    pub lowercase: Option<usize>,
+    pub lowercase: Option<u8>,
 
-    /// Minimum number of uppercase characters
+    /// Minimum number of uppercase characters (0 disables them)
     #[arg(long)]
-    pub uppercase: Option<usize>,
+    pub uppercase: Option<u8>,
 
-    /// Minimum number of numeric characters
+    /// Minimum number of numeric characters (0 disables them)
     #[arg(long)]
-    pub number: Option<usize>,
+    pub number: Option<u8>,
 
-    /// Minimum number of special characters
+    /// Minimum number of special characters (0 disables them)
     #[arg(long)]
-    pub special: Option<usize>,
+    pub special: Option<u8>,
+
+    /// Avoid ambiguous characters such as 0/O and 1/l
+    #[arg(long, value_name = "BOOL", num_args = 0..=1, default_missing_value = "true")]
+    pub ambiguous: Option<bool>,
 
     /// Number of passphrase words (default: 3)
     #[arg(long)]
-    pub words: Option<usize>,
+    pub words: Option<u8>,
 
     /// Passphrase word separator (default: -)
     #[arg(long)]
     pub separator: Option<String>,
 
-    /// Capitalize passphrase words
+    /// Capitalize passphrase or username words
+    #[arg(long, value_name = "BOOL", num_args = 0..=1, default_missing_value = "true")]
+    pub capitalize: Option<bool>,
+
+    /// Include number in passphrase or username
+    #[arg(
+        long,
+        alias = "includeNumber",
+        value_name = "BOOL",
+        num_args = 0..=1,
+        default_missing_value = "true"
+    )]
+    pub include_number: Option<bool>,
+
+    /// Wordlist file for passphrases, one word per line or in diceware
+    /// format ("eff" for the built-in list)
+    #[arg(long, value_name = "FILE")]
+    pub wordlist: Option<String>,
+
+    /// Minimum passphrase entropy in bits (default: 38)
+    #[arg(long, value_name = "BITS")]
+    pub entropy: Option<f64>,
+}
+
+impl GeneratorOptionArgs {
+    /// Apply the given options on top of `options`
+    pub fn apply(&self, options: &mut GeneratorOptions) {
+        if self.passphrase {
+            options.generator_type = GeneratorType::Passphrase;
+        } else if self.password {
+            options.generator_type = GeneratorType::Password;
+        }
+
+        if let Some(length) = self.length {
+            options.length = length;
+        }
+
+        // A minimum of 0 disables the character set; any other value enables
+        // it with that minimum
+        let character_sets = [
+            (
+                self.lowercase,
+                &mut options.lowercase,
+                &mut options.min_lowercase,
+            ),
+            (
+                self.uppercase,
+                &mut options.uppercase,
+                &mut options.min_uppercase,
+            ),
+            (self.number, &mut options.numbers, &mut options.min_number),
+            (self.special, &mut options.special, &mut options.min_special),
+        ];
+        for (arg, enabled, minimum) in character_sets {
+            if let Some(count) = arg {
+                *enabled = count > 0;
+                *minimum = Some(count).filter(|&c| c > 0);
+            }
+        }
+
+        if let Some(ambiguous) = self.ambiguous {
+            options.avoid_ambiguous = ambiguous;
+        }
+        if let Some(words) = self.words {
+            options.num_words = words;
+        }
+        if let Some(separator) = &self.separator {
+            options.word_separator = separator.clone();
+        }
+        if let Some(capitalize) = self.capitalize {
+            options.capitalize = capitalize;
+        }
+        if let Some(include_number) = self.include_number {
+            options.include_number = include_number;
+        }
+
+        // Saved wordlist paths must not depend on the working directory
+        if let Some(wordlist) = &self.wordlist {
+            options.wordlist = if wordlist.eq_ignore_ascii_case("eff") {
+                None
+            } else {
+                let path = std::path::absolute(wordlist).unwrap_or_else(|_| wordlist.into());
+                Some(path.to_string_lossy().into_owned())
+            };
+        }
+        if let Some(entropy) = self.entropy {
+            options.min_entropy = Some(entropy);
+        }
+    }
+}
+
+#[derive(Subcommand)]
+pub enum ToolsCommands {
+    /// Estimate the strength of a password
+    Strength(StrengthCommand),
+}
+
+#[derive(Args)]
+pub struct StrengthCommand {
+    /// Password to check (read from stdin when omitted)
+    #[arg(value_name = "PASSWORD")]
+    pub password: Option<String>,
+
+    /// Email address to penalize (default: the logged in account's email)
     #[arg(long)]
-    pub capitalize: bool,
+    pub email: Option<String>,
 
-    /// Include number in passphrase
-    #[arg(long, alias = "includeNumber")]
-    pub include_number: bool,
+    /// Item name to penalize
+    #[arg(long)]
+    pub name: Option<String>,
 }
 
 #[derive(Args)]
@@ -53,9 +209,20 @@ pub struct EncodeCommand {
     pub data: String,
 }
 
+#[derive(Args)]
+pub struct EncryptCommand {
+    /// Plaintext to encrypt
+    #[arg(value_name = "DATA")]
+    pub data: String,
+
+    /// Organization ID (encrypt with the organization key)
+    #[arg(long)]
+    pub organizationid: Option<String>,
+}
+
 #[derive(Args)]
 pub struct DecryptCommand {
-    /// Encrypted string to decrypt
+    /// Encrypted string to decrypt; RSA EncStrings use your private key
     #[arg(value_name = "ENCRYPTED")]
     pub encrypted: String,
 
@@ -77,15 +244,24 @@ pub struct ImportCommand {
     /// Organization ID (import to org)
     #[arg(long)]
     pub organizationid: Option<String>,
+
+    /// File password for a password-protected export
+    #[arg(long)]
+    pub password: Option<String>,
 }
 
 #[derive(Args)]
 pub struct ExportCommand {
-    /// Export format (json, csv, encrypted_json)
+    /// Master password, required for plaintext exports (prompted if omitted)
+    #[arg(value_name = "PASSWORD")]
+    pub master_password: Option<String>,
+
+    /// Export format (json, csv, encrypted_json; default: csv)
     #[arg(long)]
     pub format: Option<String>,
 
-    /// Master password (required for encrypted export)
+    /// File password for a password-protected encrypted_json export
+    /// (without it, encrypted_json exports are restricted to this account)
     #[arg(long)]
     pub password: Option<String>,
 
@@ -93,104 +269,158 @@ pub struct ExportCommand {
     #[arg(long)]
     pub organizationid: Option<String>,
 
-    /// Output file path
+    /// Output file or directory path (default: stdout)
     #[arg(long)]
     pub output: Option<String>,
 }
 
+// Helper to create generator service
+pub(crate) fn create_generator_service(ctx: &AppContext) -> GeneratorService {
+    let account_manager = Arc::new(AccountManager::new(ctx.storage()));
+
+    GeneratorService::new(ctx.storage(), account_manager)
+}
+
 pub async fn execute_generate(
     cmd: GenerateCommand,
     global_args: &GlobalArgs,
-    _ctx: &AppContext,
+    ctx: &AppContext,
 ) -> anyhow::Result<Response> {
-    use bitwarden_core::Client;
-    use bitwarden_generators::{
-        GeneratorClientsExt, PassphraseError, PassphraseGeneratorRequest, PasswordError,
-        PasswordGeneratorRequest,
-    };
-
-    // RNG Note: The SDK uses rand::thread_rng() which is a ChaCha12 CSPRNG
-    // seeded from OsRng. This is cryptographically secure and equivalent
-    // to our previous direct OsRng usage. The thread-local design provides
-    // better performance for repeated calls while maintaining security.
-
-    // Create a minimal SDK client for generator operations
-    let client = Client::new(None);
-    let generator = client.generator();
-
-    if cmd.passphrase {
-        // Generate passphrase using SDK
-        let request = PassphraseGeneratorRequest {
-            num_words: cmd.words.unwrap_or(3) as u8,
-            word_separator: cmd.separator.unwrap_or_else(|| "-".to_string()),
-            capitalize: cmd.capitalize,
-            include_number: cmd.include_number,
-        };
+    if cmd.username {
+        return generate_username(cmd, global_args, ctx).await;
+    }
 
-        let result = generator.passphrase(request).map_err(|e| match e {
-            PassphraseError::InvalidNumWords { minimum, maximum } => {
-                anyhow::anyhow!(
-                    "Invalid word count. Number of words must be between {} and {}",
-                    minimum,
-                    maximum
-                )
-            }
-        })?;
+    // 1. Saved defaults, overridden by the options given
+    let generator_service = create_generator_service(ctx);
+    let mut options = match generator_service.get_options().await {
+        Ok(options) => options,
+        Err(e) => return Ok(Response::error(e.to_string())),
+    };
+    cmd.options.apply(&mut options);
+
+    // 2. Generate, reporting the entropy of passphrases
+    let (result, entropy) = match options.generator_type {
+        GeneratorType::Password => match generator_service.generate_password(&options) {
+            Ok(password) => (password, None),
+            Err(e) => return Err(anyhow::anyhow!(e)),
+        },
+        GeneratorType::Passphrase => match generator_service.generate_passphrase(&options) {
+            Ok(generated) => (generated.passphrase, Some(generated.entropy)),
+            Err(e) => return Err(anyhow::anyhow!(e)),
+        },
+    };
 
-        if global_args.response {
-            Ok(Response::success_json(serde_json::json!({
-                "data": result
-            })))
-        } else {
-            Ok(Response::success_raw(result))
+    if global_args.response {
+        let mut data = serde_json::json!({
+            "data": result,
+            "strength": estimate_strength(&result, None, &[]),
+        });
+        if let Some(entropy) = entropy {
+            data["entropy"] = serde_json::json!((entropy * 100.0).round() / 100.0);
         }
+        Ok(Response::success_json(data))
     } else {
-        // Generate password using SDK
-        //
-        // Character set logic:
-        // - If minimum is explicitly set to 0, disable that character set
-        // - Otherwise, enable the character set with the specified minimum
-        // - Default behavior: all character sets enabled with special chars included
-        //   (preserves backward compatibility with current CLI behavior)
-        let lowercase_enabled = cmd.lowercase != Some(0);
-        let uppercase_enabled = cmd.uppercase != Some(0);
-        let numbers_enabled = cmd.number != Some(0);
-        let special_enabled = cmd.special != Some(0);
-
-        let request = PasswordGeneratorRequest {
-            length: cmd.length.unwrap_or(16) as u8,
-            lowercase: lowercase_enabled,
-            uppercase: uppercase_enabled,
-            numbers: numbers_enabled,
-            special: special_enabled,
-            avoid_ambiguous: false,
-            min_lowercase: cmd.lowercase.filter(|&v| v > 0).map(|v| v as u8),
-            min_uppercase: cmd.uppercase.filter(|&v| v > 0).map(|v| v as u8),
-            min_number: cmd.number.filter(|&v| v > 0).map(|v| v as u8),
-            min_special: cmd.special.filter(|&v| v > 0).map(|v| v as u8),
-        };
+        Ok(Response::success_raw(result))
+    }
+}
 
-        let result = generator.password(request).map_err(|e| match e {
-            PasswordError::NoCharacterSetEnabled => {
-                anyhow::anyhow!(
-                    "No character sets enabled. Enable at least one of: lowercase, uppercase, numbers, or special characters"
-                )
-            }
-            PasswordError::InvalidLength => {
-                anyhow::anyhow!(
-                    "Invalid password length. Length must be at least 4 and greater than the sum of minimum character requirements"
-                )
+async fn generate_username(
+    cmd: GenerateCommand,
+    global_args: &GlobalArgs,
+    ctx: &AppContext,
+) -> anyhow::Result<Response> {
+    // The username type follows from the options given, defaulting to a word
+    let request = if let Some(service) = cmd.forwarder {
+        UsernameRequest::Forwarded {
+            service,
+            website: cmd.website,
+        }
+    } else if let Some(email) = cmd.email {
+        UsernameRequest::Subaddress {
+            email,
+            website: cmd.website,
+        }
+    } else if let Some(domain) = cmd.domain {
+        UsernameRequest::Catchall {
+            domain,
+            website: cmd.website,
+        }
+    } else {
+        UsernameRequest::Word {
+            capitalize: cmd.options.capitalize.unwrap_or_default(),
+            include_number: cmd.options.include_number.unwrap_or_default(),
+        }
+    };
+
+    let generator_service = create_generator_service(ctx);
+    match generator_service
+        .generate_username(request, global_args.session.as_deref())
+        .await
+    {
+        Ok(username) => {
+            if global_args.response {
+                Ok(Response::success_json(serde_json::json!({
+                    "data": username
+                })))
+            } else {
+                Ok(Response::success_raw(username))
             }
-        })?;
+        }
+        Err(e) => Ok(Response::error(e.to_string())),
+    }
+}
 
-        if global_args.response {
-            Ok(Response::success_json(serde_json::json!({
-                "data": result
-            })))
-        } else {
-            Ok(Response::success_raw(result))
+pub async fn execute_tools(
+    cmd: ToolsCommands,
+    global_args: &GlobalArgs,
+    ctx: &AppContext,
+) -> anyhow::Result<Response> {
+    match cmd {
+        ToolsCommands::Strength(strength_cmd) => {
+            execute_strength(strength_cmd, global_args, ctx).await
+        }
+    }
+}
+
+async fn execute_strength(
+    cmd: StrengthCommand,
+    _global_args: &GlobalArgs,
+    ctx: &AppContext,
+) -> anyhow::Result<Response> {
+    // 1. Password from the argument or the first line of stdin
+    let password = match cmd.password {
+        Some(password) => password,
+        None => {
+            let mut line = String::new();
+            std::io::stdin().read_line(&mut line)?;
+            line.trim_end_matches(['\r', '\n']).to_string()
         }
+    };
+    if password.is_empty() {
+        return Ok(Response::error("A password is required"));
     }
+
+    // 2. Penalize the logged in account's email unless one is given
+    let email = match cmd.email {
+        Some(email) => Some(email),
+        None => {
+            let account_manager = AccountManager::new(ctx.storage());
+            match account_manager.get_active_user_id().await? {
+                Some(user_id) => account_manager
+                    .get_account(&user_id)
+                    .await?
+                    .map(|account| account.email),
+                None => None,
+            }
+        }
+    };
+
+    let inputs: Vec<&str> = cmd.name.as_deref().into_iter().collect();
+    Ok(Response::success(estimate_strength(
+        &password,
+        email.as_deref(),
+        &inputs,
+    )))
 }
 
 pub async fn execute_encode(
@@ -211,26 +441,278 @@ pub async fn execute_encode(
     }
 }
 
+pub async fn execute_encrypt(
+    cmd: EncryptCommand,
+    global_args: &GlobalArgs,
+    ctx: &AppContext,
+) -> anyhow::Result<Response> {
+    let Some(session) = global_args.session.as_deref() else {
+        return Ok(Response::error(
+            "Vault is locked. Run 'bw unlock' and set BW_SESSION environment variable.",
+        ));
+    };
+
+    let account_manager = Arc::new(AccountManager::new(ctx.storage()));
+    let key_service = KeyService::new(ctx.storage(), account_manager);
+
+    // Organization data is encrypted with the organization key
+    let key = match &cmd.organizationid {
+        Some(org_id) => key_service.get_organization_key(session, org_id).await,
+        None => key_service.get_user_key(session).await,
+    };
+    let key = match key {
+        Ok(key) => key,
+        Err(e) => return Ok(Response::error(e.to_string())),
+    };
+
+    let encrypted = match bw_core::services::encrypt_string(&cmd.data, &key) {
+        Ok(encrypted) => encrypted,
+        Err(e) => return Ok(Response::error(format!("Encryption failed: {}", e))),
+    };
+
+    if global_args.response {
+        Ok(Response::success_json(serde_json::json!({
+            "data": encrypted
+        })))
+    } else {
+        Ok(Response::success_raw(encrypted))
+    }
+}
+
 pub async fn execute_decrypt(
-    _cmd: DecryptCommand,
-    _global_args: &GlobalArgs,
-    _ctx: &AppContext,
+    cmd: DecryptCommand,
+    global_args: &GlobalArgs,
+    ctx: &AppContext,
 ) -> anyhow::Result<Response> {
-    Ok(Response::error("Not yet implemented"))
+    let Some(session) = global_args.session.as_deref() else {
+        return Ok(Response::error(
+            "Vault is locked. Run 'bw unlock' and set BW_SESSION environment variable.",
+        ));
+    };
+
+    let account_manager = Arc::new(AccountManager::new(ctx.storage()));
+    let key_service = KeyService::new(ctx.storage(), account_manager);
+
+    // Organization data is encrypted with the organization key
+    let key = match &cmd.organizationid {
+        Some(org_id) => key_service.get_organization_key(session, org_id).await,
+        None => key_service.get_user_key(session).await,
+    };
+    let key = match key {
+        Ok(key) => key,
+        Err(e) => return Ok(Response::error(e.to_string())),
+    };
+
+    // RSA EncStrings need the private key, which is only available after a sync
+    let encrypted = cmd.encrypted.trim();
+    let private_key = if bw_core::services::is_rsa_enc_string(encrypted) {
+        match key_service.get_private_key(session).await {
+            Ok(private_key) => Some(private_key),
+            Err(e) => return Ok(Response::error(e.to_string())),
+        }
+    } else {
+        None
+    };
+
+    let decrypted = match bw_core::services::decrypt_string(encrypted, &key, private_key.as_ref()) {
+        Ok(decrypted) => decrypted,
+        Err(e) => return Ok(Response::error(format!("Decryption failed: {}", e))),
+    };
+
+    if global_args.response {
+        Ok(Response::success_json(serde_json::json!({
+            "data": decrypted
+        })))
+    } else {
+        Ok(Response::success_raw(decrypted))
+    }
 }
 
 pub async fn execute_import(
-    _cmd: ImportCommand,
-    _global_args: &GlobalArgs,
-    _ctx: &AppContext,
+    cmd: ImportCommand,
+    global_args: &GlobalArgs,
+    ctx: &AppContext,
 ) -> anyhow::Result<Response> {
-    Ok(Response::error("Not yet implemented"))
+    // Items are encrypted before upload, so the vault must be unlocked
+    let Some(session) = global_args.session.as_deref() else {
+        return Ok(Response::error(
+            "Vault is locked. Run 'bw unlock' and set BW_SESSION environment variable.",
+        ));
+    };
+
+    let cipher_service = Arc::new(CipherService::new(Arc::new(ctx.sdk().clone())));
+    let import_service = ImportService::with_vault(ctx.api_client(), ctx.storage(), cipher_service);
+
+    // The user key verifies account-restricted exports
+    let account_manager = Arc::new(AccountManager::new(ctx.storage()));
+    let key_service = KeyService::new(ctx.storage(), account_manager);
+    let user_key = key_service.get_user_key(session).await.ok();
+
+    // Folders become collections encrypted with the organization key
+    let organization_key = match &cmd.organizationid {
+        Some(org_id) => match key_service.get_organization_key(session, org_id).await {
+            Ok(key) => Some(key),
+            Err(e) => return Ok(Response::error(e.to_string())),
+        },
+        None => None,
+    };
+
+    let containers = if cmd.organizationid.is_some() {
+        "collections"
+    } else {
+        "folders"
+    };
+    let mut options = ImportOptions {
+        password: cmd.password.map(Secret::new),
+        organization_id: cmd.organizationid,
+        user_key,
+        organization_key,
+    };
+
+    let mut result = import_service
+        .import(&cmd.format, &cmd.file, options.clone())
+        .await;
+
+    // Prompt for the file password if the export turned out to be password protected
+    if matches!(result, Err(ImportError::PasswordRequired)) && !global_args.nointeraction {
+        options.password = Some(input::require_password(
+            None,
+            global_args,
+            Some("File password"),
+        )?);
+        result = import_service.import(&cmd.format, &cmd.file, options).await;
+    }
+
+    match result {
+        Ok(result) => Ok(Response::success_message(format!(
+            "Imported {} items and {} {}.",
+            result.items_created, result.folders_created, containers
+        ))),
+        Err(e) => Ok(Response::error(e.to_string())),
+    }
 }
 
 pub async fn execute_export(
-    _cmd: ExportCommand,
-    _global_args: &GlobalArgs,
-    _ctx: &AppContext,
+    cmd: ExportCommand,
+    global_args: &GlobalArgs,
+    ctx: &AppContext,
 ) -> anyhow::Result<Response> {
-    Ok(Response::error("Not yet implemented"))
+    // 1. Items are decrypted for export, so the vault must be unlocked
+    let Some(session) = global_args.session.as_deref() else {
+        return Ok(Response::error(
+            "Vault is locked. Run 'bw unlock' and set BW_SESSION environment variable.",
+        ));
+    };
+
+    let format = cmd.format.as_deref().unwrap_or("csv");
+    let organization_id = cmd.organizationid.as_deref();
+    let auth_service = AuthService::new(ctx.storage(), ctx.api_client());
+
+    // 2. Plaintext exports require re-entering the master password
+    if format != "encrypted_json" {
+        let password =
+            input::require_password(cmd.master_password, global_args, Some("Master password"))?;
+        if let Err(e) = auth_service.verify_master_password(&password).await {
+            return Ok(Response::error(e.to_string()));
+        }
+    }
+
+    // 3. Gather vault data in scope
+    let vault_service = VaultService::new(
+        ctx.api_client(),
+        ctx.storage(),
+        Arc::new(ctx.sdk().clone()),
+        Arc::new(AccountManager::new(ctx.storage())),
+    );
+
+    let account_restricted = format == "encrypted_json" && cmd.password.is_none();
+
+    let mut options = ExportOptions {
+        password: cmd.password.map(Secret::new),
+        organization_id: cmd.organizationid.clone(),
+        ..Default::default()
+    };
+
+    let data = if account_restricted {
+        // Account-restricted exports keep items encrypted with the user key
+        let key_service =
+            KeyService::new(ctx.storage(), Arc::clone(auth_service.account_manager()));
+        match key_service.get_user_key(session).await {
+            Ok(user_key) => options.user_key = Some(user_key),
+            Err(e) => return Ok(Response::error(e.to_string())),
+        }
+
+        let encrypted_ciphers = match vault_service.list_encrypted_items().await {
+            Ok(ciphers) => ciphers,
+            Err(e) => return Ok(Response::error(e.to_string())),
+        };
+        let encrypted_folders = match vault_service.list_encrypted_folders().await {
+            Ok(folders) => folders,
+            Err(e) => return Ok(Response::error(e.to_string())),
+        };
+
+        ExportData {
+            folders: vec![],
+            ciphers: vec![],
+            collections: vec![],
+            encrypted_folders,
+            encrypted_ciphers,
+        }
+    } else {
+        let ciphers = match vault_service
+            .list_item_views(organization_id, session)
+            .await
+        {
+            Ok(ciphers) => ciphers,
+            Err(e) => return Ok(Response::error(e.to_string())),
+        };
+
+        // Organization exports group by collection, personal exports by folder
+        let (folders, collections) = match organization_id {
+            Some(org_id) => match vault_service
+                .list_collections(Some(org_id), None, session)
+                .await
+            {
+                Ok(collections) => (vec![], collections),
+                Err(e) => return Ok(Response::error(e.to_string())),
+            },
+            None => match vault_service.list_folders(None, session).await {
+                Ok(folders) => (folders, vec![]),
+                Err(e) => return Ok(Response::error(e.to_string())),
+            },
+        };
+
+        // Password-protected exports derive the file key with the account's KDF settings
+        if options.password.is_some() {
+            options.kdf = auth_service.get_kdf_config().await.ok();
+        }
+
+        ExportData {
+            folders,
+            ciphers,
+            collections,
+            encrypted_folders: vec![],
+            encrypted_ciphers: vec![],
+        }
+    };
+
+    // 4. Write the export
+    let result = match ExportService::new()
+        .export(format, cmd.output.as_deref(), data, options)
+        .await
+    {
+        Ok(result) => result,
+        Err(e) => return Ok(Response::error(e.to_string())),
+    };
+
+    match result.output_path {
+        Some(path) => Ok(Response::success(serde_json::json!({
+            "format": result.format,
+            "itemCount": result.item_count,
+            "encrypted": result.encrypted,
+            "outputPath": path,
+        }))),
+        // Export contents were already written to stdout
+        None => Ok(Response::none()),
+    }
 }
@@ -1,15 +1,22 @@
 use crate::AppContext;
 use crate::GlobalArgs;
-use crate::commands::input::{parse_folder_input, parse_item_input};
+use crate::commands::input::{
+    parse_collection_ids_input, parse_folder_input, parse_item_input, parse_org_collection_input,
+};
+use crate::commands::receive::output_path;
+use crate::commands::report::create_hibp_client;
 use crate::commands::templates::get_item_template;
 use crate::output::Response;
 use bw_core::models::vault::CipherView;
 use bw_core::services::storage::AccountManager;
 use bw_core::services::vault::{
-    CipherService, ConfirmationService, FieldType, ItemFilters, ValidationService, VaultError,
-    VaultService, WriteService,
+    CipherService, ConfirmationService, DownloadedAttachment, FieldType, ItemFilters,
+    MAX_ATTACHMENT_SIZE, OrganizationService, ValidationService, VaultError, VaultService,
+    WriteService,
 };
 use clap::{Args, Subcommand};
+use std::io::Write;
+use std::path::PathBuf;
 use std::sync::Arc;
 
 #[derive(Subcommand)]
@@ -176,8 +183,9 @@ pub struct GetTemplateCommand {
 
 #[derive(Args)]
 pub struct GetFingerprintCommand {
-    #[arg(value_name = "EMAIL")]
-    pub email: String,
+    /// User ID, or `me` for the current account
+    #[arg(value_name = "ID")]
+    pub id: String,
 }
 
 #[derive(Subcommand)]
@@ -247,6 +255,7 @@ pub struct EditItemCommand {
 pub struct EditItemCollectionsCommand {
     #[arg(value_name = "ID")]
     pub id: String,
+    /// JSON array of collection IDs (base64, raw JSON, or - for stdin)
     #[arg(value_name = "COLLECTION_IDS")]
     pub collection_ids: String,
 }
@@ -326,8 +335,23 @@ pub struct MoveCommand {
     pub folder_id: String,
 }
 
+#[derive(Args)]
+pub struct ShareCommand {
+    #[arg(value_name = "ITEM_ID")]
+    pub item_id: String,
+    #[arg(value_name = "ORGANIZATION_ID")]
+    pub organization_id: String,
+    /// JSON array of collection IDs (base64, raw JSON, or - for stdin)
+    #[arg(value_name = "COLLECTION_IDS")]
+    pub collection_ids: String,
+}
+
 #[derive(Args)]
 pub struct ConfirmCommand {
+    /// Object to confirm
+    #[arg(value_name = "OBJECT", value_parser = ["org-member"])]
+    pub object: String,
+    /// Organization member ID
     #[arg(value_name = "ID")]
     pub id: String,
     #[arg(long, required = true)]
@@ -342,7 +366,7 @@ fn get_session(global_args: &GlobalArgs) -> anyhow::Result<&str> {
 }
 
 // Helper to create vault service
-fn create_vault_service(ctx: &AppContext) -> VaultService {
+pub(crate) fn create_vault_service(ctx: &AppContext) -> VaultService {
     let account_manager = Arc::new(AccountManager::new(ctx.storage()));
 
     VaultService::new(
@@ -353,6 +377,13 @@ fn create_vault_service(ctx: &AppContext) -> VaultService {
     )
 }
 
+// Helper to create organization service
+fn create_organization_service(ctx: &AppContext) -> OrganizationService {
+    let account_manager = Arc::new(AccountManager::new(ctx.storage()));
+
+    OrganizationService::new(ctx.api_client(), ctx.storage(), account_manager)
+}
+
 // Helper to create write service
 fn create_write_service(ctx: &AppContext, no_interaction: bool) -> WriteService {
     let account_manager = Arc::new(AccountManager::new(ctx.storage()));
@@ -374,7 +405,7 @@ fn create_write_service(ctx: &AppContext, no_interaction: bool) -> WriteService
 ///
 /// Strategy: Update fields that are present in updates,
 /// preserve fields that are not specified (null/missing).
-fn merge_cipher_views(existing: CipherView, updates: CipherView) -> CipherView {
+pub(crate) fn merge_cipher_views(existing: CipherView, updates: CipherView) -> CipherView {
     CipherView {
         // ID must match existing
         id: existing.id,
@@ -489,8 +520,27 @@ pub async fn execute_list(
             Err(e) => Ok(Response::error(e.to_string())),
         },
 
-        ListCommands::OrgCollections(_) | ListCommands::OrgMembers(_) => {
-            Ok(Response::error("Not yet implemented"))
+        ListCommands::OrgCollections(org_cmd) => {
+            let session = get_session(global_args)?;
+            let organization_service = create_organization_service(ctx);
+            match organization_service
+                .list_collections(&org_cmd.organizationid, session)
+                .await
+            {
+                Ok(collections) => Ok(Response::success(collections)),
+                Err(e) => Ok(Response::error(e.to_string())),
+            }
+        }
+
+        ListCommands::OrgMembers(org_cmd) => {
+            let organization_service = create_organization_service(ctx);
+            match organization_service
+                .list_members(&org_cmd.organizationid)
+                .await
+            {
+                Ok(members) => Ok(Response::success(members)),
+                Err(e) => Ok(Response::error(e.to_string())),
+            }
         }
     }
 }
@@ -622,6 +672,52 @@ pub async fn execute_get(
             }
         }
 
+        GetCommands::Attachment(attachment_cmd) => {
+            let session = get_session(global_args)?;
+            let attachment = match vault_service
+                .get_attachment(&attachment_cmd.itemid, &attachment_cmd.id, session)
+                .await
+            {
+                Ok(attachment) => attachment,
+                Err(e) => return Ok(Response::error(e.to_string())),
+            };
+
+            match attachment_cmd.output {
+                Some(output) => save_attachment(&output, &attachment),
+                None => {
+                    let mut stdout = std::io::stdout().lock();
+                    stdout.write_all(&attachment.contents)?;
+                    stdout.flush()?;
+                    Ok(Response::none())
+                }
+            }
+        }
+
+        GetCommands::Exposed(exposed_cmd) => {
+            let session = get_session(global_args)?;
+            let password = match vault_service
+                .get_field(&exposed_cmd.id, FieldType::Password, session)
+                .await
+            {
+                Ok(password) => password,
+                Err(e) => return Ok(Response::error(e.to_string())),
+            };
+
+            // Only a prefix of the password's SHA-1 hash is sent
+            let hibp_client = create_hibp_client()?;
+            match hibp_client.breach_count(&password).await {
+                Ok(count) => Ok(Response::success_raw(count.to_string())),
+                Err(e) => Ok(Response::error(e.to_string())),
+            }
+        }
+
+        GetCommands::Fingerprint(fingerprint_cmd) => {
+            match vault_service.get_fingerprint(&fingerprint_cmd.id).await {
+                Ok(phrase) => Ok(Response::success_raw(phrase)),
+                Err(e) => Ok(Response::error(e.to_string())),
+            }
+        }
+
         _ => Ok(Response::error("Not yet implemented")),
     }
 }
@@ -691,8 +787,88 @@ pub async fn execute_create(
             }
         }
 
-        CreateCommands::Attachment(_) | CreateCommands::OrgCollection(_) => {
-            Ok(Response::error("Not yet implemented"))
+        CreateCommands::Attachment(attachment_cmd) => {
+            let session = get_session(global_args)?;
+
+            // 1. Check the size before reading the file into memory
+            let path = PathBuf::from(&attachment_cmd.file);
+            let size = match std::fs::metadata(&path) {
+                Ok(metadata) => metadata.len(),
+                Err(e) => {
+                    return Ok(Response::error(format!(
+                        "Cannot read file {}: {}",
+                        attachment_cmd.file, e
+                    )));
+                }
+            };
+            if size > MAX_ATTACHMENT_SIZE {
+                return Ok(Response::error(
+                    VaultError::AttachmentTooLarge {
+                        size,
+                        max: MAX_ATTACHMENT_SIZE,
+                    }
+                    .to_string(),
+                ));
+            }
+
+            // 2. Read the file
+            let contents = match std::fs::read(&path) {
+                Ok(contents) => contents,
+                Err(e) => {
+                    return Ok(Response::error(format!(
+                        "Cannot read file {}: {}",
+                        attachment_cmd.file, e
+                    )));
+                }
+            };
+            let file_name = path
+                .file_name()
+                .map(|n| n.to_string_lossy().into_owned())
+                .unwrap_or_else(|| attachment_cmd.file.clone());
+
+            // 3. Encrypt and upload via WriteService
+            let write_service = create_write_service(ctx, global_args.nointeraction);
+            match write_service
+                .create_attachment(&attachment_cmd.itemid, &file_name, contents, session)
+                .await
+            {
+                Ok(_) => {
+                    // 4. Return decrypted item with the new attachment
+                    let vault_service = create_vault_service(ctx);
+                    match vault_service
+                        .get_item(&attachment_cmd.itemid, session)
+                        .await
+                    {
+                        Ok(decrypted) => Ok(Response::success(decrypted)),
+                        Err(e) => Ok(Response::error(e.to_string())),
+                    }
+                }
+                Err(VaultError::ItemNotFound) => Ok(Response::error(format!(
+                    "Item not found: {}",
+                    attachment_cmd.itemid
+                ))),
+                Err(e) => Ok(Response::error(e.to_string())),
+            }
+        }
+
+        CreateCommands::OrgCollection(collection_cmd) => {
+            let session = get_session(global_args)?;
+
+            // 1. Parse input (base64/JSON/stdin)
+            let collection_view = match parse_org_collection_input(&collection_cmd.json) {
+                Ok(view) => view,
+                Err(e) => return Ok(Response::error(format!("Invalid input: {}", e))),
+            };
+
+            // 2. Encrypt and create via OrganizationService
+            let organization_service = create_organization_service(ctx);
+            match organization_service
+                .create_collection(&collection_cmd.organizationid, collection_view, session)
+                .await
+            {
+                Ok(created) => Ok(Response::success(created)),
+                Err(e) => Ok(Response::error(e.to_string())),
+            }
         }
     }
 }
@@ -785,8 +961,65 @@ pub async fn execute_edit(
             }
         }
 
-        EditCommands::ItemCollections(_) | EditCommands::OrgCollection(_) => {
-            Ok(Response::error("Not yet implemented"))
+        EditCommands::OrgCollection(collection_cmd) => {
+            let session = get_session(global_args)?;
+
+            // 1. Parse input (base64/JSON/stdin)
+            let collection_view = match parse_org_collection_input(&collection_cmd.json) {
+                Ok(view) => view,
+                Err(e) => return Ok(Response::error(format!("Invalid input: {}", e))),
+            };
+
+            // 2. Encrypt and replace via OrganizationService
+            let organization_service = create_organization_service(ctx);
+            match organization_service
+                .update_collection(
+                    &collection_cmd.organizationid,
+                    &collection_cmd.id,
+                    collection_view,
+                    session,
+                )
+                .await
+            {
+                Ok(updated) => Ok(Response::success(updated)),
+                Err(VaultError::CollectionNotFound) => Ok(Response::error(format!(
+                    "Collection not found: {}",
+                    collection_cmd.id
+                ))),
+                Err(e) => Ok(Response::error(e.to_string())),
+            }
+        }
+
+        EditCommands::ItemCollections(collections_cmd) => {
+            let session = get_session(global_args)?;
+
+            // 1. Parse input (base64/JSON/stdin)
+            let collection_ids = match parse_collection_ids_input(&collections_cmd.collection_ids) {
+                Ok(ids) => ids,
+                Err(e) => return Ok(Response::error(format!("Invalid input: {}", e))),
+            };
+
+            // 2. Replace assignments via WriteService
+            let write_service = create_write_service(ctx, global_args.nointeraction);
+            match write_service
+                .update_cipher_collections(&collections_cmd.id, collection_ids)
+                .await
+            {
+                Ok(updated) => {
+                    // 3. Return decrypted view - updated.id is Option<CipherId>
+                    let id_str = updated.id.map(|id| id.to_string()).unwrap_or_default();
+                    let vault_service = create_vault_service(ctx);
+                    match vault_service.get_item(&id_str, session).await {
+                        Ok(decrypted) => Ok(Response::success(decrypted)),
+                        Err(e) => Ok(Response::error(e.to_string())),
+                    }
+                }
+                Err(VaultError::ItemNotFound) => Ok(Response::error(format!(
+                    "Item not found: {}",
+                    collections_cmd.id
+                ))),
+                Err(e) => Ok(Response::error(e.to_string())),
+            }
         }
     }
 }
@@ -840,8 +1073,40 @@ pub async fn execute_delete(
             }
         }
 
-        DeleteCommands::Attachment(_) | DeleteCommands::OrgCollection(_) => {
-            Ok(Response::error("Not yet implemented"))
+        DeleteCommands::Attachment(attachment_cmd) => {
+            let write_service = create_write_service(ctx, global_args.nointeraction);
+
+            match write_service
+                .delete_attachment(&attachment_cmd.itemid, &attachment_cmd.id)
+                .await
+            {
+                Ok(_) => Ok(Response::success_message("Attachment deleted")),
+                Err(VaultError::ItemNotFound) => Ok(Response::error(format!(
+                    "Item not found: {}",
+                    attachment_cmd.itemid
+                ))),
+                Err(VaultError::AttachmentNotFound) => Ok(Response::error(format!(
+                    "Attachment not found: {}",
+                    attachment_cmd.id
+                ))),
+                Err(e) => Ok(Response::error(e.to_string())),
+            }
+        }
+
+        DeleteCommands::OrgCollection(collection_cmd) => {
+            let organization_service = create_organization_service(ctx);
+
+            match organization_service
+                .delete_collection(&collection_cmd.organizationid, &collection_cmd.id)
+                .await
+            {
+                Ok(_) => Ok(Response::success_message("Collection deleted")),
+                Err(VaultError::CollectionNotFound) => Ok(Response::error(format!(
+                    "Collection not found: {}",
+                    collection_cmd.id
+                ))),
+                Err(e) => Ok(Response::error(e.to_string())),
+            }
         }
     }
 }
@@ -911,10 +1176,135 @@ pub async fn execute_move(
     }
 }
 
+// Share command implementation
+pub async fn execute_share(
+    cmd: ShareCommand,
+    global_args: &GlobalArgs,
+    ctx: &AppContext,
+) -> anyhow::Result<Response> {
+    let session = get_session(global_args)?;
+
+    // 1. Parse input (base64/JSON/stdin)
+    let collection_ids = match parse_collection_ids_input(&cmd.collection_ids) {
+        Ok(ids) => ids,
+        Err(e) => return Ok(Response::error(format!("Invalid input: {}", e))),
+    };
+
+    // 2. Re-encrypt and move via WriteService
+    let write_service = create_write_service(ctx, global_args.nointeraction);
+    match write_service
+        .share_cipher(&cmd.item_id, &cmd.organization_id, collection_ids, session)
+        .await
+    {
+        Ok(shared) => {
+            // 3. Return decrypted view - shared.id is Option<CipherId>
+            let vault_service = create_vault_service(ctx);
+            let id_str = shared.id.map(|id| id.to_string()).unwrap_or_default();
+            match vault_service.get_item(&id_str, session).await {
+                Ok(decrypted) => Ok(Response::success(decrypted)),
+                Err(e) => Ok(Response::error(e.to_string())),
+            }
+        }
+        Err(VaultError::ItemNotFound) => {
+            Ok(Response::error(format!("Item not found: {}", cmd.item_id)))
+        }
+        Err(e) => Ok(Response::error(e.to_string())),
+    }
+}
+
 pub async fn execute_confirm(
-    _cmd: ConfirmCommand,
-    _global_args: &GlobalArgs,
-    _ctx: &AppContext,
+    cmd: ConfirmCommand,
+    global_args: &GlobalArgs,
+    ctx: &AppContext,
 ) -> anyhow::Result<Response> {
-    Ok(Response::error("Not yet implemented"))
+    let session = get_session(global_args)?;
+    let organization_service = create_organization_service(ctx);
+
+    // 1. Look up the member and their fingerprint phrase
+    let pending = match organization_service
+        .prepare_member_confirmation(&cmd.organizationid, &cmd.id)
+        .await
+    {
+        Ok(pending) => pending,
+        Err(VaultError::MemberNotFound) => {
+            return Ok(Response::error(format!("Member not found: {}", cmd.id)));
+        }
+        Err(e) => return Ok(Response::error(e.to_string())),
+    };
+
+    // 2. Let the user compare the phrase before granting access
+    let member = pending
+        .member()
+        .email
+        .clone()
+        .unwrap_or_else(|| cmd.id.clone());
+    let confirmation_service = ConfirmationService::new(global_args.nointeraction);
+    match confirmation_service.confirm_member(&member, pending.fingerprint()) {
+        Ok(true) => {}
+        Ok(false) => return Ok(Response::success_message("Confirmation cancelled")),
+        Err(e) => return Ok(Response::error(e.to_string())),
+    }
+
+    // 3. Share the organization key with the member
+    match organization_service
+        .confirm_member(&cmd.organizationid, &pending, session)
+        .await
+    {
+        Ok(()) => Ok(Response::success_message("Member confirmed")),
+        Err(e) => Ok(Response::error(e.to_string())),
+    }
+}
+
+/// Write a downloaded attachment to a file or directory
+fn save_attachment(output: &str, attachment: &DownloadedAttachment) -> anyhow::Result<Response> {
+    let Some(path) = output_path(output, &attachment.file_name) else {
+        return Ok(Response::error(format!(
+            "Unsafe file name: {}",
+            attachment.file_name
+        )));
+    };
+    std::fs::write(&path, &attachment.contents)?;
+    Ok(Response::success_message(format!(
+        "Saved {}",
+        path.display()
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attachment(file_name: &str) -> DownloadedAttachment {
+        DownloadedAttachment {
+            file_name: file_name.to_string(),
+            contents: b"secret".to_vec(),
+        }
+    }
+
+    #[test]
+    fn test_save_attachment_stays_in_output_directory() {
+        let root = tempfile::tempdir().unwrap();
+        let output = root.path().join("downloads");
+        std::fs::create_dir(&output).unwrap();
+
+        let response =
+            save_attachment(output.to_str().unwrap(), &attachment("../escaped.txt")).unwrap();
+
+        assert!(matches!(response, Response::Success(_)));
+        assert_eq!(
+            std::fs::read(output.join("escaped.txt")).unwrap(),
+            b"secret"
+        );
+        assert!(!root.path().join("escaped.txt").exists());
+    }
+
+    #[test]
+    fn test_save_attachment_rejects_unusable_file_name() {
+        let output = tempfile::tempdir().unwrap();
+
+        let response = save_attachment(output.path().to_str().unwrap(), &attachment("..")).unwrap();
+
+        assert!(matches!(response, Response::Error(_)));
+        assert_eq!(std::fs::read_dir(output.path()).unwrap().count(), 0);
+    }
 }
@@ -14,14 +14,19 @@ use tokio::sync::Mutex;
 ///
 /// Created once at startup and passed to all command handlers.
 /// This enables consistent service initialization and easier testing.
+/// Cloning is cheap and shares the same underlying services.
+#[derive(Clone)]
 pub struct AppContext {
     container: Arc<ServiceContainer>,
 }
 
 impl AppContext {
     /// Create a new application context with default configuration
-    pub fn new() -> Result<Self> {
-        let container = ServiceContainer::new(None, None, None, None)?;
+    ///
+    /// With `account` (an email or user ID), commands act for that account
+    /// instead of the active one.
+    pub fn new(account: Option<&str>) -> Result<Self> {
+        let container = ServiceContainer::for_account(None, None, None, None, account)?;
         Ok(Self {
             container: Arc::new(container),
         })
@@ -49,6 +49,10 @@ pub struct GlobalArgs {
     /// Always exit with code 0 (success)
     #[arg(long, env = "BW_CLEANEXIT", global = true)]
     pub cleanexit: bool,
+
+    /// Account to use (email or user ID) instead of the active account
+    #[arg(long, env = "BW_ACCOUNT", global = true)]
+    pub account: Option<String>,
 }
 
 #[derive(Subcommand)]
@@ -79,6 +83,8 @@ enum Commands {
 
     Restore(commands::RestoreCommand),
     Move(commands::MoveCommand),
+    /// Move a personal item into an organization
+    Share(commands::ShareCommand),
     Confirm(commands::ConfirmCommand),
 
     /// Sync vault with server
@@ -87,13 +93,20 @@ enum Commands {
     /// Utility commands
     Generate(commands::GenerateCommand),
     Encode(commands::EncodeCommand),
+    Encrypt(commands::EncryptCommand),
     Decrypt(commands::DecryptCommand),
     Import(commands::ImportCommand),
     Export(commands::ExportCommand),
+    /// Password tools
+    #[command(subcommand)]
+    Tools(commands::ToolsCommands),
 
-    /// Send commands
+    /// Vault health reports
     #[command(subcommand)]
-    Send(commands::SendCommands),
+    Report(commands::ReportCommands),
+
+    /// Send commands
+    Send(commands::SendCommand),
 
     /// Receive and decrypt a Send
     Receive(commands::ReceiveCommand),
@@ -101,8 +114,15 @@ enum Commands {
     /// Configuration
     Config(commands::ConfigCommand),
 
+    /// List and switch between logged in accounts
+    #[command(subcommand)]
+    Account(commands::AccountCommands),
+
     /// Status
     Status(commands::StatusCommand),
+
+    /// Start a local REST API server
+    Serve(commands::ServeCommand),
 }
 
 #[tokio::main]
@@ -118,7 +138,7 @@ async fn main() -> ExitCode {
     let cli = Cli::parse();
 
     // Initialize application context (services) once
-    let ctx = match AppContext::new() {
+    let ctx = match AppContext::new(cli.global_args.account.as_deref()) {
         Ok(ctx) => ctx,
         Err(e) => {
             if !cli.global_args.quiet {
@@ -174,17 +194,23 @@ async fn execute_command(
         Delete(cmd) => commands::execute_delete(cmd, global_args, ctx).await,
         Restore(cmd) => commands::execute_restore(cmd, global_args, ctx).await,
         Move(cmd) => commands::execute_move(cmd, global_args, ctx).await,
+        Share(cmd) => commands::execute_share(cmd, global_args, ctx).await,
         Confirm(cmd) => commands::execute_confirm(cmd, global_args, ctx).await,
         Sync(cmd) => commands::execute_sync(cmd, global_args, ctx).await,
         Generate(cmd) => commands::execute_generate(cmd, global_args, ctx).await,
         Encode(cmd) => commands::execute_encode(cmd, global_args, ctx).await,
+        Encrypt(cmd) => commands::execute_encrypt(cmd, global_args, ctx).await,
         Decrypt(cmd) => commands::execute_decrypt(cmd, global_args, ctx).await,
         Import(cmd) => commands::execute_import(cmd, global_args, ctx).await,
         Export(cmd) => commands::execute_export(cmd, global_args, ctx).await,
+        Tools(cmd) => commands::execute_tools(cmd, global_args, ctx).await,
+        Report(cmd) => commands::execute_report(cmd, global_args, ctx).await,
         Send(cmd) => commands::execute_send(cmd, global_args, ctx).await,
         Receive(cmd) => commands::execute_receive(cmd, global_args, ctx).await,
         Config(cmd) => commands::execute_config(cmd, global_args, ctx).await,
+        Account(cmd) => commands::execute_account(cmd, global_args, ctx).await,
         Status(cmd) => commands::execute_status(cmd, global_args, ctx).await,
+        Serve(cmd) => commands::execute_serve(cmd, global_args, ctx).await,
     }
 }
 
@@ -4,8 +4,8 @@ use serde_json::Value;
 
 /// Print response according to global args (--response, --pretty, --quiet, --raw)
 pub fn print_response(response: Response, args: &GlobalArgs) {
-    // Quiet mode: suppress all output
-    if args.quiet {
+    // Quiet mode, or the command already wrote its output
+    if args.quiet || matches!(response, Response::None) {
         return;
     }
 
@@ -51,6 +51,7 @@ fn print_raw(response: &Response) {
         Response::Error(e) => {
             eprintln!("{}", e.message);
         }
+        Response::None => {}
     }
 }
 
@@ -92,5 +93,6 @@ fn print_human(response: &Response) {
         Response::Error(e) => {
             eprintln!("Error: {}", e.message);
         }
+        Response::None => {}
     }
 }
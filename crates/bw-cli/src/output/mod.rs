@@ -10,6 +10,8 @@ pub use formatter::print_response;
 pub enum Response {
     Success(SuccessResponse),
     Error(ErrorResponse),
+    /// Success whose output the command already wrote to stdout
+    None,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -54,6 +56,14 @@ impl Response {
         })
     }
 
+    /// Create a success response that prints nothing
+    ///
+    /// For commands that write file contents to stdout themselves, where any
+    /// trailing newline or JSON envelope would corrupt the output.
+    pub fn none() -> Self {
+        Response::None
+    }
+
     /// Create a success response with raw string data
     /// Used for commands that output plain text (like generate, encode)
     pub fn success_raw(data: impl Into<String>) -> Self {
@@ -76,7 +86,7 @@ impl Response {
 
     /// Check if this is a success response
     pub fn is_success(&self) -> bool {
-        matches!(self, Response::Success(_))
+        matches!(self, Response::Success(_) | Response::None)
     }
 
     /// Extract data as a specific type
@@ -86,7 +96,7 @@ impl Response {
                 .data
                 .as_ref()
                 .and_then(|v| serde_json::from_value(v.clone()).ok()),
-            Response::Error(_) => None,
+            Response::Error(_) | Response::None => None,
         }
     }
 }
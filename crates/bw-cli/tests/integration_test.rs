@@ -1,5 +1,17 @@
 use assert_cmd::Command;
+use base64::{Engine, engine::general_purpose::STANDARD};
+use bitwarden_crypto::{
+    AsymmetricCryptoKey, EncString, KeyEncryptable, PublicKeyEncryptionAlgorithm,
+    SymmetricCryptoKey,
+};
+use bw_core::services::KeyService;
+use bw_core::services::storage::{
+    AccountManager, JsonFileStorage, Storage, StorageKey, format_session_key, generate_session_key,
+};
 use predicates::prelude::*;
+use std::path::Path;
+use std::sync::Arc;
+use tokio::sync::Mutex;
 
 #[test]
 fn test_cli_help() {
@@ -83,7 +95,7 @@ fn test_all_auth_commands_exist() {
 #[test]
 fn test_all_vault_commands_exist() {
     for cmd_name in &[
-        "list", "get", "create", "edit", "delete", "restore", "move", "confirm",
+        "list", "get", "create", "edit", "delete", "restore", "move", "share", "confirm",
     ] {
         let mut cmd = Command::cargo_bin("bw").unwrap();
         cmd.args(&[cmd_name, "--help"]);
@@ -91,6 +103,229 @@ fn test_all_vault_commands_exist() {
     }
 }
 
+#[test]
+fn test_generate_options_override_defaults() {
+    // Empty storage, so no saved generator defaults are picked up
+    let temp_dir = tempfile::tempdir().unwrap();
+    let mut cmd = Command::cargo_bin("bw").unwrap();
+    cmd.env("BITWARDENCLI_APPDATA_DIR", temp_dir.path());
+    cmd.args(&["generate", "--password", "--length", "24", "--special", "0"])
+        .arg("--raw");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::is_match(r"^[A-Za-z0-9]{24}\n?$").unwrap());
+}
+
+#[test]
+fn test_tools_strength_reads_stdin() {
+    let mut cmd = Command::cargo_bin("bw").unwrap();
+    cmd.args(&["tools", "strength", "--email", "jane@example.com"])
+        .arg("--response")
+        .write_stdin("password123\n");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(r#""score":0"#))
+        .stdout(predicate::str::contains(r#""crackTime""#));
+}
+
+#[test]
+fn test_report_commands_require_unlocked_vault() {
+    for report in &["weak", "reused", "old", "exposed"] {
+        let mut cmd = Command::cargo_bin("bw").unwrap();
+        cmd.env_remove("BW_SESSION")
+            .args(&["report", report, "--response"]);
+
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::contains("Vault is locked"));
+    }
+}
+
+#[test]
+fn test_config_pin_requires_unlocked_vault() {
+    let mut cmd = Command::cargo_bin("bw").unwrap();
+    cmd.env_remove("BW_SESSION")
+        .args(&["config", "pin", "1234", "--response"]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Vault is locked"));
+}
+
+#[test]
+fn test_unlock_has_pin_option() {
+    let mut cmd = Command::cargo_bin("bw").unwrap();
+    cmd.args(&["unlock", "--help"]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("--pin"));
+}
+
+#[test]
+fn test_login_remember_requires_code() {
+    let mut cmd = Command::cargo_bin("bw").unwrap();
+    cmd.args(&[
+        "login",
+        "password",
+        "user@example.com",
+        "password",
+        "--remember",
+    ]);
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("--code"));
+}
+
+#[test]
+fn test_account_switch_unknown_account() {
+    let mut cmd = Command::cargo_bin("bw").unwrap();
+    cmd.args(&["account", "switch", "nobody@example.invalid", "--response"]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(r#""success":false"#))
+        .stdout(predicate::str::contains("not found"));
+}
+
+#[test]
+fn test_account_flag_rejects_unknown_account() {
+    let mut cmd = Command::cargo_bin("bw").unwrap();
+    cmd.args(&["status", "--account", "nobody@example.invalid"]);
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("not found"));
+}
+
+#[test]
+fn test_serve_command_exists() {
+    let mut cmd = Command::cargo_bin("bw").unwrap();
+    cmd.args(&["serve", "--help"]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("--port"))
+        .stdout(predicate::str::contains("--socket"));
+}
+
+/// Storage with an unlocked, synced account for commands that need vault keys
+///
+/// Returns the session key for BW_SESSION, the user key and the account's
+/// RSA private key.
+fn unlocked_account(dir: &Path) -> (String, SymmetricCryptoKey, AsymmetricCryptoKey) {
+    tokio::runtime::Runtime::new().unwrap().block_on(async {
+        let storage = Arc::new(Mutex::new(
+            JsonFileStorage::new(Some(dir.to_path_buf())).unwrap(),
+        ));
+        let account_manager = Arc::new(AccountManager::new(Arc::clone(&storage)));
+        account_manager
+            .register_account("user-123", "test@example.com")
+            .await
+            .unwrap();
+        account_manager
+            .set_active_user_id("user-123")
+            .await
+            .unwrap();
+
+        let user_key = SymmetricCryptoKey::make_aes256_cbc_hmac_key();
+        let session_key = generate_session_key();
+        KeyService::new(Arc::clone(&storage), account_manager)
+            .store_user_key("user-123", &user_key, &session_key)
+            .await
+            .unwrap();
+
+        // Sync stores the private key encrypted with the user key
+        let private_key = AsymmetricCryptoKey::make(PublicKeyEncryptionAlgorithm::RsaOaepSha1);
+        let encrypted_private_key: EncString = private_key
+            .to_der()
+            .unwrap()
+            .as_ref()
+            .encrypt_with_key(&user_key)
+            .unwrap();
+        let mut storage = storage.lock().await;
+        storage
+            .set(
+                &StorageKey::UserPrivateKey.format(Some("user-123")),
+                &encrypted_private_key.to_string(),
+            )
+            .await
+            .unwrap();
+        storage.flush().await.unwrap();
+
+        (format_session_key(&session_key), user_key, private_key)
+    })
+}
+
+/// Run `bw decrypt` against `dir` and return its trimmed output
+fn decrypt(dir: &Path, session: &str, encrypted: &str) -> String {
+    let mut cmd = Command::cargo_bin("bw").unwrap();
+    cmd.env("BITWARDENCLI_APPDATA_DIR", dir)
+        .env("BW_SESSION", session)
+        .args(&["decrypt", encrypted, "--raw"]);
+
+    let output = cmd.assert().success().get_output().stdout.clone();
+    String::from_utf8(output).unwrap().trim().to_string()
+}
+
+#[test]
+fn test_encrypt_decrypt_round_trip() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let (session, user_key, _) = unlocked_account(temp_dir.path());
+
+    let mut cmd = Command::cargo_bin("bw").unwrap();
+    cmd.env("BITWARDENCLI_APPDATA_DIR", temp_dir.path())
+        .env("BW_SESSION", &session)
+        .args(&["encrypt", "secret value", "--raw"]);
+    let output = cmd.assert().success().get_output().stdout.clone();
+    let encrypted = String::from_utf8(output).unwrap().trim().to_string();
+
+    assert!(encrypted.starts_with("2."));
+    assert_eq!(
+        bw_core::services::decrypt_string(&encrypted, &user_key, None).unwrap(),
+        "secret value"
+    );
+    assert_eq!(
+        decrypt(temp_dir.path(), &session, &encrypted),
+        "secret value"
+    );
+}
+
+#[test]
+fn test_decrypt_rsa_enc_string() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let (session, _, private_key) = unlocked_account(temp_dir.path());
+
+    // A key shared to the account isn't text, so it comes back base64-encoded
+    let public_key = STANDARD.encode(private_key.to_public_key().to_der().unwrap());
+    let org_key = SymmetricCryptoKey::make_aes256_cbc_hmac_key();
+    let encrypted = bw_core::services::encapsulate_key(&org_key, &public_key).unwrap();
+
+    assert_eq!(
+        decrypt(temp_dir.path(), &session, &encrypted),
+        org_key.to_base64().to_string()
+    );
+}
+
+#[test]
+fn test_decrypt_rejects_invalid_enc_string() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let (session, _, _) = unlocked_account(temp_dir.path());
+
+    let mut cmd = Command::cargo_bin("bw").unwrap();
+    cmd.env("BITWARDENCLI_APPDATA_DIR", temp_dir.path())
+        .env("BW_SESSION", &session)
+        .args(&["decrypt", "not-an-enc-string", "--response"]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(r#""success":false"#))
+        .stdout(predicate::str::contains("Decryption failed"));
+}
+
 #[test]
 fn test_invalid_command() {
     let mut cmd = Command::cargo_bin("bw").unwrap();
@@ -0,0 +1,77 @@
+use crate::models::vault::Cipher;
+use serde::{Deserialize, Serialize};
+
+/// Request an upload slot for a new attachment
+/// (`POST /ciphers/{id}/attachment/v2`)
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AttachmentUploadRequest {
+    /// Attachment key encrypted with the cipher key (EncString)
+    pub key: String,
+    /// Encrypted file name (EncString)
+    pub file_name: String,
+    /// Size of the encrypted file in bytes
+    pub file_size: u64,
+    /// Whether the request is made with organization admin permissions
+    pub admin_request: bool,
+}
+
+/// Where the encrypted attachment contents are uploaded
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(from = "u8")]
+pub enum FileUploadType {
+    /// Multipart POST to the Bitwarden API
+    Direct,
+    /// PUT to a pre-signed Azure blob storage URL
+    Azure,
+}
+
+impl From<u8> for FileUploadType {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => Self::Azure,
+            _ => Self::Direct,
+        }
+    }
+}
+
+/// Upload slot returned for a new attachment
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AttachmentUploadDataResponse {
+    pub attachment_id: String,
+    /// Upload URL (Azure uploads only)
+    pub url: Option<String>,
+    pub file_upload_type: FileUploadType,
+    /// The cipher including the new attachment
+    pub cipher_response: Option<Cipher>,
+}
+
+/// Attachment download data (`GET /ciphers/{id}/attachment/{attachmentId}`)
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AttachmentResponse {
+    pub id: String,
+    /// Short-lived download URL for the encrypted contents
+    pub url: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_upload_data_response_parses_upload_type() {
+        let response: AttachmentUploadDataResponse = serde_json::from_value(serde_json::json!({
+            "attachmentId": "att-1",
+            "url": "https://blob.example.com/att-1?sig=abc",
+            "fileUploadType": 1,
+            "cipherResponse": null,
+            "object": "attachment-fileUpload"
+        }))
+        .unwrap();
+
+        assert_eq!(response.attachment_id, "att-1");
+        assert_eq!(response.file_upload_type, FileUploadType::Azure);
+    }
+}
@@ -84,6 +84,42 @@ pub struct ApiKeyLoginRequest {
     pub device_identifier: String,
 }
 
+/// SSO login request (OAuth2 authorization code grant with PKCE)
+///
+/// NOTE: This must be form-encoded, not JSON
+#[derive(Debug, Clone, Serialize)]
+pub struct SsoLoginRequest {
+    /// OAuth2 grant type ("authorization_code")
+    pub grant_type: String,
+    /// Authorization code received on the redirect URI
+    pub code: String,
+    /// PKCE code verifier matching the challenge sent to the authorize endpoint
+    pub code_verifier: String,
+    /// Redirect URI used for the authorize request
+    pub redirect_uri: String,
+    /// OAuth2 scope
+    pub scope: String,
+    /// Client ID ("cli")
+    pub client_id: String,
+    /// Device type code (8 = CLI)
+    #[serde(rename = "deviceType")]
+    pub device_type: u8,
+    /// Device name
+    #[serde(rename = "deviceName")]
+    pub device_name: String,
+    /// Device identifier (UUID)
+    #[serde(rename = "deviceIdentifier")]
+    pub device_identifier: String,
+
+    // Optional 2FA fields
+    #[serde(rename = "twoFactorToken", skip_serializing_if = "Option::is_none")]
+    pub two_factor_token: Option<String>,
+    #[serde(rename = "twoFactorProvider", skip_serializing_if = "Option::is_none")]
+    pub two_factor_provider: Option<u8>,
+    #[serde(rename = "twoFactorRemember", skip_serializing_if = "Option::is_none")]
+    pub two_factor_remember: Option<u8>,
+}
+
 /// Login response (both password and API key)
 ///
 /// Note: OAuth2 fields use snake_case (access_token, expires_in, etc.)
@@ -131,6 +167,77 @@ pub struct LoginResponse {
     /// 2FA provider details
     #[serde(rename = "TwoFactorProviders2")]
     pub two_factor_providers2: Option<serde_json::Value>,
+
+    /// Remember-device token (returned when `twoFactorRemember=1` was sent)
+    #[serde(rename = "TwoFactorToken")]
+    pub two_factor_token: Option<String>,
+
+    /// How the user key can be decrypted (SSO logins)
+    #[serde(rename = "UserDecryptionOptions")]
+    pub user_decryption_options: Option<UserDecryptionOptions>,
+}
+
+/// Ways the account's user key can be decrypted
+///
+/// SSO users may not have a master password; their user key is instead
+/// protected by a trusted device or released by a Key Connector.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct UserDecryptionOptions {
+    /// Whether the user has a master password
+    #[serde(default)]
+    pub has_master_password: bool,
+    /// Trusted device encryption details
+    pub trusted_device_option: Option<TrustedDeviceUserDecryptionOption>,
+    /// Key Connector details
+    pub key_connector_option: Option<KeyConnectorUserDecryptionOption>,
+}
+
+/// Trusted device decryption option
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct TrustedDeviceUserDecryptionOption {
+    /// Device private key encrypted with the device key (EncString)
+    pub encrypted_private_key: Option<String>,
+    /// User key encrypted with the device public key
+    pub encrypted_user_key: Option<String>,
+}
+
+/// Key Connector decryption option
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct KeyConnectorUserDecryptionOption {
+    /// Base URL of the organization's Key Connector
+    pub key_connector_url: String,
+}
+
+/// Master key released by a Key Connector (`GET {url}/user-keys`)
+#[derive(Debug, Deserialize)]
+pub struct KeyConnectorUserKeyResponse {
+    /// Base64-encoded master key
+    pub key: String,
+}
+
+/// Keys that make this device trusted (`PUT /devices/{identifier}/keys`)
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeviceKeysRequest {
+    /// User key encrypted with the device public key
+    pub encrypted_user_key: String,
+    /// Device public key encrypted with the user key (EncString)
+    pub encrypted_public_key: String,
+    /// Device private key encrypted with the device key (EncString)
+    pub encrypted_private_key: String,
+}
+
+/// Request to send the email two-factor code during login
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TwoFactorEmailRequest {
+    pub email: String,
+    /// Server authorization hash of the master password
+    pub master_password_hash: String,
+    pub device_identifier: String,
 }
 
 /// User profile response
@@ -0,0 +1,13 @@
+use crate::models::vault::CipherRequestModel;
+use serde::Serialize;
+
+/// Request body for moving a personal cipher into an organization
+/// (`PUT /ciphers/{id}/share`)
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CipherShareRequest {
+    /// The cipher re-encrypted with the organization key
+    pub cipher: CipherRequestModel,
+    /// Organization collections to assign the cipher to
+    pub collection_ids: Vec<String>,
+}
@@ -0,0 +1,34 @@
+use crate::models::vault::CollectionAccessSelection;
+use serde::{Deserialize, Serialize};
+
+/// Request body for creating or updating an organization collection
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CollectionRequest {
+    /// Encrypted collection name (EncString)
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<String>,
+    /// Replaces the collection's group access
+    pub groups: Vec<CollectionAccessSelection>,
+    /// Replaces the collection's member access
+    pub users: Vec<CollectionAccessSelection>,
+}
+
+/// Organization collection from the organization endpoints
+///
+/// `groups` and `users` are only present on the details responses.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CollectionResponse {
+    pub id: String,
+    pub organization_id: String,
+    /// Encrypted collection name (EncString)
+    pub name: String,
+    #[serde(default)]
+    pub external_id: Option<String>,
+    #[serde(default)]
+    pub groups: Option<Vec<CollectionAccessSelection>>,
+    #[serde(default)]
+    pub users: Option<Vec<CollectionAccessSelection>>,
+}
@@ -0,0 +1,30 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Server configuration (`GET /config`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ServerConfigResponse {
+    /// Server version, e.g. "2025.1.0"
+    pub version: String,
+
+    #[serde(default)]
+    pub git_hash: Option<String>,
+
+    /// Set by third-party servers such as Vaultwarden
+    #[serde(default)]
+    pub server: Option<ThirdPartyServerResponse>,
+
+    /// Feature flags by name, usually booleans
+    #[serde(default)]
+    pub feature_states: BTreeMap<String, serde_json::Value>,
+}
+
+/// Third-party server information
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThirdPartyServerResponse {
+    pub name: String,
+    #[serde(default)]
+    pub url: Option<String>,
+}
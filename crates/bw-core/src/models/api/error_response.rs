@@ -15,4 +15,9 @@ pub struct ApiErrorResponse {
 
     #[serde(rename = "error_description")]
     pub error_description: Option<String>,
+
+    /// Identity server: two-factor providers available when 2FA is required,
+    /// keyed by provider code
+    #[serde(rename = "TwoFactorProviders2")]
+    pub two_factor_providers2: Option<HashMap<String, serde_json::Value>>,
 }
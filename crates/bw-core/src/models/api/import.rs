@@ -0,0 +1,58 @@
+use crate::models::vault::CipherRequestModel;
+use serde::Serialize;
+
+/// Bulk import request for the personal vault (`POST /ciphers/import`)
+///
+/// Folder relationships map a cipher index to a folder index in the
+/// request's `folders` list.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportCiphersRequest {
+    pub ciphers: Vec<CipherRequestModel>,
+    pub folders: Vec<ImportFolderRequest>,
+    pub folder_relationships: Vec<ImportRelationship>,
+}
+
+/// Bulk import request for an organization vault
+/// (`POST /ciphers/import-organization`)
+///
+/// Collection relationships map a cipher index to a collection index in the
+/// request's `collections` list.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportOrganizationCiphersRequest {
+    pub ciphers: Vec<CipherRequestModel>,
+    pub collections: Vec<ImportCollectionRequest>,
+    pub collection_relationships: Vec<ImportRelationship>,
+}
+
+/// Folder to create during import
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportFolderRequest {
+    /// Encrypted folder name (EncString)
+    pub name: String,
+    /// Existing folder ID, if reusing a folder
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+}
+
+/// Collection to create during an organization import
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportCollectionRequest {
+    /// Encrypted collection name (EncString)
+    pub name: String,
+    /// Existing collection ID, if reusing a collection
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+}
+
+/// Index pair linking an imported cipher to a folder or collection
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct ImportRelationship {
+    /// Cipher index
+    pub key: usize,
+    /// Folder or collection index
+    pub value: usize,
+}
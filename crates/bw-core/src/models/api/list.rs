@@ -0,0 +1,11 @@
+use serde::Deserialize;
+
+/// Wrapper for list endpoints (`{ "data": [...], "object": "list" }`)
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListResponse<T> {
+    #[serde(default = "Vec::new")]
+    pub data: Vec<T>,
+    #[serde(default)]
+    pub continuation_token: Option<String>,
+}
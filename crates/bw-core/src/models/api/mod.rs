@@ -1,10 +1,33 @@
+pub mod attachment;
 pub mod auth;
+pub mod cipher;
+pub mod collection;
+pub mod config;
 pub mod error_response;
+pub mod import;
+pub mod list;
+pub mod send;
 pub mod token;
+pub mod user;
 
+pub use attachment::{
+    AttachmentResponse, AttachmentUploadDataResponse, AttachmentUploadRequest, FileUploadType,
+};
 pub use auth::{
-    ApiKeyLoginRequest, LoginResponse, PasswordLoginRequest, PreloginRequest, PreloginResponse,
-    ProfileResponse,
+    ApiKeyLoginRequest, DeviceKeysRequest, KeyConnectorUserDecryptionOption, KeyConnectorUserKeyResponse,
+    LoginResponse, PasswordLoginRequest, PreloginRequest, PreloginResponse, ProfileResponse,
+    SsoLoginRequest, TrustedDeviceUserDecryptionOption, TwoFactorEmailRequest,
+    UserDecryptionOptions,
 };
+pub use cipher::CipherShareRequest;
+pub use collection::{CollectionRequest, CollectionResponse};
+pub use config::{ServerConfigResponse, ThirdPartyServerResponse};
 pub use error_response::ApiErrorResponse;
+pub use import::{
+    ImportCiphersRequest, ImportCollectionRequest, ImportFolderRequest,
+    ImportOrganizationCiphersRequest, ImportRelationship,
+};
+pub use list::ListResponse;
+pub use send::{SendFileDownloadDataResponse, SendFileUploadDataResponse};
 pub use token::{TokenRefreshRequest, TokenResponse};
+pub use user::{OrganizationUserConfirmRequest, UserPublicKeyResponse};
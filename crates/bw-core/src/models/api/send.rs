@@ -0,0 +1,23 @@
+use crate::models::api::FileUploadType;
+use crate::models::send::Send;
+use serde::Deserialize;
+
+/// Upload slot returned for a new file Send (`POST /sends/file/v2`)
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SendFileUploadDataResponse {
+    /// Upload URL (Azure uploads only)
+    pub url: Option<String>,
+    pub file_upload_type: FileUploadType,
+    /// The created Send, including its file ID
+    pub send_response: Send,
+}
+
+/// Download link for a file Send (`POST /sends/{id}/access/file/{fileId}`)
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SendFileDownloadDataResponse {
+    pub id: String,
+    /// Short-lived download URL for the encrypted file
+    pub url: String,
+}
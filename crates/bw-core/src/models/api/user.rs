@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+/// A user's RSA public key (`GET /users/{id}/public-key`)
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserPublicKeyResponse {
+    pub user_id: String,
+    /// Base64-encoded SPKI DER public key
+    pub public_key: String,
+}
+
+/// Request body for confirming an organization member
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrganizationUserConfirmRequest {
+    /// Organization key encrypted to the member's public key (RSA EncString)
+    pub key: String,
+}
@@ -6,4 +6,6 @@ mod two_factor;
 pub use device::DeviceInfo;
 pub use login::{LoginResult, UnlockResult};
 pub use session::{SessionKey, SessionKeyError};
-pub use two_factor::{TwoFactorData, TwoFactorMethod, provider_code_to_sdk};
+pub use two_factor::{
+    REMEMBER_PROVIDER_CODE, TwoFactorData, TwoFactorMethod, provider_code_to_sdk,
+};
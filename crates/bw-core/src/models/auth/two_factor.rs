@@ -1,5 +1,9 @@
 use bitwarden_core::auth::login::TwoFactorProvider as SdkTwoFactorProvider;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Provider code sent with a remembered-device token
+pub const REMEMBER_PROVIDER_CODE: u8 = 5;
 
 /// Two-factor authentication data
 #[derive(Debug, Clone)]
@@ -70,6 +74,28 @@ impl TwoFactorMethod {
             _ => None,
         }
     }
+
+    /// Whether the code for this method can be typed in at a terminal
+    ///
+    /// Duo and FIDO methods need a browser, so the CLI can't complete them.
+    pub fn supports_code_entry(&self) -> bool {
+        matches!(self, Self::Authenticator | Self::Email | Self::YubiKey)
+    }
+
+    /// Parse the identity server's `TwoFactorProviders2` map
+    ///
+    /// Keys are provider codes as strings; values hold provider metadata
+    /// (e.g. the obfuscated email address). Unknown providers are skipped and
+    /// the result is ordered by provider code.
+    pub fn from_providers2<V>(providers: &HashMap<String, V>) -> Vec<Self> {
+        let mut methods: Vec<Self> = providers
+            .keys()
+            .filter_map(|key| key.parse::<u8>().ok())
+            .filter_map(Self::from_provider_code)
+            .collect();
+        methods.sort_by_key(|m| m.to_provider_code());
+        methods
+    }
 }
 
 #[cfg(test)]
@@ -95,4 +121,21 @@ mod tests {
         );
         assert_eq!(TwoFactorMethod::from_provider_code(99), None);
     }
+
+    #[test]
+    fn test_two_factor_methods_from_providers2() {
+        let providers: HashMap<String, serde_json::Value> = serde_json::from_str(
+            r#"{"1": {"Email": "t***@example.com"}, "0": null, "5": null, "7": {}}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            TwoFactorMethod::from_providers2(&providers),
+            vec![
+                TwoFactorMethod::Authenticator,
+                TwoFactorMethod::Email,
+                TwoFactorMethod::WebAuthn
+            ]
+        );
+    }
 }
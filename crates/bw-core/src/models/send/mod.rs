@@ -3,9 +3,11 @@ mod send_access;
 mod send_file;
 mod send_request;
 mod send_text;
+mod send_view;
 
 pub use send::{Send, SendType};
-pub use send_access::SendAccess;
+pub use send_access::{SendAccess, SendAccessView};
 pub use send_file::SendFile;
-pub use send_request::{SendFileRequest, SendRequest, SendTextRequest};
+pub use send_request::{SendAccessRequest, SendFileRequest, SendRequest, SendTextRequest};
 pub use send_text::SendText;
+pub use send_view::{SendFileView, SendTextView, SendView};
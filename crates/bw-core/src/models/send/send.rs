@@ -2,13 +2,34 @@ use crate::models::send::{SendFile, SendText};
 use serde::{Deserialize, Serialize};
 
 /// Send type enumeration
+///
+/// Serialized as the integer the API uses.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "u8", into = "u8")]
 #[repr(u8)]
 pub enum SendType {
     Text = 0,
     File = 1,
 }
 
+impl TryFrom<u8> for SendType {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(SendType::Text),
+            1 => Ok(SendType::File),
+            _ => Err(format!("Invalid send type: {}", value)),
+        }
+    }
+}
+
+impl From<SendType> for u8 {
+    fn from(send_type: SendType) -> Self {
+        send_type as u8
+    }
+}
+
 impl SendType {
     pub fn as_str(&self) -> &'static str {
         match self {
@@ -67,6 +88,7 @@ pub struct Send {
     pub max_access_count: Option<u32>,
 
     /// Current access count
+    #[serde(default)]
     pub access_count: u32,
 
     /// Expiration date (ISO 8601, null = no expiration)
@@ -77,9 +99,10 @@ pub struct Send {
     pub deletion_date: String,
 
     /// Whether Send is disabled
+    #[serde(default)]
     pub disabled: bool,
 
-    /// Whether password is required for access
+    /// Password hash (set when a password is required for access)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub password: Option<String>,
 
@@ -109,4 +132,37 @@ mod tests {
 
         assert!(SendType::from_str("invalid").is_err());
     }
+
+    #[test]
+    fn test_send_deserializes_api_response() {
+        let json = r#"{
+            "id": "send-1",
+            "accessId": "access-1",
+            "type": 1,
+            "name": "2.name",
+            "notes": null,
+            "file": {"id": "file-1", "fileName": "2.file", "size": "1024", "sizeName": "1 KB"},
+            "key": "2.key",
+            "maxAccessCount": 5,
+            "accessCount": 2,
+            "password": null,
+            "disabled": false,
+            "revisionDate": "2025-01-01T00:00:00Z",
+            "expirationDate": null,
+            "deletionDate": "2025-01-08T00:00:00Z",
+            "hideEmail": false,
+            "object": "send"
+        }"#;
+
+        let send: Send = serde_json::from_str(json).unwrap();
+
+        assert_eq!(send.send_type, SendType::File);
+        assert_eq!(send.max_access_count, Some(5));
+        let file = send.file.unwrap();
+        assert_eq!(file.size.as_deref(), Some("1024"));
+
+        // Type serializes back to the integer form
+        let value = serde_json::to_value(SendType::Text).unwrap();
+        assert_eq!(value, serde_json::json!(0));
+    }
 }
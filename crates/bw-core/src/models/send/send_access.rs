@@ -1,7 +1,10 @@
-use crate::models::send::{SendFile, SendText, SendType};
+use crate::models::send::{SendFile, SendFileView, SendText, SendTextView, SendType};
 use serde::{Deserialize, Serialize};
 
-/// Response from public Send access
+/// Response from public Send access (`POST /sends/access/{accessId}`)
+///
+/// Content is encrypted with the Send key, which the recipient derives from
+/// the access URL; the server never returns it.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SendAccess {
@@ -16,19 +19,40 @@ pub struct SendAccess {
     pub name: String,
 
     /// Text data (if type=0)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub text: Option<SendText>,
 
     /// File data (if type=1)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub file: Option<SendFile>,
 
-    /// Encrypted key
-    pub key: String,
+    /// Expiration date (ISO 8601)
+    #[serde(default)]
+    pub expiration_date: Option<String>,
+
+    /// Sender's email, unless the sender chose to hide it
+    #[serde(default)]
+    pub creator_identifier: Option<String>,
+}
+
+/// Decrypted Send as seen by a recipient
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SendAccessView {
+    pub id: String,
+
+    #[serde(rename = "type")]
+    pub send_type: SendType,
+
+    pub name: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<SendTextView>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<SendFileView>,
 
-    /// Access count
-    pub access_count: u32,
+    pub expiration_date: Option<String>,
 
-    /// Whether password required
-    pub password_required: bool,
+    pub creator_identifier: Option<String>,
 }
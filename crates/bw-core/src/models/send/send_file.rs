@@ -1,18 +1,34 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 
 /// File Send data
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SendFile {
+    /// File ID (assigned by the server)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
     /// Encrypted file name (EncString)
     pub file_name: String,
 
-    /// File size in bytes
-    pub size: u64,
+    /// File size in bytes (the API sends this as a string)
+    #[serde(default, deserialize_with = "deserialize_size")]
+    pub size: Option<String>,
 
     /// File size string (human-readable)
-    pub size_name: String,
+    #[serde(default)]
+    pub size_name: Option<String>,
+}
 
-    /// File ID
-    pub id: String,
+/// Accept the file size as either a string or a number
+fn deserialize_size<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value: Option<serde_json::Value> = Option::deserialize(deserializer)?;
+    Ok(match value {
+        Some(serde_json::Value::String(s)) => Some(s),
+        Some(serde_json::Value::Number(n)) => Some(n.to_string()),
+        _ => None,
+    })
 }
@@ -19,6 +19,10 @@ pub struct SendRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub file: Option<SendFileRequest>,
 
+    /// Size of the encrypted file in bytes (file Sends only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_length: Option<u64>,
+
     pub key: String,
 
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -29,6 +33,7 @@ pub struct SendRequest {
 
     pub deletion_date: String,
 
+    /// Password hash; omitted to keep the current password on edit
     #[serde(skip_serializing_if = "Option::is_none")]
     pub password: Option<String>,
 
@@ -49,6 +54,13 @@ pub struct SendTextRequest {
 #[serde(rename_all = "camelCase")]
 pub struct SendFileRequest {
     pub file_name: String,
-    pub size: u64,
-    pub size_name: String,
+}
+
+/// Request body for public Send access
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SendAccessRequest {
+    /// Access password hash (password-protected Sends only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
 }
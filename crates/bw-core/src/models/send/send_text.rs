@@ -5,8 +5,10 @@ use serde::{Deserialize, Serialize};
 #[serde(rename_all = "camelCase")]
 pub struct SendText {
     /// Encrypted text content (EncString)
-    pub text: String,
+    #[serde(default)]
+    pub text: Option<String>,
 
     /// Whether text should be hidden by default
+    #[serde(default)]
     pub hidden: bool,
 }
@@ -0,0 +1,111 @@
+use crate::models::send::SendType;
+use serde::{Deserialize, Serialize};
+
+/// Decrypted Send
+///
+/// Output of `bw send list/get` and input of `bw send create/edit`, so every
+/// field the server assigns is optional.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SendView {
+    /// Send ID (UUID)
+    #[serde(default)]
+    pub id: Option<String>,
+
+    /// Access ID used in public URL
+    #[serde(default)]
+    pub access_id: Option<String>,
+
+    /// Send type: 0=Text, 1=File
+    #[serde(rename = "type")]
+    pub send_type: SendType,
+
+    /// Send name
+    pub name: String,
+
+    /// Private notes (not shown to recipients)
+    #[serde(default)]
+    pub notes: Option<String>,
+
+    /// Text Send data (if type=0)
+    #[serde(default)]
+    pub text: Option<SendTextView>,
+
+    /// File Send data (if type=1)
+    #[serde(default)]
+    pub file: Option<SendFileView>,
+
+    /// Send key seed, URL-safe base64 (the last segment of the access URL)
+    #[serde(default)]
+    pub key: Option<String>,
+
+    /// Maximum access count (null = unlimited)
+    #[serde(default)]
+    pub max_access_count: Option<u32>,
+
+    /// Current access count
+    #[serde(default)]
+    pub access_count: u32,
+
+    /// Expiration date (ISO 8601, null = no expiration)
+    #[serde(default)]
+    pub expiration_date: Option<String>,
+
+    /// Deletion date (ISO 8601, default: 7 days from creation)
+    #[serde(default)]
+    pub deletion_date: Option<String>,
+
+    /// Whether Send is disabled
+    #[serde(default)]
+    pub disabled: bool,
+
+    /// New access password (input only; the server never returns it)
+    #[serde(default)]
+    pub password: Option<String>,
+
+    /// Whether a password is required for access
+    #[serde(default)]
+    pub password_set: bool,
+
+    /// Whether to hide email from recipient
+    #[serde(default)]
+    pub hide_email: bool,
+
+    /// Revision date (ISO 8601)
+    #[serde(default)]
+    pub revision_date: Option<String>,
+
+    /// Public URL to share with recipients
+    #[serde(default)]
+    pub access_url: Option<String>,
+}
+
+/// Decrypted text Send data
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SendTextView {
+    #[serde(default)]
+    pub text: Option<String>,
+
+    #[serde(default)]
+    pub hidden: bool,
+}
+
+/// Decrypted file Send data
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SendFileView {
+    #[serde(default)]
+    pub id: Option<String>,
+
+    #[serde(default)]
+    pub file_name: String,
+
+    /// File size in bytes
+    #[serde(default)]
+    pub size: Option<String>,
+
+    /// File size string (human-readable)
+    #[serde(default)]
+    pub size_name: Option<String>,
+}
@@ -1,4 +1,86 @@
 use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Bitwarden cloud regions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Region {
+    #[serde(rename = "US")]
+    Us,
+    #[serde(rename = "EU")]
+    Eu,
+}
+
+/// Service URLs of a cloud region
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RegionUrls {
+    pub web_vault: &'static str,
+    pub api: &'static str,
+    pub identity: &'static str,
+    pub icons: &'static str,
+    pub notifications: &'static str,
+    pub events: &'static str,
+}
+
+const US_URLS: RegionUrls = RegionUrls {
+    web_vault: "https://vault.bitwarden.com",
+    api: "https://api.bitwarden.com",
+    identity: "https://identity.bitwarden.com",
+    icons: "https://icons.bitwarden.net",
+    notifications: "https://notifications.bitwarden.com",
+    events: "https://events.bitwarden.com",
+};
+
+const EU_URLS: RegionUrls = RegionUrls {
+    web_vault: "https://vault.bitwarden.eu",
+    api: "https://api.bitwarden.eu",
+    identity: "https://identity.bitwarden.eu",
+    icons: "https://icons.bitwarden.eu",
+    notifications: "https://notifications.bitwarden.eu",
+    events: "https://events.bitwarden.eu",
+};
+
+impl Region {
+    pub const ALL: [Region; 2] = [Self::Us, Self::Eu];
+
+    /// Name used on the command line
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Us => "us",
+            Self::Eu => "eu",
+        }
+    }
+
+    /// Service URLs of the region
+    pub fn urls(&self) -> &'static RegionUrls {
+        match self {
+            Self::Us => &US_URLS,
+            Self::Eu => &EU_URLS,
+        }
+    }
+}
+
+impl fmt::Display for Region {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Region {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "us" => Ok(Self::Us),
+            "eu" => Ok(Self::Eu),
+            _ => Err(format!(
+                "Unknown region '{}'. Expected one of: {}",
+                s,
+                Self::ALL.map(|r| r.as_str()).join(", ")
+            )),
+        }
+    }
+}
 
 /// Environment server URLs configuration
 ///
@@ -6,6 +88,10 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct EnvironmentUrls {
+    /// Cloud region the URLs default to, when no base URL is set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<Region>,
+
     /// Base API URL (default: https://bitwarden.com)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub base: Option<String>,
@@ -35,9 +121,22 @@ pub struct EnvironmentUrls {
     pub events: Option<String>,
 }
 
+impl EnvironmentUrls {
+    /// URL identifying the server: the base URL, or else the web vault, API or
+    /// region's web vault URL
+    pub fn server_url(&self) -> Option<&str> {
+        self.base
+            .as_deref()
+            .or(self.web_vault.as_deref())
+            .or(self.api.as_deref())
+            .or(self.region.map(|region| region.urls().web_vault))
+    }
+}
+
 impl Default for EnvironmentUrls {
     fn default() -> Self {
         Self {
+            region: Some(Region::Us),
             base: Some("https://bitwarden.com".to_string()),
             api: Some("https://api.bitwarden.com".to_string()),
             identity: Some("https://identity.bitwarden.com".to_string()),
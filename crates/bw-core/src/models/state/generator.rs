@@ -0,0 +1,157 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Email forwarding services that can create aliases
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ForwarderService {
+    SimpleLogin,
+    AddyIo,
+    Firefox,
+    Fastmail,
+    DuckDuckGo,
+    ForwardEmail,
+}
+
+impl ForwarderService {
+    pub const ALL: [ForwarderService; 6] = [
+        Self::SimpleLogin,
+        Self::AddyIo,
+        Self::Firefox,
+        Self::Fastmail,
+        Self::DuckDuckGo,
+        Self::ForwardEmail,
+    ];
+
+    /// Name used on the command line and in storage
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::SimpleLogin => "simplelogin",
+            Self::AddyIo => "addyio",
+            Self::Firefox => "firefox",
+            Self::Fastmail => "fastmail",
+            Self::DuckDuckGo => "duckduckgo",
+            Self::ForwardEmail => "forwardemail",
+        }
+    }
+
+    /// Whether aliases are created on a domain chosen by the user
+    pub fn requires_domain(&self) -> bool {
+        matches!(self, Self::AddyIo | Self::ForwardEmail)
+    }
+
+    /// Default API URL for services that can be self-hosted
+    pub fn default_base_url(&self) -> Option<&'static str> {
+        match self {
+            Self::SimpleLogin => Some("https://app.simplelogin.io"),
+            Self::AddyIo => Some("https://app.addy.io"),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ForwarderService {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ForwarderService {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "simplelogin" => Ok(Self::SimpleLogin),
+            "addyio" | "addy.io" | "anonaddy" => Ok(Self::AddyIo),
+            "firefox" | "firefoxrelay" => Ok(Self::Firefox),
+            "fastmail" => Ok(Self::Fastmail),
+            "duckduckgo" => Ok(Self::DuckDuckGo),
+            "forwardemail" => Ok(Self::ForwardEmail),
+            _ => Err(format!(
+                "Unknown forwarder '{}'. Expected one of: {}",
+                s,
+                Self::ALL.map(|f| f.as_str()).join(", ")
+            )),
+        }
+    }
+}
+
+/// Credentials for an email forwarding service
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ForwarderSettings {
+    /// API token or key, encrypted with the user key in storage
+    pub api_token: String,
+
+    /// Alias domain (addy.io and Forward Email)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub domain: Option<String>,
+
+    /// API URL for self-hosted SimpleLogin or addy.io
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base_url: Option<String>,
+}
+
+/// Kind of secret `bw generate` produces
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GeneratorType {
+    #[default]
+    Password,
+    Passphrase,
+}
+
+/// Saved generator options, used as the defaults for `bw generate`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase", default)]
+pub struct GeneratorOptions {
+    #[serde(rename = "type")]
+    pub generator_type: GeneratorType,
+
+    // Password options
+    pub length: u8,
+    pub lowercase: bool,
+    pub uppercase: bool,
+    pub numbers: bool,
+    pub special: bool,
+    pub min_lowercase: Option<u8>,
+    pub min_uppercase: Option<u8>,
+    pub min_number: Option<u8>,
+    pub min_special: Option<u8>,
+    pub avoid_ambiguous: bool,
+
+    // Passphrase options
+    pub num_words: u8,
+    pub word_separator: String,
+    pub capitalize: bool,
+    pub include_number: bool,
+    /// Custom wordlist file; the built-in EFF list when unset
+    pub wordlist: Option<String>,
+    /// Minimum passphrase entropy in bits
+    pub min_entropy: Option<f64>,
+}
+
+impl Default for GeneratorOptions {
+    fn default() -> Self {
+        Self {
+            generator_type: GeneratorType::Password,
+            length: 16,
+            lowercase: true,
+            uppercase: true,
+            numbers: true,
+            special: true,
+            min_lowercase: None,
+            min_uppercase: None,
+            min_number: None,
+            min_special: None,
+            avoid_ambiguous: false,
+            num_words: 3,
+            word_separator: "-".to_string(),
+            capitalize: false,
+            include_number: false,
+            wordlist: None,
+            min_entropy: None,
+        }
+    }
+}
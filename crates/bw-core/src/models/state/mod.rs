@@ -1,11 +1,15 @@
 mod auth;
 mod environment;
+mod generator;
 mod kdf;
+mod pin;
 mod user;
 mod vault;
 
 pub use auth::AuthState;
-pub use environment::EnvironmentUrls;
+pub use environment::{EnvironmentUrls, Region, RegionUrls};
+pub use generator::{ForwarderService, ForwarderSettings, GeneratorOptions, GeneratorType};
 pub use kdf::{KdfConfig, KdfType};
+pub use pin::{MAX_PIN_ATTEMPTS, PinState};
 pub use user::UserProfile;
 pub use vault::{OrgKey, VaultState};
@@ -0,0 +1,100 @@
+use serde::{Deserialize, Serialize};
+
+/// Wrong PINs allowed before PIN unlock is disabled
+///
+/// Once reached, the PIN-protected user key is dropped and the vault has to
+/// be unlocked with the master password, which re-enables the PIN.
+pub const MAX_PIN_ATTEMPTS: u32 = 5;
+
+/// PIN unlock state of an account
+///
+/// The user key is encrypted with a key derived from the PIN, using the
+/// account's KDF configuration and email. The PIN itself is kept encrypted
+/// with the user key, so the PIN-protected copy can be re-created after a
+/// master password unlock.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PinState {
+    /// The PIN, encrypted with the user key (EncString)
+    pub user_key_encrypted_pin: String,
+
+    /// The user key, encrypted with the PIN key (EncString)
+    ///
+    /// `None` while the master password is required, i.e. after a lock or
+    /// restart with `require_master_password` set or after too many wrong PINs.
+    #[serde(default)]
+    pub pin_key_encrypted_user_key: Option<String>,
+
+    /// Require the master password after `bw lock` or a system restart before
+    /// the PIN can be used again. Dropping the session without locking
+    /// doesn't count. Logging out always removes the PIN.
+    #[serde(default)]
+    pub require_master_password: bool,
+
+    /// System boot the PIN-protected user key was created in, with
+    /// `require_master_password` set
+    #[serde(default)]
+    pub boot_id: Option<String>,
+
+    /// Wrong PINs entered since the last successful unlock
+    #[serde(default)]
+    pub failed_attempts: u32,
+}
+
+impl PinState {
+    /// Whether `bw unlock --pin` can currently unlock the vault
+    pub fn is_available(&self) -> bool {
+        self.pin_key_encrypted_user_key.is_some() && self.failed_attempts < MAX_PIN_ATTEMPTS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pin_state_defaults_when_deserializing() {
+        let json = r#"{"userKeyEncryptedPin": "2.abc|def|ghi"}"#;
+        let state: PinState = serde_json::from_str(json).unwrap();
+
+        assert_eq!(state.user_key_encrypted_pin, "2.abc|def|ghi");
+        assert!(state.pin_key_encrypted_user_key.is_none());
+        assert!(!state.require_master_password);
+        assert_eq!(state.failed_attempts, 0);
+        assert!(state.boot_id.is_none());
+        assert!(!state.is_available());
+    }
+
+    #[test]
+    fn test_pin_state_unavailable_after_max_attempts() {
+        let mut state = PinState {
+            user_key_encrypted_pin: "2.abc|def|ghi".to_string(),
+            pin_key_encrypted_user_key: Some("2.jkl|mno|pqr".to_string()),
+            require_master_password: false,
+            failed_attempts: MAX_PIN_ATTEMPTS - 1,
+            boot_id: None,
+        };
+        assert!(state.is_available());
+
+        state.failed_attempts = MAX_PIN_ATTEMPTS;
+        assert!(!state.is_available());
+    }
+
+    #[test]
+    fn test_pin_state_serialization_uses_camel_case() {
+        let state = PinState {
+            user_key_encrypted_pin: "pin".to_string(),
+            pin_key_encrypted_user_key: Some("key".to_string()),
+            require_master_password: true,
+            failed_attempts: 2,
+            boot_id: Some("boot".to_string()),
+        };
+        let json = serde_json::to_value(&state).unwrap();
+
+        assert_eq!(json["userKeyEncryptedPin"], "pin");
+        assert_eq!(json["pinKeyEncryptedUserKey"], "key");
+        assert_eq!(json["requireMasterPassword"], true);
+        assert_eq!(json["failedAttempts"], 2);
+        assert_eq!(json["bootId"], "boot");
+    }
+}
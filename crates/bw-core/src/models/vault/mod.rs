@@ -3,6 +3,7 @@
 //! This module re-exports vault types from the Bitwarden SDK for use throughout the CLI.
 //! Custom types are only defined where the SDK doesn't provide suitable types.
 
+mod org_collection;
 mod organization;
 mod sync_response;
 mod validation_error;
@@ -46,6 +47,7 @@ pub use bitwarden_collections::collection::{Collection, CollectionId, Collection
 pub use bitwarden_core::OrganizationId;
 
 // CLI-specific types
+pub use org_collection::{CollectionAccessSelection, OrgCollectionView};
 pub use organization::*;
 pub use sync_response::{parse_sync_response, SyncData, VaultData};
 pub use validation_error::*;
@@ -54,5 +56,6 @@ pub use validation_error::*;
 pub use bitwarden_api_api::models::{
     SyncResponseModel,
     CipherRequestModel,
+    CipherCollectionsRequestModel,
     FolderRequestModel,
 };
@@ -0,0 +1,51 @@
+//! Organization collection models
+//!
+//! Used by the organization admin commands (`bw create/edit/delete
+//! org-collection`, `bw list org-collections`), which manage collections
+//! through the organization endpoints rather than the user's vault.
+
+use serde::{Deserialize, Serialize};
+
+/// A group's or member's access to a collection
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CollectionAccessSelection {
+    /// Group ID or organization user ID
+    pub id: String,
+    #[serde(default)]
+    pub read_only: bool,
+    #[serde(default)]
+    pub hide_passwords: bool,
+    /// Can manage the collection's access and items
+    #[serde(default)]
+    pub manage: bool,
+}
+
+/// Decrypted organization collection
+///
+/// Output of the org-collection commands and input of create/edit, so the
+/// server-assigned ID is optional.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrgCollectionView {
+    #[serde(default)]
+    pub id: Option<String>,
+
+    #[serde(default)]
+    pub organization_id: Option<String>,
+
+    /// Collection name (decrypted)
+    pub name: String,
+
+    /// Identifier from an external directory
+    #[serde(default)]
+    pub external_id: Option<String>,
+
+    /// Group access; omitted when not loaded
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub groups: Option<Vec<CollectionAccessSelection>>,
+
+    /// Member access; omitted when not loaded
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub users: Option<Vec<CollectionAccessSelection>>,
+}
@@ -10,16 +10,24 @@ pub struct Organization {
     pub id: String,
 
     /// Organization name (plain text)
+    #[serde(default)]
     pub name: String,
 
-    /// Status: 0=Invited, 1=Accepted, 2=Confirmed
-    pub status: u8,
+    /// The user's membership ID in this organization, used in collection
+    /// access lists
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization_user_id: Option<String>,
 
-    /// Organization type: 0=Owner, 1=Admin, 2=User, 3=Manager
-    #[serde(rename = "type")]
+    /// Status: -1=Revoked, 0=Invited, 1=Accepted, 2=Confirmed
+    #[serde(default)]
+    pub status: i8,
+
+    /// Organization type: 0=Owner, 1=Admin, 2=User, 3=Manager, 4=Custom
+    #[serde(rename = "type", default)]
     pub org_type: u8,
 
     /// Whether user is enabled
+    #[serde(default)]
     pub enabled: bool,
 
     /// Available features
@@ -44,8 +52,10 @@ pub struct Organization {
 }
 
 /// Organization permissions
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+///
+/// Custom-role permissions; the server omits the ones that don't apply.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
 pub struct OrganizationPermissions {
     pub access_business_portal: bool,
     pub access_event_logs: bool,
@@ -59,3 +69,36 @@ pub struct OrganizationPermissions {
     pub manage_users: bool,
     pub manage_reset_password: bool,
 }
+
+/// Organization member, as listed by the organization admin endpoints
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrganizationMember {
+    /// Organization user ID (not the account's user ID)
+    pub id: String,
+
+    /// Account user ID; not set until the invitation is accepted
+    #[serde(default)]
+    pub user_id: Option<String>,
+
+    #[serde(default)]
+    pub email: Option<String>,
+
+    #[serde(default)]
+    pub name: Option<String>,
+
+    /// Status: -1=Revoked, 0=Invited, 1=Accepted, 2=Confirmed
+    pub status: i8,
+
+    /// Member type: 0=Owner, 1=Admin, 2=User, 3=Manager, 4=Custom
+    #[serde(rename = "type")]
+    pub member_type: u8,
+
+    #[serde(default)]
+    pub two_factor_enabled: bool,
+}
+
+impl OrganizationMember {
+    /// Status of a member who accepted the invitation but isn't confirmed yet
+    pub const STATUS_ACCEPTED: i8 = 1;
+}
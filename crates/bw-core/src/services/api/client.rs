@@ -5,13 +5,16 @@ use crate::models::api::token::{TokenRefreshRequest, TokenResponse};
 use crate::services::storage::JsonFileStorage;
 use anyhow::Result;
 use async_trait::async_trait;
-use reqwest::{Client as ReqwestClient, Request, Response, StatusCode, header};
+use reqwest::{Client as ReqwestClient, Request, Response, StatusCode, header, multipart};
 use secrecy::ExposeSecret;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::Mutex;
 
+/// Azure Blob Storage REST API version used for attachment uploads
+const AZURE_STORAGE_VERSION: &str = "2020-04-08";
+
 /// Bitwarden API client implementation
 ///
 /// Features:
@@ -293,6 +296,53 @@ impl BitwardenApiClient {
         Ok(data)
     }
 
+    /// Post form data without mapping error responses
+    ///
+    /// Identity server errors carry structured data (such as the available
+    /// two-factor providers) that the generic error mapping discards, so the
+    /// caller receives the status code and raw body instead.
+    ///
+    /// # Returns
+    /// Response status and body text
+    pub async fn post_form_raw<T>(
+        &self,
+        path: &str,
+        body: &T,
+        extra_headers: Option<Vec<(&str, String)>>,
+    ) -> Result<(StatusCode, String)>
+    where
+        T: Serialize + Send + Sync,
+    {
+        let use_identity = path.contains("/identity/") || path.contains("/connect/");
+        let url = self.build_url(path, use_identity);
+
+        let mut request_builder = self
+            .http_client
+            .post(&url)
+            .header(
+                header::CONTENT_TYPE,
+                "application/x-www-form-urlencoded; charset=utf-8",
+            )
+            .header(header::ACCEPT, "application/json")
+            .form(body);
+
+        if let Some(headers) = extra_headers {
+            for (name, value) in headers {
+                request_builder = request_builder.header(name, value);
+            }
+        }
+
+        let response = self
+            .http_client
+            .execute(request_builder.build()?)
+            .await
+            .map_err(ApiError::network_error)?;
+        let status = response.status();
+        let text = response.text().await?;
+
+        Ok((status, text))
+    }
+
     /// Post JSON to identity server endpoint
     ///
     /// # Arguments
@@ -368,6 +418,119 @@ impl BitwardenApiClient {
 
         Ok(data)
     }
+
+    /// Upload a file to a pre-signed Azure blob storage URL
+    ///
+    /// The URL carries its own authorization (SAS token), so no bearer
+    /// token is sent.
+    pub async fn put_blob(&self, url: &str, contents: Vec<u8>) -> Result<()> {
+        let request = self
+            .http_client
+            .put(url)
+            .header("x-ms-blob-type", "BlockBlob")
+            .header("x-ms-version", AZURE_STORAGE_VERSION)
+            .header(
+                "x-ms-date",
+                chrono::Utc::now()
+                    .format("%a, %d %b %Y %H:%M:%S GMT")
+                    .to_string(),
+            )
+            .header(header::CONTENT_LENGTH, contents.len())
+            .body(contents)
+            .build()?;
+
+        self.execute_with_retry(request, false).await?;
+
+        Ok(())
+    }
+
+    /// Upload a file as the `data` form field alongside extra text fields
+    ///
+    /// Used where the server expects metadata in the same multipart body,
+    /// such as the re-encrypted key when sharing a legacy attachment.
+    pub async fn post_multipart_fields_with_auth(
+        &self,
+        path: &str,
+        fields: &[(&str, String)],
+        file_name: &str,
+        contents: Vec<u8>,
+    ) -> Result<()> {
+        let url = self.build_url(path, false);
+
+        let token = self
+            .token_manager
+            .get_access_token()
+            .await?
+            .ok_or_else(|| ApiError::Authentication {
+                message: "Not authenticated".to_string(),
+                hint: "Run 'bw login' to authenticate".to_string(),
+            })?;
+
+        let part = multipart::Part::bytes(contents)
+            .file_name(file_name.to_string())
+            .mime_str("application/octet-stream")?;
+        let form = fields
+            .iter()
+            .fold(multipart::Form::new(), |form, (name, value)| {
+                form.text(name.to_string(), value.clone())
+            })
+            .part("data", part);
+
+        // Multipart bodies can't be cloned, so this skips the refresh-and-retry path
+        let request = self
+            .http_client
+            .post(&url)
+            .header(
+                header::AUTHORIZATION,
+                format!("Bearer {}", token.expose_secret()),
+            )
+            .multipart(form)
+            .build()?;
+
+        let response = self
+            .http_client
+            .execute(request)
+            .await
+            .map_err(ApiError::network_error)?;
+        self.process_response(response).await?;
+
+        Ok(())
+    }
+
+    /// Download raw bytes from an absolute URL
+    ///
+    /// Used for attachment contents, which are served from short-lived
+    /// signed URLs rather than authenticated API paths.
+    pub async fn download(&self, url: &str) -> Result<Vec<u8>> {
+        let request = self.http_client.get(url).build()?;
+
+        let response = self.execute_with_retry(request, false).await?;
+        Ok(response.bytes().await?.to_vec())
+    }
+
+    /// GET an absolute URL outside the configured environment with a bearer token
+    ///
+    /// Used for services hosted separately from the Bitwarden server, such as
+    /// an organization's Key Connector.
+    ///
+    /// # Arguments
+    /// * `url` - Absolute URL
+    /// * `access_token` - Bearer token for authorization
+    pub async fn get_external_authenticated<R>(&self, url: &str, access_token: &str) -> Result<R>
+    where
+        R: for<'de> Deserialize<'de>,
+    {
+        let request = self
+            .http_client
+            .get(url)
+            .header(header::AUTHORIZATION, format!("Bearer {}", access_token))
+            .build()?;
+
+        let response = self.execute_with_retry(request, false).await?;
+        let data: R = response.json().await?;
+
+        Ok(data)
+    }
 }
 
 #[async_trait]
@@ -453,6 +616,24 @@ impl ApiClient for BitwardenApiClient {
         Ok(data)
     }
 
+    async fn post_no_response<T>(&self, path: &str, body: &T) -> Result<()>
+    where
+        T: Serialize + Send + Sync,
+    {
+        let url = self.build_url(path, false);
+
+        let request = self
+            .http_client
+            .post(&url)
+            .header(header::CONTENT_TYPE, "application/json")
+            .json(body)
+            .build()?;
+
+        let _response = self.execute_with_retry(request, false).await?;
+
+        Ok(())
+    }
+
     async fn post_with_auth<T, R>(&self, path: &str, body: &T) -> Result<R>
     where
         T: Serialize + Send + Sync,
@@ -486,6 +667,47 @@ impl ApiClient for BitwardenApiClient {
         Ok(data)
     }
 
+    async fn post_with_auth_no_response<T>(&self, path: &str, body: &T) -> Result<()>
+    where
+        T: Serialize + Send + Sync,
+    {
+        let url = self.build_url(path, false);
+
+        let token = self
+            .token_manager
+            .get_access_token()
+            .await?
+            .ok_or_else(|| ApiError::Authentication {
+                message: "Not authenticated".to_string(),
+                hint: "Run 'bw login' to authenticate".to_string(),
+            })?;
+
+        let request = self
+            .http_client
+            .post(&url)
+            .header(header::CONTENT_TYPE, "application/json")
+            .header(
+                header::AUTHORIZATION,
+                format!("Bearer {}", token.expose_secret()),
+            )
+            .json(body)
+            .build()?;
+
+        let _response = self.execute_with_retry(request, true).await?;
+
+        Ok(())
+    }
+
+    async fn post_multipart_with_auth(
+        &self,
+        path: &str,
+        file_name: &str,
+        contents: Vec<u8>,
+    ) -> Result<()> {
+        self.post_multipart_fields_with_auth(path, &[], file_name, contents)
+            .await
+    }
+
     async fn put_with_auth<T, R>(&self, path: &str, body: &T) -> Result<R>
     where
         T: Serialize + Send + Sync,
@@ -8,12 +8,21 @@ pub mod identity {
     /// KDF configuration lookup (prelogin)
     pub const PRELOGIN: &str = "/identity/accounts/prelogin";
 
-    /// OAuth2 token endpoint for password and API key login
+    /// OAuth2 token endpoint for password, API key and SSO login
     pub const TOKEN: &str = "/identity/connect/token";
 }
 
+/// Key Connector endpoints (relative to the organization's Key Connector URL)
+pub mod key_connector {
+    /// Master key for the authenticated user
+    pub const USER_KEYS: &str = "/user-keys";
+}
+
 /// API server endpoints
 pub mod api {
+    /// Server version and feature flags
+    pub const CONFIG: &str = "/config";
+
     /// User profile
     pub const PROFILE: &str = "/accounts/profile";
 
@@ -39,6 +48,49 @@ pub mod api {
         pub fn restore(id: &str) -> String {
             format!("/ciphers/{}/restore", id)
         }
+
+        /// Replace an organization cipher's collection assignments
+        pub fn collections(id: &str) -> String {
+            format!("/ciphers/{}/collections", id)
+        }
+
+        /// Move a personal cipher into an organization
+        pub fn share(id: &str) -> String {
+            format!("/ciphers/{}/share", id)
+        }
+
+        /// Request an upload slot for a new attachment
+        pub fn attachment_v2(id: &str) -> String {
+            format!("/ciphers/{}/attachment/v2", id)
+        }
+
+        /// Get, upload (direct) or delete an attachment
+        pub fn attachment(cipher_id: &str, attachment_id: &str) -> String {
+            format!("/ciphers/{}/attachment/{}", cipher_id, attachment_id)
+        }
+
+        /// Re-upload a legacy attachment encrypted for an organization
+        pub fn attachment_share(
+            cipher_id: &str,
+            attachment_id: &str,
+            organization_id: &str,
+        ) -> String {
+            format!(
+                "/ciphers/{}/attachment/{}/share?organizationId={}",
+                cipher_id, attachment_id, organization_id
+            )
+        }
+
+        /// Bulk import into the personal vault
+        pub const IMPORT: &str = "/ciphers/import";
+
+        /// Bulk import into an organization vault
+        pub fn import_organization(organization_id: &str) -> String {
+            format!(
+                "/ciphers/import-organization?organizationId={}",
+                organization_id
+            )
+        }
     }
 
     /// Folders endpoints
@@ -51,4 +103,88 @@ pub mod api {
             format!("/folders/{}", id)
         }
     }
+
+    /// Organization administration endpoints
+    pub mod organizations {
+        /// List/create collections in an organization
+        pub fn collections(organization_id: &str) -> String {
+            format!("/organizations/{}/collections", organization_id)
+        }
+
+        /// Update/delete an organization collection
+        pub fn collection(organization_id: &str, id: &str) -> String {
+            format!("/organizations/{}/collections/{}", organization_id, id)
+        }
+
+        /// List an organization's members
+        pub fn users(organization_id: &str) -> String {
+            format!("/organizations/{}/users", organization_id)
+        }
+
+        /// Get an organization member by organization user ID
+        pub fn user(organization_id: &str, id: &str) -> String {
+            format!("/organizations/{}/users/{}", organization_id, id)
+        }
+
+        /// Confirm an accepted member by sending them the organization key
+        pub fn confirm_user(organization_id: &str, id: &str) -> String {
+            format!("/organizations/{}/users/{}/confirm", organization_id, id)
+        }
+    }
+
+    /// User endpoints
+    pub mod users {
+        /// Get a user's public key
+        pub fn public_key(user_id: &str) -> String {
+            format!("/users/{}/public-key", user_id)
+        }
+    }
+
+    /// Send endpoints
+    pub mod sends {
+        /// Base path for Send operations (POST to create a text Send)
+        pub const BASE: &str = "/sends";
+
+        /// Request an upload slot for a new file Send
+        pub const FILE_V2: &str = "/sends/file/v2";
+
+        /// Get/update/delete Send by ID path
+        pub fn by_id(id: &str) -> String {
+            format!("/sends/{}", id)
+        }
+
+        /// Upload file contents directly to the API
+        pub fn file(send_id: &str, file_id: &str) -> String {
+            format!("/sends/{}/file/{}", send_id, file_id)
+        }
+
+        /// Remove the access password from a Send
+        pub fn remove_password(id: &str) -> String {
+            format!("/sends/{}/remove-password", id)
+        }
+
+        /// Public (anonymous) access to a Send by its access ID
+        pub fn access(access_id: &str) -> String {
+            format!("/sends/access/{}", access_id)
+        }
+
+        /// Request a download URL for a file Send's contents
+        pub fn access_file(send_id: &str, file_id: &str) -> String {
+            format!("/sends/{}/access/file/{}", send_id, file_id)
+        }
+    }
+
+    /// Device endpoints
+    pub mod devices {
+        /// Register the keys that make a device trusted
+        pub fn keys(identifier: &str) -> String {
+            format!("/devices/{}/keys", identifier)
+        }
+    }
+
+    /// Two-factor authentication endpoints
+    pub mod two_factor {
+        /// Send the email two-factor code during login
+        pub const SEND_EMAIL_LOGIN: &str = "/two-factor/send-email-login";
+    }
 }
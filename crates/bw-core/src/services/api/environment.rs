@@ -1,3 +1,4 @@
+use crate::models::state::{EnvironmentUrls, Region};
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use url::Url;
@@ -6,7 +7,7 @@ use url::Url;
 ///
 /// Resolves URLs for all Bitwarden server endpoints.
 /// Supports self-hosted installations with custom base URLs.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Environment {
     /// Base URL (e.g., "https://vault.bitwarden.com")
     base: String,
@@ -15,7 +16,7 @@ pub struct Environment {
     urls: ServiceUrls,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 struct ServiceUrls {
     /// API server URL (default: {base}/api)
     api: String,
@@ -88,6 +89,64 @@ impl Environment {
         Ok(Self { base, urls })
     }
 
+    /// Create environment from saved URLs
+    ///
+    /// Services without an override are derived from the base URL, or use
+    /// the region's cloud servers when no base URL is set. Overrides are
+    /// validated like the base URL.
+    pub fn from_urls(urls: &EnvironmentUrls) -> Result<Self> {
+        let mut env = match (&urls.base, urls.region) {
+            (Some(base), _) => Self::from_base_url(base)?,
+            (None, Some(region)) => Self::for_region(region),
+            (None, None) => Self::default_cloud(),
+        };
+
+        let normalize = |url: &Option<String>| {
+            url.as_deref()
+                .map(Self::validate_and_normalize_url)
+                .transpose()
+        };
+        if let Some(api) = normalize(&urls.api)? {
+            env.urls.api = api;
+        }
+        if let Some(identity) = normalize(&urls.identity)? {
+            env.urls.identity = identity;
+        }
+        if let Some(web_vault) = normalize(&urls.web_vault)? {
+            env.urls.web_vault = web_vault;
+        }
+        if let Some(icons) = normalize(&urls.icons)? {
+            env.urls.icons = icons;
+        }
+        if let Some(notifications) = normalize(&urls.notifications)? {
+            env.urls.notifications = notifications;
+        }
+        if let Some(events) = normalize(&urls.events)? {
+            env.urls.events = Some(events);
+        }
+
+        Ok(env)
+    }
+
+    /// Cloud environment of a region
+    ///
+    /// Each region uses separate domains for the different services, e.g.
+    /// `api.bitwarden.eu` and `identity.bitwarden.eu` for the EU region.
+    pub fn for_region(region: Region) -> Self {
+        let urls = region.urls();
+        Self {
+            base: urls.web_vault.to_string(),
+            urls: ServiceUrls {
+                api: urls.api.to_string(),
+                identity: urls.identity.to_string(),
+                web_vault: urls.web_vault.to_string(),
+                icons: urls.icons.to_string(),
+                notifications: urls.notifications.to_string(),
+                events: Some(urls.events.to_string()),
+            },
+        }
+    }
+
     /// Default cloud environment
     ///
     /// Note: Bitwarden cloud uses separate domains for different services:
@@ -95,17 +154,17 @@ impl Environment {
     /// - Identity: https://identity.bitwarden.com
     /// - Web Vault: https://vault.bitwarden.com
     pub fn default_cloud() -> Self {
-        Self {
-            base: "https://vault.bitwarden.com".to_string(),
-            urls: ServiceUrls {
-                api: "https://api.bitwarden.com".to_string(),
-                identity: "https://identity.bitwarden.com".to_string(),
-                web_vault: "https://vault.bitwarden.com".to_string(),
-                icons: "https://icons.bitwarden.net".to_string(),
-                notifications: "https://notifications.bitwarden.com".to_string(),
-                events: Some("https://events.bitwarden.com".to_string()),
-            },
-        }
+        Self::for_region(Region::Us)
+    }
+
+    /// Whether this is the default cloud environment
+    pub fn is_default_cloud(&self) -> bool {
+        *self == Self::default_cloud()
+    }
+
+    /// Get base URL
+    pub fn base_url(&self) -> &str {
+        &self.base
     }
 
     /// Get API base URL
@@ -210,4 +269,90 @@ mod tests {
         let env = Environment::from_base_url("https://vault.bitwarden.com/").unwrap();
         assert!(!env.api_url().ends_with("//api"));
     }
+
+    #[test]
+    fn test_from_urls_with_overrides() {
+        let urls = EnvironmentUrls {
+            region: None,
+            base: Some("https://my.server.com/".to_string()),
+            api: None,
+            identity: Some("https://id.server.com".to_string()),
+            web_vault: None,
+            icons: None,
+            notifications: None,
+            events: None,
+        };
+
+        let env = Environment::from_urls(&urls).unwrap();
+
+        assert_eq!(env.api_url(), "https://my.server.com/api");
+        assert_eq!(env.identity_url(), "https://id.server.com");
+        assert_eq!(env.web_vault_url(), "https://my.server.com");
+        assert!(!env.is_default_cloud());
+    }
+
+    #[test]
+    fn test_from_urls_without_base_uses_cloud() {
+        let urls = EnvironmentUrls {
+            region: None,
+            base: None,
+            api: None,
+            identity: None,
+            web_vault: None,
+            icons: None,
+            notifications: None,
+            events: Some("https://events.example.com".to_string()),
+        };
+
+        let env = Environment::from_urls(&urls).unwrap();
+
+        assert_eq!(env.api_url(), "https://api.bitwarden.com");
+        assert_eq!(env.events_url(), Some("https://events.example.com"));
+    }
+
+    #[test]
+    fn test_from_urls_validates_overrides() {
+        let urls = EnvironmentUrls {
+            region: None,
+            base: None,
+            api: Some("http://remote.server.com".to_string()),
+            identity: None,
+            web_vault: None,
+            icons: None,
+            notifications: None,
+            events: None,
+        };
+
+        assert!(Environment::from_urls(&urls).is_err());
+    }
+
+    #[test]
+    fn test_eu_region() {
+        let env = Environment::for_region(Region::Eu);
+        assert_eq!(env.api_url(), "https://api.bitwarden.eu");
+        assert_eq!(env.identity_url(), "https://identity.bitwarden.eu");
+        assert_eq!(env.icons_url(), "https://icons.bitwarden.eu");
+        assert_eq!(env.events_url(), Some("https://events.bitwarden.eu"));
+        assert!(!env.is_default_cloud());
+        assert!(Environment::for_region(Region::Us).is_default_cloud());
+    }
+
+    #[test]
+    fn test_from_urls_with_region() {
+        let urls = EnvironmentUrls {
+            region: Some(Region::Eu),
+            base: None,
+            api: None,
+            identity: None,
+            web_vault: None,
+            icons: None,
+            notifications: Some("https://push.example.com".to_string()),
+            events: None,
+        };
+
+        let env = Environment::from_urls(&urls).unwrap();
+
+        assert_eq!(env.api_url(), "https://api.bitwarden.eu");
+        assert_eq!(env.notifications_url(), "https://push.example.com");
+    }
 }
@@ -0,0 +1,207 @@
+//! Server environment configuration
+//!
+//! `bw config server` saves the server URLs globally and they are copied to
+//! the account on login, like the TypeScript CLI. The active account's copy
+//! wins, so an account keeps talking to the server it logged in to.
+
+use super::{client::BitwardenApiClient, endpoints, environment::Environment, traits::ApiClient};
+use crate::models::api::ServerConfigResponse;
+use crate::models::state::{EnvironmentUrls, Region};
+use crate::services::storage::{AccountManager, JsonFileStorage, Storage, StorageKey};
+use anyhow::{Context, Result, bail};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+/// Request timeout when checking a server before saving it
+const SERVER_CHECK_TIMEOUT_SECONDS: u64 = 15;
+
+/// Service for reading and saving the configured server
+pub struct EnvironmentService {
+    storage: Arc<Mutex<JsonFileStorage>>,
+    account_manager: Arc<AccountManager>,
+}
+
+impl EnvironmentService {
+    /// Create new environment service
+    pub fn new(storage: Arc<Mutex<JsonFileStorage>>, account_manager: Arc<AccountManager>) -> Self {
+        Self {
+            storage,
+            account_manager,
+        }
+    }
+
+    /// Get the configured server URLs, `None` for the default cloud
+    pub async fn get_urls(&self) -> Result<Option<EnvironmentUrls>> {
+        let storage = self.storage.lock().await;
+        Self::stored_urls(&storage)
+    }
+
+    /// Check a server and save its URLs for the next login
+    ///
+    /// The server's `/api/config` is fetched first, so an unreachable server
+    /// or one that isn't Bitwarden is never saved. `None` selects the default
+    /// cloud, which is saved without a check and returns no server config.
+    pub async fn configure(
+        &self,
+        urls: Option<EnvironmentUrls>,
+    ) -> Result<Option<ServerConfigResponse>> {
+        // 1. Refuse while logged in and validate the URLs
+        self.ensure_logged_out().await?;
+        let environment = Self::resolve(urls.as_ref())?;
+
+        // 2. Ask a custom server for its version and feature flags
+        let server_config = match urls {
+            Some(_) => Some(self.fetch_server_config(&environment).await?),
+            None => None,
+        };
+
+        // 3. Save
+        self.set_urls(urls).await?;
+        Ok(server_config)
+    }
+
+    /// Save the server URLs used by the next login, without checking them
+    ///
+    /// `None` restores the default cloud. Refused while logged in, since the
+    /// account's tokens belong to the current server.
+    pub async fn set_urls(&self, urls: Option<EnvironmentUrls>) -> Result<Environment> {
+        // 1. The server can't change under the account being configured
+        let user_id = self.ensure_logged_out().await?;
+
+        // 2. Validate the URLs before saving them
+        let environment = Self::resolve(urls.as_ref())?;
+
+        // 3. Other logged in accounts keep the server they logged in to
+        self.pin_logged_in_accounts(user_id.as_deref()).await?;
+
+        // 4. Save globally, and for the account being configured, whose copy
+        //    would otherwise shadow the new URLs
+        let mut keys = vec![StorageKey::GlobalEnvironment.format(None)];
+        if let Some(user_id) = &user_id {
+            keys.push(StorageKey::UserEnvironment.format(Some(user_id)));
+        }
+
+        let mut storage = self.storage.lock().await;
+        for key in &keys {
+            match &urls {
+                Some(urls) => storage.set(key, urls).await?,
+                None => {
+                    storage.remove(key).await?;
+                }
+            }
+        }
+
+        Ok(environment)
+    }
+
+    /// Fetch a server's configuration from `/api/config`
+    pub async fn fetch_server_config(
+        &self,
+        environment: &Environment,
+    ) -> Result<ServerConfigResponse> {
+        let api_client = BitwardenApiClient::new(
+            environment.clone(),
+            Arc::clone(&self.storage),
+            Some(SERVER_CHECK_TIMEOUT_SECONDS),
+        )?;
+
+        api_client
+            .get(endpoints::api::CONFIG)
+            .await
+            .with_context(|| {
+                format!(
+                    "Unable to fetch the server configuration from {}",
+                    environment.api_url()
+                )
+            })
+    }
+
+    /// Copy the configured server URLs to an account that just logged in
+    pub async fn save_for_user(storage: &mut JsonFileStorage, user_id: &str) -> Result<()> {
+        let urls: Option<EnvironmentUrls> =
+            storage.get(&StorageKey::GlobalEnvironment.format(None))?;
+        let key = StorageKey::UserEnvironment.format(Some(user_id));
+
+        match urls {
+            Some(urls) => storage.set(&key, &urls).await,
+            None => storage.remove(&key).await.map(|_| ()),
+        }
+    }
+
+    /// Load the configured environment at startup
+    ///
+    /// Takes the storage directly, as services aren't created yet.
+    pub fn load(storage: &JsonFileStorage) -> Result<Environment> {
+        Self::resolve(Self::stored_urls(storage)?.as_ref())
+    }
+
+    /// Refuse while the account being configured is logged in
+    ///
+    /// That's the `--account` account, or else the active one. Returns its
+    /// user ID, if there is one.
+    async fn ensure_logged_out(&self) -> Result<Option<String>> {
+        let user_id = self.account_manager.get_active_user_id().await?;
+        if let Some(user_id) = &user_id {
+            if self.account_manager.is_account_logged_in(user_id).await? {
+                bail!("Logout required before server config update.");
+            }
+        }
+        Ok(user_id)
+    }
+
+    /// Copy the global URLs to other logged in accounts that don't have their own
+    ///
+    /// Such accounts follow the global URLs, so without a copy saving new
+    /// ones would move them to a server they never logged in to.
+    async fn pin_logged_in_accounts(&self, configured: Option<&str>) -> Result<()> {
+        let mut logged_in = Vec::new();
+        for user_id in self.account_manager.get_all_accounts().await?.into_keys() {
+            if Some(user_id.as_str()) != configured
+                && self.account_manager.is_account_logged_in(&user_id).await?
+            {
+                logged_in.push(user_id);
+            }
+        }
+
+        let mut storage = self.storage.lock().await;
+        // No global URLs means the default cloud
+        let global_urls = storage
+            .get(&StorageKey::GlobalEnvironment.format(None))?
+            .unwrap_or(EnvironmentUrls {
+                region: Some(Region::Us),
+                base: None,
+                api: None,
+                identity: None,
+                web_vault: None,
+                icons: None,
+                notifications: None,
+                events: None,
+            });
+        for user_id in logged_in {
+            let key = StorageKey::UserEnvironment.format(Some(&user_id));
+            if storage.get::<EnvironmentUrls>(&key)?.is_none() {
+                storage.set(&key, &global_urls).await?;
+            }
+        }
+        Ok(())
+    }
+
+    fn resolve(urls: Option<&EnvironmentUrls>) -> Result<Environment> {
+        match urls {
+            Some(urls) => Environment::from_urls(urls),
+            None => Ok(Environment::default_cloud()),
+        }
+    }
+
+    /// The active account's URLs, falling back to the global ones
+    fn stored_urls(storage: &JsonFileStorage) -> Result<Option<EnvironmentUrls>> {
+        if let Some(user_id) = storage.active_user_id()? {
+            let urls = storage.get(&StorageKey::UserEnvironment.format(Some(&user_id)))?;
+            if urls.is_some() {
+                return Ok(urls);
+            }
+        }
+
+        storage.get(&StorageKey::GlobalEnvironment.format(None))
+    }
+}
@@ -1,6 +1,7 @@
 mod client;
 pub mod endpoints;
 mod environment;
+mod environment_service;
 mod errors;
 mod token_manager;
 mod traits;
@@ -8,5 +9,6 @@ mod traits;
 // Public exports
 pub use client::BitwardenApiClient;
 pub use environment::Environment;
+pub use environment_service::EnvironmentService;
 pub use errors::ApiError;
 pub use traits::ApiClient;
@@ -56,13 +56,9 @@ impl TokenManager {
     async fn get_user_token(&self, key_type: StorageKey) -> Result<Option<Secret<String>>> {
         let storage = self.storage.lock().await;
 
-        // Get active user ID using namespaced key
-        let active_id_key = StorageKey::GlobalActiveAccountId.format(None);
-        let active_id: Option<serde_json::Value> = storage.get(&active_id_key)?;
-
-        let user_id = match active_id {
-            Some(serde_json::Value::String(id)) if !id.is_empty() => id,
-            _ => return Ok(None),
+        // Get active user ID
+        let Some(user_id) = storage.active_user_id()? else {
+            return Ok(None);
         };
 
         // Get token for this user using namespaced key
@@ -147,13 +143,9 @@ impl TokenManager {
             let mut storage = self.storage.lock().await;
 
             // Get active user ID
-            let active_id_key = StorageKey::GlobalActiveAccountId.format(None);
-            let active_id: Option<serde_json::Value> = storage.get(&active_id_key)?;
-
-            let user_id = match active_id {
-                Some(serde_json::Value::String(id)) if !id.is_empty() => id,
-                _ => return Err(anyhow::anyhow!("No active user during token refresh")),
-            };
+            let user_id = storage
+                .active_user_id()?
+                .ok_or_else(|| anyhow::anyhow!("No active user during token refresh"))?;
 
             let access_token_key = StorageKey::UserAccessToken.format(Some(&user_id));
             storage
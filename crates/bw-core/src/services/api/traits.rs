@@ -61,6 +61,14 @@ pub trait ApiClient: Send + Sync {
         T: Serialize + Send + Sync,
         R: for<'de> Deserialize<'de>;
 
+    /// Make an unauthenticated POST request with no response body
+    ///
+    /// For pre-login operations that don't return data (like sending a
+    /// two-factor email code).
+    async fn post_no_response<T>(&self, path: &str, body: &T) -> Result<()>
+    where
+        T: Serialize + Send + Sync;
+
     /// Make an authenticated POST request
     ///
     /// Automatically includes Bearer token in Authorization header.
@@ -70,6 +78,24 @@ pub trait ApiClient: Send + Sync {
         T: Serialize + Send + Sync,
         R: for<'de> Deserialize<'de>;
 
+    /// Make an authenticated POST request with no response body
+    ///
+    /// For operations that don't return data (like bulk import).
+    async fn post_with_auth_no_response<T>(&self, path: &str, body: &T) -> Result<()>
+    where
+        T: Serialize + Send + Sync;
+
+    /// Make an authenticated multipart POST request with a single file part
+    ///
+    /// The file is sent as the `data` form field. Used for direct
+    /// attachment uploads.
+    async fn post_multipart_with_auth(
+        &self,
+        path: &str,
+        file_name: &str,
+        contents: Vec<u8>,
+    ) -> Result<()>;
+
     /// Make an authenticated PUT request
     ///
     /// Updates an existing resource with provided data.
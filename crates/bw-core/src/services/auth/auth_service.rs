@@ -1,23 +1,34 @@
 use crate::models::{
     api::{
-        ApiKeyLoginRequest, LoginResponse, PasswordLoginRequest, PreloginRequest, PreloginResponse,
-        ProfileResponse,
+        ApiErrorResponse, ApiKeyLoginRequest, DeviceKeysRequest, KeyConnectorUserKeyResponse,
+        LoginResponse, PasswordLoginRequest, PreloginRequest, PreloginResponse, ProfileResponse,
+        SsoLoginRequest, TwoFactorEmailRequest,
     },
-    auth::{DeviceInfo, LoginResult, TwoFactorData, UnlockResult},
-    state::{KdfConfig, KdfType},
+    auth::{
+        DeviceInfo, LoginResult, REMEMBER_PROVIDER_CODE, TwoFactorData, TwoFactorMethod,
+        UnlockResult,
+    },
+    state::{KdfConfig, KdfType, MAX_PIN_ATTEMPTS, PinState},
 };
 use crate::services::{
-    api::{ApiClient, BitwardenApiClient, endpoints},
-    auth::{errors::AuthError, session_manager::SessionManager},
+    api::{ApiClient, BitwardenApiClient, EnvironmentService, endpoints},
+    auth::{
+        boot,
+        errors::AuthError,
+        session_manager::SessionManager,
+        sso::{PendingSsoLogin, SSO_CLIENT_ID, SsoDecryption},
+    },
     crypto,
+    key_service::KeyService,
     storage::{
         AccountManager, JsonFileStorage, Storage, StorageKey, encrypt_user_key, format_session_key,
         generate_session_key, make_protected_key, user_key_protected_storage_key,
     },
 };
 use anyhow::Result;
-use bitwarden_crypto::{CryptoError, Kdf, MasterKey, SymmetricCryptoKey};
+use bitwarden_crypto::{CryptoError, DeviceKey, Kdf, MasterKey, PinKey, SymmetricCryptoKey};
 use secrecy::{ExposeSecret, Secret};
+use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 use tracing::{debug, info, warn};
@@ -27,7 +38,8 @@ use tracing::{debug, info, warn};
 /// Handles all authentication flows including:
 /// - Password-based login
 /// - API key login
-/// - Vault unlock
+/// - SSO login
+/// - Vault unlock (master password or PIN)
 /// - Lock/logout operations
 pub struct AuthService {
     storage: Arc<Mutex<JsonFileStorage>>,
@@ -88,10 +100,22 @@ impl AuthService {
         debug!("Hashing password for authentication");
         let hashed_password = self.hash_password_for_auth(&password, &master_key).await?;
 
-        // Step 4: Authenticate with server
+        // Step 4: Authenticate with server, using a remembered 2FA token if we have one
         debug!("Authenticating with server");
         let device_info = self.get_device_info().await?;
-        let login_response = self
+        let remembered = match two_factor {
+            Some(_) => None,
+            None => self.get_two_factor_token(email).await?,
+        };
+        let using_remembered = remembered.is_some();
+        let two_factor = two_factor.or(remembered.map(|token| TwoFactorData {
+            token,
+            provider: REMEMBER_PROVIDER_CODE,
+            remember: false,
+        }));
+        let remember_requested = two_factor.as_ref().is_some_and(|tf| tf.remember);
+
+        let login_response = match self
             .authenticate_password(
                 email,
                 &hashed_password,
@@ -99,7 +123,23 @@ impl AuthService {
                 two_factor,
                 new_device_otp,
             )
-            .await?;
+            .await
+        {
+            Ok(response) => response,
+            Err(AuthError::TwoFactorRequired { providers }) if using_remembered => {
+                // Remembered token was rejected (expired or revoked)
+                self.clear_two_factor_token(email).await?;
+                return Err(AuthError::TwoFactorRequired { providers });
+            }
+            Err(e) => return Err(e),
+        };
+
+        if remember_requested {
+            if let Some(ref token) = login_response.two_factor_token {
+                debug!("Remembering two-factor token for this device");
+                self.set_two_factor_token(email, token).await?;
+            }
+        }
 
         // Step 5: Decrypt user key (if available)
         let user_key = if let Some(ref encrypted_key) = login_response.key {
@@ -159,6 +199,32 @@ impl AuthService {
         })
     }
 
+    /// Send the email two-factor code for a password login
+    ///
+    /// The server requires the master password hash to prove the request
+    /// comes from someone who knows the password.
+    pub async fn send_two_factor_email(
+        &self,
+        email: &str,
+        password: &Secret<String>,
+    ) -> Result<(), AuthError> {
+        let kdf_config = self.fetch_kdf_config(email).await?;
+        let master_key = self.derive_master_key(password, email, &kdf_config).await?;
+        let master_password_hash = self.hash_password_for_auth(password, &master_key).await?;
+        let device_info = self.get_device_info().await?;
+
+        let request = TwoFactorEmailRequest {
+            email: email.to_string(),
+            master_password_hash,
+            device_identifier: device_info.device_identifier.to_string(),
+        };
+
+        self.api_client
+            .post_no_response(endpoints::api::two_factor::SEND_EMAIL_LOGIN, &request)
+            .await
+            .map_err(|e| AuthError::Other(format!("Failed to send two-factor email: {}", e)))
+    }
+
     /// Login with API key
     ///
     /// # Arguments
@@ -223,6 +289,215 @@ impl AuthService {
         })
     }
 
+    /// Exchange an SSO authorization code for tokens
+    ///
+    /// The account is authenticated but the vault is not yet decrypted; pass
+    /// the result to `complete_sso_login`. If two-step login is required the
+    /// same code can be exchanged again with `two_factor` filled in.
+    ///
+    /// # Arguments
+    /// * `code` - Authorization code received on the redirect URI
+    /// * `code_verifier` - PKCE verifier for the authorize request
+    /// * `redirect_uri` - Redirect URI used for the authorize request
+    /// * `two_factor` - Optional 2FA data (if 2FA is required)
+    pub async fn login_with_sso(
+        &self,
+        code: &str,
+        code_verifier: &str,
+        redirect_uri: &str,
+        two_factor: Option<TwoFactorData>,
+    ) -> Result<PendingSsoLogin, AuthError> {
+        info!("Starting SSO login");
+
+        // Step 1: Exchange the authorization code
+        debug!("Exchanging authorization code");
+        let device_info = self.get_device_info().await?;
+        let request = SsoLoginRequest {
+            grant_type: "authorization_code".to_string(),
+            code: code.to_string(),
+            code_verifier: code_verifier.to_string(),
+            redirect_uri: redirect_uri.to_string(),
+            scope: "api offline_access".to_string(),
+            client_id: SSO_CLIENT_ID.to_string(),
+            device_type: device_info.device_type,
+            device_name: device_info.device_name.clone(),
+            device_identifier: device_info.device_identifier.to_string(),
+            two_factor_token: two_factor.as_ref().map(|tf| tf.token.clone()),
+            two_factor_provider: two_factor.as_ref().map(|tf| tf.provider),
+            two_factor_remember: two_factor
+                .as_ref()
+                .map(|tf| if tf.remember { 1 } else { 0 }),
+        };
+        let extra_headers = vec![("Device-Type", device_info.device_type.to_string())];
+
+        let (status, body) = self
+            .api_client
+            .post_form_raw(endpoints::identity::TOKEN, &request, Some(extra_headers))
+            .await
+            .map_err(|e| AuthError::InvalidCredentials {
+                message: format!("SSO authentication failed: {}", e),
+            })?;
+
+        if !status.is_success() {
+            return Err(Self::parse_identity_error(&body));
+        }
+
+        let login_response: LoginResponse =
+            serde_json::from_str(&body).map_err(|e| AuthError::InvalidCredentials {
+                message: format!("SSO authentication failed: invalid token response: {}", e),
+            })?;
+
+        // Step 2: Fetch user profile
+        debug!("Fetching user profile");
+        let profile = self.fetch_profile(&login_response.access_token).await?;
+
+        // Step 3: Decide how the user key will be decrypted
+        let decryption = self
+            .select_sso_decryption(&login_response, &profile.id)
+            .await?;
+        debug!("SSO decryption method: {:?}", decryption);
+
+        Ok(PendingSsoLogin {
+            login_response,
+            profile,
+            decryption,
+        })
+    }
+
+    /// Decrypt the vault and persist state for an authenticated SSO login
+    ///
+    /// # Arguments
+    /// * `pending` - Result of `login_with_sso`
+    /// * `master_password` - Required when `pending.requires_master_password()`
+    ///
+    /// # Returns
+    /// LoginResult with session key for BW_SESSION export
+    pub async fn complete_sso_login(
+        &self,
+        pending: PendingSsoLogin,
+        master_password: Option<Secret<String>>,
+    ) -> Result<LoginResult, AuthError> {
+        let PendingSsoLogin {
+            login_response,
+            profile,
+            decryption,
+        } = pending;
+        let kdf_config = Self::kdf_config_from_login_response(&login_response);
+
+        // Organizations with trusted device encryption let this device skip
+        // the master password or Key Connector on the next SSO login
+        let trust_device = decryption != SsoDecryption::TrustedDevice
+            && login_response
+                .user_decryption_options
+                .as_ref()
+                .is_some_and(|o| o.trusted_device_option.is_some());
+
+        // Step 1: Decrypt user key
+        let user_key = match decryption {
+            SsoDecryption::MasterPassword => {
+                let password = master_password.ok_or_else(|| {
+                    AuthError::Other("Master password is required to unlock the vault".to_string())
+                })?;
+                let encrypted_key = login_response.key.as_deref().ok_or_else(|| {
+                    AuthError::CryptoOperationFailed {
+                        message: "No user key in login response".to_string(),
+                    }
+                })?;
+
+                debug!("Deriving master key");
+                let master_key = self
+                    .derive_master_key(&password, &profile.email, &kdf_config)
+                    .await?;
+                self.decrypt_user_key(encrypted_key, &master_key)
+                    .await
+                    .map_err(|_| AuthError::InvalidPassword)?
+            }
+            SsoDecryption::KeyConnector { url } => {
+                let encrypted_key = login_response.key.as_deref().ok_or_else(|| {
+                    AuthError::CryptoOperationFailed {
+                        message: "No user key in login response".to_string(),
+                    }
+                })?;
+
+                debug!("Fetching master key from Key Connector");
+                let response: KeyConnectorUserKeyResponse = self
+                    .api_client
+                    .get_external_authenticated(
+                        &format!(
+                            "{}{}",
+                            url.trim_end_matches('/'),
+                            endpoints::key_connector::USER_KEYS
+                        ),
+                        &login_response.access_token,
+                    )
+                    .await
+                    .map_err(|e| {
+                        AuthError::Other(format!("Key Connector request failed: {}", e))
+                    })?;
+
+                let master_key = crypto::master_key_from_b64(&response.key)?;
+                self.decrypt_user_key(encrypted_key, &master_key).await?
+            }
+            SsoDecryption::TrustedDevice => {
+                debug!("Decrypting user key with device key");
+                let option = login_response
+                    .user_decryption_options
+                    .as_ref()
+                    .and_then(|o| o.trusted_device_option.as_ref());
+                let (Some(private_key), Some(user_key)) = (
+                    option.and_then(|o| o.encrypted_private_key.as_deref()),
+                    option.and_then(|o| o.encrypted_user_key.as_deref()),
+                ) else {
+                    return Err(AuthError::CryptoOperationFailed {
+                        message: "Trusted device keys missing from login response".to_string(),
+                    });
+                };
+
+                let storage = self.storage.lock().await;
+                let device_key: String = storage
+                    .get(&StorageKey::UserDeviceKey.format(Some(&profile.id)))?
+                    .ok_or_else(|| AuthError::CryptoOperationFailed {
+                        message: "Device key not found in storage".to_string(),
+                    })?;
+                drop(storage);
+
+                crypto::decrypt_user_key_with_device_key(&device_key, private_key, user_key)?
+            }
+        };
+
+        // Step 2: Store user key in protected storage
+        let session_key_str = self
+            .store_protected_user_key(&profile.id, &user_key)
+            .await?;
+
+        // Step 3: Persist authentication state
+        debug!("Persisting authentication state");
+        self.persist_auth_state(
+            &profile.id,
+            &profile.email,
+            &login_response.access_token,
+            &login_response.refresh_token,
+            login_response.key.as_deref(),
+            &kdf_config,
+        )
+        .await?;
+
+        // Step 4: Trust this device; the login itself already succeeded
+        if trust_device {
+            if let Err(e) = self.trust_device(&profile.id, &user_key).await {
+                warn!("Failed to trust this device: {}", e);
+            }
+        }
+
+        info!("SSO login successful");
+
+        Ok(LoginResult {
+            user_id: profile.id,
+            email: profile.email,
+            session_key: session_key_str,
+        })
+    }
+
     /// Unlock vault with master password
     ///
     /// # Arguments
@@ -233,79 +508,194 @@ impl AuthService {
     pub async fn unlock(&self, password: Secret<String>) -> Result<UnlockResult, AuthError> {
         info!("Starting vault unlock");
 
-        // Get active user ID
-        let user_id = self
-            .account_manager
-            .get_active_user_id()
-            .await?
-            .ok_or(AuthError::NotLoggedIn)?;
+        let (user_id, user_key) = self.decrypt_user_key_with_password(&password).await?;
 
-        // Get account info for email
-        let account = self
-            .account_manager
-            .get_account(&user_id)
-            .await?
-            .ok_or(AuthError::NotLoggedIn)?;
+        // Store user key in protected storage under a new session key
+        let session_key_str = self.store_protected_user_key(&user_id, &user_key).await?;
 
-        let email = account.email;
+        // The master password re-enables a PIN that was locked out or
+        // required it after locking
+        if let Err(e) = self.restore_pin_unlock(&user_id, &user_key).await {
+            warn!("Failed to re-enable PIN unlock: {}", e);
+        }
 
-        // Load KDF configuration using namespaced key
-        let storage = self.storage.lock().await;
-        let kdf_key = StorageKey::UserKdfConfig.format(Some(&user_id));
-        let kdf_config: KdfConfig = storage.get(&kdf_key)?.ok_or_else(|| AuthError::KdfError {
-            message: "KDF configuration not found in storage".to_string(),
-        })?;
+        info!("Vault unlock successful");
 
-        // Load encrypted user key using namespaced key
-        let user_key_key = StorageKey::UserKey.format(Some(&user_id));
-        let encrypted_user_key: Option<String> = storage.get(&user_key_key)?;
+        Ok(UnlockResult {
+            session_key: session_key_str,
+        })
+    }
 
-        drop(storage); // Release lock
+    /// Unlock vault with a PIN set with [`AuthService::set_pin`]
+    ///
+    /// After [`MAX_PIN_ATTEMPTS`] wrong PINs the PIN-protected user key is
+    /// dropped and the next unlock has to use the master password.
+    ///
+    /// # Returns
+    /// UnlockResult with session key for BW_SESSION export
+    pub async fn unlock_with_pin(&self, pin: Secret<String>) -> Result<UnlockResult, AuthError> {
+        info!("Starting vault unlock with PIN");
 
-        let encrypted_user_key =
-            encrypted_user_key.ok_or_else(|| AuthError::CryptoOperationFailed {
-                message: "User key not found in storage".to_string(),
-            })?;
+        // 1. Load the PIN-protected user key
+        let (user_id, email, kdf_config) = self.get_active_account_kdf().await?;
+        let mut pin_state = self
+            .get_pin_state(&user_id)
+            .await?
+            .ok_or(AuthError::PinNotSet)?;
+        let encrypted_user_key = match &pin_state.pin_key_encrypted_user_key {
+            Some(key) if pin_state.failed_attempts < MAX_PIN_ATTEMPTS => key.clone(),
+            _ => return Err(AuthError::PinUnavailable),
+        };
 
-        // Derive master key
-        debug!("Deriving master key for unlock");
-        let master_key = self
-            .derive_master_key(&password, &email, &kdf_config)
-            .await?;
+        // 2. Derive the PIN key and try to decrypt the user key
+        debug!("Deriving PIN key");
+        let pin_key = self.derive_pin_key(&pin, &email, &kdf_config).await?;
+        let user_key = match crypto::decrypt_user_key_with_pin(&pin_key, &encrypted_user_key) {
+            Ok(user_key) => user_key,
+            // A wrong PIN derives a key that fails the MAC check; anything
+            // else is a problem with the stored key, not the PIN
+            Err(CryptoError::InvalidMac) => {
+                // 3. Count the wrong PIN, dropping the key once out of attempts
+                pin_state.failed_attempts += 1;
+                let remaining = MAX_PIN_ATTEMPTS.saturating_sub(pin_state.failed_attempts);
+                if remaining == 0 {
+                    pin_state.pin_key_encrypted_user_key = None;
+                }
+                self.save_pin_state(&user_id, &pin_state).await?;
+
+                warn!("Invalid PIN, {} attempts remaining", remaining);
+                return Err(if remaining == 0 {
+                    AuthError::PinLockedOut
+                } else {
+                    AuthError::InvalidPin { remaining }
+                });
+            }
+            Err(e) => return Err(e.into()),
+        };
 
-        // Try to decrypt user key (validates password)
-        debug!("Decrypting user key");
-        let user_key = self
-            .decrypt_user_key(&encrypted_user_key, &master_key)
+        // 4. Reset the attempts and store the user key under a new session key
+        if pin_state.failed_attempts > 0 {
+            pin_state.failed_attempts = 0;
+            self.save_pin_state(&user_id, &pin_state).await?;
+        }
+        let session_key_str = self.store_protected_user_key(&user_id, &user_key).await?;
+
+        info!("Vault unlock with PIN successful");
+
+        Ok(UnlockResult {
+            session_key: session_key_str,
+        })
+    }
+
+    /// Set up PIN unlock for the active account
+    ///
+    /// The vault must be unlocked, since the user key from `session` is what
+    /// the PIN protects.
+    ///
+    /// # Arguments
+    /// * `pin` - The new PIN
+    /// * `require_master_password` - Require a master password unlock after
+    ///   an explicit [`AuthService::lock`] or a system restart before the PIN
+    ///   can be used again
+    /// * `session` - Session key of the unlocked vault
+    pub async fn set_pin(
+        &self,
+        pin: Secret<String>,
+        require_master_password: bool,
+        session: &str,
+    ) -> Result<(), AuthError> {
+        if pin.expose_secret().is_empty() {
+            return Err(AuthError::Other("PIN cannot be empty".to_string()));
+        }
+        let boot_id = if require_master_password {
+            Some(boot::current_boot_id().ok_or_else(|| {
+                AuthError::Other(
+                    "Requiring the master password after a restart isn't supported on this system"
+                        .to_string(),
+                )
+            })?)
+        } else {
+            None
+        };
+
+        // 1. Get the user key of the unlocked vault
+        let (user_id, email, kdf_config) = self.get_active_account_kdf().await?;
+        let key_service =
+            KeyService::new(Arc::clone(&self.storage), Arc::clone(&self.account_manager));
+        let user_key = key_service
+            .get_user_key(session)
             .await
-            .map_err(|_| AuthError::InvalidPassword)?;
+            .map_err(|e| AuthError::Other(e.to_string()))?;
 
-        // Generate new session key using SDK
-        debug!("Generating session key");
-        let session_key = generate_session_key();
-        let session_key_str = format_session_key(&session_key);
+        // 2. Protect the user key with the PIN, and the PIN with the user key
+        let pin_key_encrypted_user_key = self
+            .encrypt_user_key_with_pin(&pin, &email, &kdf_config, &user_key)
+            .await?;
+        let pin_state = PinState {
+            user_key_encrypted_pin: crypto::encrypt_string(pin.expose_secret(), &user_key)?,
+            pin_key_encrypted_user_key: Some(pin_key_encrypted_user_key),
+            require_master_password,
+            failed_attempts: 0,
+            boot_id,
+        };
 
-        // Store user key in protected storage
-        debug!("Storing user key in protected storage");
-        let encrypted_protected_key = encrypt_user_key(&user_key, &session_key).map_err(|e| {
-            AuthError::CryptoOperationFailed {
-                message: format!("Failed to encrypt user key for protected storage: {}", e),
-            }
-        })?;
+        // 3. Save
+        self.save_pin_state(&user_id, &pin_state).await?;
 
-        let protected_key = make_protected_key(&user_key_protected_storage_key(&user_id));
+        info!("PIN unlock enabled");
+        Ok(())
+    }
+
+    /// Remove PIN unlock from the active account
+    ///
+    /// Returns whether a PIN was set.
+    pub async fn remove_pin(&self) -> Result<bool, AuthError> {
+        let user_id = self
+            .account_manager
+            .get_active_user_id()
+            .await?
+            .ok_or(AuthError::NotLoggedIn)?;
 
         let mut storage = self.storage.lock().await;
-        storage
-            .set(&protected_key, &encrypted_protected_key)
+        let removed = storage
+            .remove(&StorageKey::UserPinState.format(Some(&user_id)))
             .await?;
-        storage.flush().await?;
-        drop(storage);
 
-        info!("Vault unlock successful");
+        info!("PIN unlock removed");
+        Ok(removed)
+    }
 
-        Ok(UnlockResult {
-            session_key: session_key_str,
+    /// Get the active account's PIN unlock state, `None` if no PIN is set
+    pub async fn get_active_pin_state(&self) -> Result<Option<PinState>, AuthError> {
+        let user_id = self
+            .account_manager
+            .get_active_user_id()
+            .await?
+            .ok_or(AuthError::NotLoggedIn)?;
+
+        self.get_pin_state(&user_id).await
+    }
+
+    /// Verify the master password without changing lock state
+    ///
+    /// Used to re-confirm the user before sensitive operations like export.
+    pub async fn verify_master_password(&self, password: &Secret<String>) -> Result<(), AuthError> {
+        self.decrypt_user_key_with_password(password).await?;
+        Ok(())
+    }
+
+    /// Get the active account's KDF configuration
+    pub async fn get_kdf_config(&self) -> Result<KdfConfig, AuthError> {
+        let user_id = self
+            .account_manager
+            .get_active_user_id()
+            .await?
+            .ok_or(AuthError::NotLoggedIn)?;
+
+        let storage = self.storage.lock().await;
+        let kdf_key = StorageKey::UserKdfConfig.format(Some(&user_id));
+        storage.get(&kdf_key)?.ok_or_else(|| AuthError::KdfError {
+            message: "KDF configuration not found in storage".to_string(),
         })
     }
 
@@ -330,6 +720,15 @@ impl AuthService {
         let protected_key = make_protected_key(&user_key_protected_storage_key(&user_id));
         let mut storage = self.storage.lock().await;
         storage.remove(&protected_key).await?;
+
+        // A PIN that requires the master password after locking can't unlock
+        // again until the next master password unlock
+        let pin_state_key = StorageKey::UserPinState.format(Some(&user_id));
+        let pin_state: Option<PinState> = storage.get(&pin_state_key)?;
+        if let Some(mut pin_state) = pin_state.filter(|state| state.require_master_password) {
+            pin_state.pin_key_encrypted_user_key = None;
+            storage.set(&pin_state_key, &pin_state).await?;
+        }
         storage.flush().await?;
         drop(storage);
 
@@ -340,7 +739,7 @@ impl AuthService {
         Ok(())
     }
 
-    /// Logout (clear all authentication state)
+    /// Logout (clear the active or `--account` scoped account's authentication state)
     pub async fn logout(&self) -> Result<(), AuthError> {
         info!("Logging out");
 
@@ -368,15 +767,19 @@ impl AuthService {
             )
             .await?;
 
-        // Clear protected user key
+        // Clear protected user key and PIN unlock
         let protected_key = make_protected_key(&user_key_protected_storage_key(&user_id));
         storage.remove(&protected_key).await?;
+        storage
+            .remove(&StorageKey::UserPinState.format(Some(&user_id)))
+            .await?;
 
         storage.flush().await?;
         drop(storage);
 
-        // Clear active account (but preserve in accounts registry)
-        self.account_manager.clear_active_account().await?;
+        // If it's the active account, another logged in account takes over
+        // (it stays in the accounts registry). Other accounts stay logged in.
+        self.account_manager.deactivate_account(&user_id).await?;
 
         // Clear session key hint
         self.session_manager.clear_session_key().await?;
@@ -387,6 +790,271 @@ impl AuthService {
 
     // Internal helper methods
 
+    /// Decrypt the stored user key with the master password
+    ///
+    /// Returns the active user ID and the decrypted user key, or
+    /// `AuthError::InvalidPassword` if the password is wrong.
+    async fn decrypt_user_key_with_password(
+        &self,
+        password: &Secret<String>,
+    ) -> Result<(String, SymmetricCryptoKey), AuthError> {
+        let (user_id, email, kdf_config) = self.get_active_account_kdf().await?;
+
+        // Load encrypted user key using namespaced key
+        let storage = self.storage.lock().await;
+        let user_key_key = StorageKey::UserKey.format(Some(&user_id));
+        let encrypted_user_key: Option<String> = storage.get(&user_key_key)?;
+
+        drop(storage); // Release lock
+
+        let encrypted_user_key =
+            encrypted_user_key.ok_or_else(|| AuthError::CryptoOperationFailed {
+                message: "User key not found in storage".to_string(),
+            })?;
+
+        // Derive master key
+        debug!("Deriving master key");
+        let master_key = self
+            .derive_master_key(password, &email, &kdf_config)
+            .await?;
+
+        // Try to decrypt user key (validates password)
+        debug!("Decrypting user key");
+        let user_key = self
+            .decrypt_user_key(&encrypted_user_key, &master_key)
+            .await
+            .map_err(|_| AuthError::InvalidPassword)?;
+
+        Ok((user_id, user_key))
+    }
+
+    /// Get the active user ID with the account's email and KDF configuration
+    ///
+    /// Both are needed to derive keys from the master password or PIN.
+    async fn get_active_account_kdf(&self) -> Result<(String, String, KdfConfig), AuthError> {
+        // Get active user ID
+        let user_id = self
+            .account_manager
+            .get_active_user_id()
+            .await?
+            .ok_or(AuthError::NotLoggedIn)?;
+
+        // Get account info for email
+        let account = self
+            .account_manager
+            .get_account(&user_id)
+            .await?
+            .ok_or(AuthError::NotLoggedIn)?;
+
+        // Load KDF configuration using namespaced key
+        let storage = self.storage.lock().await;
+        let kdf_key = StorageKey::UserKdfConfig.format(Some(&user_id));
+        let kdf_config: KdfConfig = storage.get(&kdf_key)?.ok_or_else(|| AuthError::KdfError {
+            message: "KDF configuration not found in storage".to_string(),
+        })?;
+
+        Ok((user_id, account.email, kdf_config))
+    }
+
+    /// Load an account's PIN unlock state
+    ///
+    /// A PIN that requires the master password after a restart loses its
+    /// PIN-protected user key once the system boot changed.
+    async fn get_pin_state(&self, user_id: &str) -> Result<Option<PinState>, AuthError> {
+        let mut storage = self.storage.lock().await;
+        let pin_state_key = StorageKey::UserPinState.format(Some(user_id));
+        let Some(mut pin_state) = storage.get::<PinState>(&pin_state_key)? else {
+            return Ok(None);
+        };
+
+        if pin_state.require_master_password
+            && pin_state.pin_key_encrypted_user_key.is_some()
+            && (pin_state.boot_id.is_none() || pin_state.boot_id != boot::current_boot_id())
+        {
+            debug!("System restarted, PIN unlock requires the master password");
+            pin_state.pin_key_encrypted_user_key = None;
+            storage.set(&pin_state_key, &pin_state).await?;
+        }
+        Ok(Some(pin_state))
+    }
+
+    async fn save_pin_state(&self, user_id: &str, pin_state: &PinState) -> Result<(), AuthError> {
+        let mut storage = self.storage.lock().await;
+        storage
+            .set(&StorageKey::UserPinState.format(Some(user_id)), pin_state)
+            .await?;
+        Ok(())
+    }
+
+    /// Re-create the PIN-protected user key after a master password unlock
+    ///
+    /// The PIN is recovered from its copy encrypted with the user key, so the
+    /// user doesn't have to set it again. Does nothing without a PIN, or when
+    /// the PIN is already usable.
+    async fn restore_pin_unlock(
+        &self,
+        user_id: &str,
+        user_key: &SymmetricCryptoKey,
+    ) -> Result<(), AuthError> {
+        let Some(mut pin_state) = self.get_pin_state(user_id).await? else {
+            return Ok(());
+        };
+        if pin_state.is_available() && pin_state.failed_attempts == 0 {
+            return Ok(());
+        }
+
+        if pin_state.pin_key_encrypted_user_key.is_none() {
+            let pin = Secret::new(crypto::decrypt_string(
+                &pin_state.user_key_encrypted_pin,
+                user_key,
+                None,
+            )?);
+            let (_, email, kdf_config) = self.get_active_account_kdf().await?;
+            pin_state.pin_key_encrypted_user_key = Some(
+                self.encrypt_user_key_with_pin(&pin, &email, &kdf_config, user_key)
+                    .await?,
+            );
+            if pin_state.require_master_password {
+                pin_state.boot_id = boot::current_boot_id();
+            }
+        }
+        pin_state.failed_attempts = 0;
+
+        debug!("Re-enabling PIN unlock");
+        self.save_pin_state(user_id, &pin_state).await
+    }
+
+    /// Encrypt the user key with a key derived from the PIN
+    async fn encrypt_user_key_with_pin(
+        &self,
+        pin: &Secret<String>,
+        email: &str,
+        kdf_config: &KdfConfig,
+        user_key: &SymmetricCryptoKey,
+    ) -> Result<String, AuthError> {
+        let pin_key = self.derive_pin_key(pin, email, kdf_config).await?;
+        Ok(crypto::encrypt_user_key_with_pin(&pin_key, user_key)?)
+    }
+
+    /// Make this device trusted for the user's SSO logins
+    ///
+    /// Encrypts the user key to a new device key pair, registers the pair
+    /// with the server and keeps the device key locally. Needs the access
+    /// token to be persisted first.
+    async fn trust_device(
+        &self,
+        user_id: &str,
+        user_key: &SymmetricCryptoKey,
+    ) -> Result<(), AuthError> {
+        let trusted = DeviceKey::trust_device(user_key)?;
+        let device_id = self.session_manager.get_or_create_device_id().await?;
+
+        let request = DeviceKeysRequest {
+            encrypted_user_key: trusted.protected_user_key.to_string(),
+            encrypted_public_key: trusted.protected_device_public_key.to_string(),
+            encrypted_private_key: trusted.protected_device_private_key.to_string(),
+        };
+        let _: serde_json::Value = self
+            .api_client
+            .put_with_auth(&endpoints::api::devices::keys(&device_id), &request)
+            .await
+            .map_err(|e| AuthError::Api(e.into()))?;
+
+        let mut storage = self.storage.lock().await;
+        storage
+            .set(
+                &StorageKey::UserDeviceKey.format(Some(user_id)),
+                &trusted.device_key.to_string(),
+            )
+            .await?;
+        storage.flush().await?;
+
+        Ok(())
+    }
+
+    /// Choose how an SSO login decrypts the user key
+    ///
+    /// Key Connector takes precedence, then a trusted device (when this
+    /// device holds a device key), then the master password.
+    async fn select_sso_decryption(
+        &self,
+        login_response: &LoginResponse,
+        user_id: &str,
+    ) -> Result<SsoDecryption, AuthError> {
+        let Some(options) = &login_response.user_decryption_options else {
+            // Older servers only return a master-key-encrypted user key
+            return Ok(SsoDecryption::MasterPassword);
+        };
+
+        if let Some(key_connector) = &options.key_connector_option {
+            return Ok(SsoDecryption::KeyConnector {
+                url: key_connector.key_connector_url.clone(),
+            });
+        }
+
+        if options.trusted_device_option.is_some() {
+            let storage = self.storage.lock().await;
+            let device_key: Option<String> =
+                storage.get(&StorageKey::UserDeviceKey.format(Some(user_id)))?;
+            if device_key.is_some() {
+                return Ok(SsoDecryption::TrustedDevice);
+            }
+        }
+
+        if options.has_master_password {
+            return Ok(SsoDecryption::MasterPassword);
+        }
+
+        Err(AuthError::Other(
+            "This device is not trusted and the account has no master password. \
+             Approve this device from another Bitwarden client, then log in again."
+                .to_string(),
+        ))
+    }
+
+    /// KDF configuration returned with a token response
+    fn kdf_config_from_login_response(response: &LoginResponse) -> KdfConfig {
+        KdfConfig {
+            kdf_type: if response.kdf == 0 {
+                KdfType::PBKDF2SHA256
+            } else {
+                KdfType::Argon2id
+            },
+            iterations: Some(response.kdf_iterations),
+            memory: response.kdf_memory,
+            parallelism: response.kdf_parallelism,
+        }
+    }
+
+    /// Encrypt the user key with a new session key and store it
+    ///
+    /// Returns the formatted session key for BW_SESSION export.
+    async fn store_protected_user_key(
+        &self,
+        user_id: &str,
+        user_key: &SymmetricCryptoKey,
+    ) -> Result<String, AuthError> {
+        debug!("Generating session key");
+        let session_key = generate_session_key();
+
+        debug!("Storing user key in protected storage");
+        let encrypted_protected_key = encrypt_user_key(user_key, &session_key).map_err(|e| {
+            AuthError::CryptoOperationFailed {
+                message: format!("Failed to encrypt user key for protected storage: {}", e),
+            }
+        })?;
+
+        let protected_key = make_protected_key(&user_key_protected_storage_key(user_id));
+
+        let mut storage = self.storage.lock().await;
+        storage
+            .set(&protected_key, &encrypted_protected_key)
+            .await?;
+        storage.flush().await?;
+
+        Ok(format_session_key(&session_key))
+    }
+
     /// Fetch KDF configuration from server
     async fn fetch_kdf_config(&self, email: &str) -> Result<KdfConfig, AuthError> {
         let request = PreloginRequest {
@@ -448,6 +1116,33 @@ impl AuthService {
         })
     }
 
+    /// Derive PIN key using the account's KDF (SDK-backed)
+    async fn derive_pin_key(
+        &self,
+        pin: &Secret<String>,
+        email: &str,
+        kdf_config: &KdfConfig,
+    ) -> Result<PinKey, AuthError> {
+        let kdf: Kdf = kdf_config
+            .try_into()
+            .map_err(|e: anyhow::Error| AuthError::KdfError {
+                message: e.to_string(),
+            })?;
+
+        let pin_str = pin.expose_secret().clone();
+        let email_clone = email.to_string();
+
+        // Run KDF in blocking task (CPU-intensive)
+        tokio::task::spawn_blocking(move || crypto::derive_pin_key(&pin_str, &email_clone, &kdf))
+            .await
+            .map_err(|e| AuthError::CryptoOperationFailed {
+                message: format!("KDF task failed: {}", e),
+            })?
+            .map_err(|e: CryptoError| AuthError::CryptoOperationFailed {
+                message: format!("Key derivation failed: {}", e),
+            })
+    }
+
     /// Hash password for authentication request (SDK-backed)
     async fn hash_password_for_auth(
         &self,
@@ -516,20 +1211,86 @@ impl AuthService {
             device_info.device_type, device_info.device_name, device_info.device_identifier,
         );
 
-        self.api_client
-            .post_form(endpoints::identity::TOKEN, &request, Some(extra_headers))
+        let (status, body) = self
+            .api_client
+            .post_form_raw(endpoints::identity::TOKEN, &request, Some(extra_headers))
             .await
-            .map_err(|e| {
-                let error_str = e.to_string().to_lowercase();
-                // Check for new device verification required
-                if error_str.contains("new device verification required") {
-                    return AuthError::NewDeviceVerificationRequired;
-                }
-                // TODO: Parse error response for 2FA requirement
-                AuthError::InvalidCredentials {
-                    message: format!("Authentication failed: {}", e),
-                }
-            })
+            .map_err(|e| AuthError::InvalidCredentials {
+                message: format!("Authentication failed: {}", e),
+            })?;
+
+        if status.is_success() {
+            return serde_json::from_str(&body).map_err(|e| AuthError::InvalidCredentials {
+                message: format!("Authentication failed: invalid token response: {}", e),
+            });
+        }
+
+        Err(Self::parse_identity_error(&body))
+    }
+
+    /// Map an identity server error body to an AuthError
+    fn parse_identity_error(body: &str) -> AuthError {
+        let Ok(response) = serde_json::from_str::<ApiErrorResponse>(body) else {
+            return AuthError::InvalidCredentials {
+                message: format!("Authentication failed: {}", body),
+            };
+        };
+
+        // 2FA required: the response lists the account's providers
+        if let Some(providers) = &response.two_factor_providers2 {
+            return AuthError::TwoFactorRequired {
+                providers: TwoFactorMethod::from_providers2(providers),
+            };
+        }
+
+        let message = response
+            .message
+            .or(response.error_description)
+            .or(response.error)
+            .unwrap_or_else(|| "Unknown error".to_string());
+        let lower = message.to_lowercase();
+
+        if lower.contains("new device verification required") {
+            AuthError::NewDeviceVerificationRequired
+        } else if lower.contains("two-step token is invalid") {
+            AuthError::InvalidTwoFactorCode
+        } else {
+            AuthError::InvalidCredentials {
+                message: format!("Authentication failed: {}", message),
+            }
+        }
+    }
+
+    /// Get the remembered two-factor token for an email, if any
+    async fn get_two_factor_token(&self, email: &str) -> Result<Option<String>, AuthError> {
+        let storage = self.storage.lock().await;
+        let tokens: HashMap<String, String> = storage
+            .get(&StorageKey::GlobalTwoFactorTokens.format(None))?
+            .unwrap_or_default();
+        Ok(tokens.get(&email.trim().to_lowercase()).cloned())
+    }
+
+    /// Remember a two-factor token for an email
+    async fn set_two_factor_token(&self, email: &str, token: &str) -> Result<(), AuthError> {
+        let key = StorageKey::GlobalTwoFactorTokens.format(None);
+        let mut storage = self.storage.lock().await;
+        let mut tokens: HashMap<String, String> = storage.get(&key)?.unwrap_or_default();
+        tokens.insert(email.trim().to_lowercase(), token.to_string());
+        storage.set(&key, &tokens).await?;
+        storage.flush().await?;
+        Ok(())
+    }
+
+    /// Forget the two-factor token for an email
+    async fn clear_two_factor_token(&self, email: &str) -> Result<(), AuthError> {
+        let key = StorageKey::GlobalTwoFactorTokens.format(None);
+        let mut storage = self.storage.lock().await;
+        let mut tokens: HashMap<String, String> = storage.get(&key)?.unwrap_or_default();
+        if tokens.remove(&email.trim().to_lowercase()).is_some() {
+            storage.set(&key, &tokens).await?;
+            storage.flush().await?;
+        }
+        Ok(())
     }
 
     /// Fetch user profile
@@ -617,6 +1378,9 @@ impl AuthService {
             .set(&StorageKey::UserKdfConfig.format(Some(user_id)), kdf_config)
             .await?;
 
+        // Remember the server the account logged in to
+        EnvironmentService::save_for_user(&mut storage, user_id).await?;
+
         storage.flush().await?;
 
         Ok(())
@@ -664,6 +1428,9 @@ impl AuthService {
             )
             .await?;
 
+        // Remember the server the account logged in to
+        EnvironmentService::save_for_user(&mut storage, user_id).await?;
+
         storage.flush().await?;
 
         Ok(())
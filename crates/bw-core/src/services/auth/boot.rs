@@ -0,0 +1,44 @@
+//! Identify the current system boot
+//!
+//! PIN unlock with `require_master_password` must not survive a restart. The
+//! CLI doesn't run between commands, so it compares the boot the
+//! PIN-protected user key was created in with the current one instead.
+
+/// ID of the current system boot, `None` where it can't be determined
+#[cfg(target_os = "linux")]
+pub(crate) fn current_boot_id() -> Option<String> {
+    std::fs::read_to_string("/proc/sys/kernel/random/boot_id")
+        .ok()
+        .map(|id| id.trim().to_string())
+        .filter(|id| !id.is_empty())
+}
+
+/// ID of the current system boot, `None` where it can't be determined
+#[cfg(target_os = "macos")]
+pub(crate) fn current_boot_id() -> Option<String> {
+    // e.g. "{ sec = 1700000000, usec = 123456 } Tue Nov 14 22:13:20 2023"
+    let output = std::process::Command::new("sysctl")
+        .args(["-n", "kern.boottime"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())?;
+    let boot_time = String::from_utf8(output.stdout).ok()?;
+    Some(boot_time.trim().to_string()).filter(|time| !time.is_empty())
+}
+
+/// ID of the current system boot, `None` where it can't be determined
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+pub(crate) fn current_boot_id() -> Option<String> {
+    None
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_boot_id_is_stable_within_a_boot() {
+        let boot_id = current_boot_id().unwrap();
+        assert_eq!(current_boot_id().as_deref(), Some(boot_id.as_str()));
+    }
+}
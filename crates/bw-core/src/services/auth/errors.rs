@@ -10,9 +10,7 @@ pub enum AuthError {
     InvalidCredentials { message: String },
 
     #[error("Two-factor authentication required")]
-    TwoFactorRequired {
-        available_methods: Vec<TwoFactorMethod>,
-    },
+    TwoFactorRequired { providers: Vec<TwoFactorMethod> },
 
     #[error("Invalid two-factor code")]
     InvalidTwoFactorCode,
@@ -26,6 +24,18 @@ pub enum AuthError {
     #[error("Master password incorrect")]
     InvalidPassword,
 
+    #[error("PIN unlock is not set up")]
+    PinNotSet,
+
+    #[error("PIN unlock requires a master password unlock first")]
+    PinUnavailable,
+
+    #[error("PIN incorrect, {remaining} attempts remaining")]
+    InvalidPin { remaining: u32 },
+
+    #[error("Too many incorrect PINs")]
+    PinLockedOut,
+
     #[error("KDF configuration error: {message}")]
     KdfError { message: String },
 
@@ -86,6 +96,18 @@ impl AuthError {
             Self::InvalidPassword => {
                 "Invalid master password.\n\nPlease try again or run 'bw login' if you've forgotten your password.".to_string()
             }
+            Self::PinNotSet => {
+                "PIN unlock is not set up.\n\nRun 'bw config pin' to set a PIN.".to_string()
+            }
+            Self::PinUnavailable => {
+                "Your master password is required.\n\nRun 'bw unlock' with your master password to use your PIN again.".to_string()
+            }
+            Self::InvalidPin { remaining } => {
+                format!("Invalid PIN. {} attempts remaining.", remaining)
+            }
+            Self::PinLockedOut => {
+                "Too many invalid PIN attempts. PIN unlock is disabled.\n\nRun 'bw unlock' with your master password to use your PIN again.".to_string()
+            }
             Self::KdfError { message } => {
                 format!("Key derivation error: {}\n\nThis may indicate a server issue. Please try again.", message)
             }
@@ -164,6 +186,16 @@ mod tests {
         assert!(msg.contains("bw login"));
     }
 
+    #[test]
+    fn test_user_message_pin_locked_out() {
+        let msg = AuthError::PinLockedOut.user_message();
+        assert!(msg.contains("Too many invalid PIN attempts"));
+        assert!(msg.contains("master password"));
+
+        let msg = AuthError::InvalidPin { remaining: 3 }.user_message();
+        assert_eq!(msg, "Invalid PIN. 3 attempts remaining.");
+    }
+
     #[test]
     fn test_user_message_kdf_error() {
         let err = AuthError::KdfError {
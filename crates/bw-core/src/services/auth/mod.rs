@@ -1,10 +1,16 @@
 mod auth_service;
+mod boot;
 mod errors;
 mod session_manager;
+mod sso;
 
 pub use auth_service::AuthService;
 pub use errors::AuthError;
 pub use session_manager::SessionManager;
+pub use sso::{
+    PendingSsoLogin, PkceParams, SSO_CALLBACK_PORTS, SsoCallbackListener, SsoDecryption,
+    build_authorize_url,
+};
 
 // Re-export for convenience
 pub use crate::models::auth::{LoginResult, TwoFactorData, UnlockResult};
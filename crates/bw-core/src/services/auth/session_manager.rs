@@ -94,17 +94,12 @@ impl SessionManager {
         let storage = self.storage.lock().await;
 
         // Check for active account ID first (new format)
-        let active_id_key = StorageKey::GlobalActiveAccountId.format(None);
-        let active_id: Option<serde_json::Value> = storage.get(&active_id_key)?;
-
-        if let Some(serde_json::Value::String(user_id)) = active_id {
-            if !user_id.is_empty() {
-                // Check if user has access token
-                let token_key = StorageKey::UserAccessToken.format(Some(&user_id));
-                let token: Option<serde_json::Value> = storage.get(&token_key)?;
-                if matches!(token, Some(serde_json::Value::String(s)) if !s.is_empty()) {
-                    return Ok(true);
-                }
+        if let Some(user_id) = storage.active_user_id()? {
+            // Check if user has access token
+            let token_key = StorageKey::UserAccessToken.format(Some(&user_id));
+            let token: Option<serde_json::Value> = storage.get(&token_key)?;
+            if matches!(token, Some(serde_json::Value::String(s)) if !s.is_empty()) {
+                return Ok(true);
             }
         }
 
@@ -120,12 +115,8 @@ impl SessionManager {
         let storage = self.storage.lock().await;
 
         // Get active user ID
-        let active_id_key = StorageKey::GlobalActiveAccountId.format(None);
-        let active_id: Option<serde_json::Value> = storage.get(&active_id_key)?;
-
-        let user_id = match active_id {
-            Some(serde_json::Value::String(id)) if !id.is_empty() => id,
-            _ => return Ok(None),
+        let Some(user_id) = storage.active_user_id()? else {
+            return Ok(None);
         };
 
         // Get access token for this user
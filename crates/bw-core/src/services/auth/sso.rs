@@ -0,0 +1,287 @@
+//! SSO login helpers
+//!
+//! The CLI authenticates SSO users with the OAuth2 authorization code flow:
+//! the browser completes the identity provider login and redirects to a
+//! loopback listener on this machine, which hands the authorization code back
+//! to the CLI. PKCE ties the code to this process so an intercepted code is
+//! useless on its own.
+
+use crate::models::api::{LoginResponse, ProfileResponse};
+use crate::services::auth::errors::AuthError;
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use rand::{Rng, distributions::Alphanumeric, rngs::OsRng};
+use sha2::{Digest, Sha256};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tracing::debug;
+use url::Url;
+
+/// Loopback ports the identity server accepts as redirect URIs for the CLI
+pub const SSO_CALLBACK_PORTS: std::ops::RangeInclusive<u16> = 8065..=8070;
+
+/// OAuth2 client ID used by the CLI
+pub const SSO_CLIENT_ID: &str = "cli";
+
+/// Host of the loopback listener, used for both binding and the redirect URI
+/// so the browser reaches the address the listener is on
+const SSO_CALLBACK_HOST: &str = "localhost";
+
+const SUCCESS_PAGE: &str = "<html><head><title>Success | Bitwarden CLI</title></head><body>\
+     <h1>Successfully authenticated with the Bitwarden CLI</h1>\
+     <p>You may now close this tab and return to the terminal.</p></body></html>";
+
+const FAILURE_PAGE: &str = "<html><head><title>Failed | Bitwarden CLI</title></head><body>\
+     <h1>Something went wrong logging into the Bitwarden CLI</h1>\
+     <p>You may now close this tab and return to the terminal.</p></body></html>";
+
+/// PKCE parameters for a single authorization request
+#[derive(Debug, Clone)]
+pub struct PkceParams {
+    /// Random secret sent with the token exchange
+    pub code_verifier: String,
+    /// Base64url SHA-256 of the verifier, sent with the authorize request
+    pub code_challenge: String,
+    /// Random value echoed back on the redirect to detect forged callbacks
+    pub state: String,
+}
+
+impl PkceParams {
+    /// Generate a fresh verifier, challenge and state
+    pub fn generate() -> Self {
+        let code_verifier = random_string(64);
+        let code_challenge = URL_SAFE_NO_PAD.encode(Sha256::digest(code_verifier.as_bytes()));
+
+        Self {
+            code_verifier,
+            code_challenge,
+            state: random_string(64),
+        }
+    }
+}
+
+/// How an SSO login will decrypt the user key
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SsoDecryption {
+    /// Derive the master key from the master password
+    MasterPassword,
+    /// Use the device key stored on this trusted device
+    TrustedDevice,
+    /// Fetch the master key from the organization's Key Connector
+    KeyConnector { url: String },
+}
+
+/// An SSO login that has authenticated but not yet decrypted the vault
+///
+/// Returned by `AuthService::login_with_sso` and consumed by
+/// `AuthService::complete_sso_login`.
+#[derive(Debug)]
+pub struct PendingSsoLogin {
+    pub(super) login_response: LoginResponse,
+    pub(super) profile: ProfileResponse,
+    pub(super) decryption: SsoDecryption,
+}
+
+impl PendingSsoLogin {
+    /// Email address of the authenticated account
+    pub fn email(&self) -> &str {
+        &self.profile.email
+    }
+
+    /// How the user key will be decrypted
+    pub fn decryption(&self) -> &SsoDecryption {
+        &self.decryption
+    }
+
+    /// Whether completing the login needs the master password
+    pub fn requires_master_password(&self) -> bool {
+        self.decryption == SsoDecryption::MasterPassword
+    }
+}
+
+/// Build the URL the user opens to start SSO
+///
+/// Points at the web vault's SSO page, which resolves the organization's
+/// identity provider and then redirects back to `redirect_uri` with the
+/// authorization code.
+pub fn build_authorize_url(
+    web_vault_url: &str,
+    redirect_uri: &str,
+    pkce: &PkceParams,
+    org_identifier: Option<&str>,
+) -> String {
+    let mut query = url::form_urlencoded::Serializer::new(String::new());
+    query
+        .append_pair("clientId", SSO_CLIENT_ID)
+        .append_pair("redirectUri", redirect_uri)
+        .append_pair("state", &pkce.state)
+        .append_pair("codeChallenge", &pkce.code_challenge);
+    if let Some(identifier) = org_identifier {
+        query.append_pair("identifier", identifier);
+    }
+
+    format!(
+        "{}/#/sso?{}",
+        web_vault_url.trim_end_matches('/'),
+        query.finish()
+    )
+}
+
+/// Loopback HTTP listener that receives the SSO redirect
+pub struct SsoCallbackListener {
+    listener: TcpListener,
+    port: u16,
+}
+
+impl SsoCallbackListener {
+    /// Bind the first free port in [`SSO_CALLBACK_PORTS`]
+    pub async fn bind() -> Result<Self, AuthError> {
+        for port in SSO_CALLBACK_PORTS {
+            if let Ok(listener) = TcpListener::bind((SSO_CALLBACK_HOST, port)).await {
+                debug!("SSO callback listener bound to port {}", port);
+                return Ok(Self { listener, port });
+            }
+        }
+
+        Err(AuthError::Other(format!(
+            "Could not start the SSO callback listener: ports {}-{} are in use",
+            SSO_CALLBACK_PORTS.start(),
+            SSO_CALLBACK_PORTS.end()
+        )))
+    }
+
+    /// Port the listener is bound to
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+
+    /// Redirect URI to register with the authorize request
+    pub fn redirect_uri(&self) -> String {
+        format!("http://{}:{}", SSO_CALLBACK_HOST, self.port)
+    }
+
+    /// Wait for the browser redirect and return the authorization code
+    ///
+    /// Requests without a code or error (e.g. favicon fetches) are ignored.
+    /// The callback's `state` must match `expected_state`.
+    pub async fn wait_for_code(self, expected_state: &str) -> Result<String, AuthError> {
+        loop {
+            let (mut stream, _) =
+                self.listener.accept().await.map_err(|e| {
+                    AuthError::Other(format!("SSO callback listener failed: {}", e))
+                })?;
+
+            let Some(target) = read_request_target(&mut stream).await else {
+                continue;
+            };
+            let Ok(url) = Url::parse(&format!("http://{}{}", SSO_CALLBACK_HOST, target)) else {
+                continue;
+            };
+            let param = |name: &str| {
+                url.query_pairs()
+                    .find(|(key, _)| key == name)
+                    .map(|(_, value)| value.into_owned())
+            };
+
+            if let Some(error) = param("error") {
+                respond(&mut stream, "400 Bad Request", FAILURE_PAGE).await;
+                return Err(AuthError::Other(format!("SSO login failed: {}", error)));
+            }
+
+            let Some(code) = param("code") else {
+                respond(&mut stream, "404 Not Found", "").await;
+                continue;
+            };
+
+            if param("state").as_deref() != Some(expected_state) {
+                respond(&mut stream, "400 Bad Request", FAILURE_PAGE).await;
+                return Err(AuthError::Other(
+                    "SSO login failed: state mismatch in callback".to_string(),
+                ));
+            }
+
+            respond(&mut stream, "200 OK", SUCCESS_PAGE).await;
+            return Ok(code);
+        }
+    }
+}
+
+/// Read an HTTP request head and return its request target (path and query)
+async fn read_request_target(stream: &mut TcpStream) -> Option<String> {
+    let mut buf = Vec::with_capacity(1024);
+    let mut chunk = [0u8; 1024];
+
+    // The request line is all we need; stop at the end of the headers
+    while !buf.windows(4).any(|w| w == b"\r\n\r\n") && buf.len() < 16 * 1024 {
+        let n = stream.read(&mut chunk).await.ok()?;
+        if n == 0 {
+            break;
+        }
+        buf.extend_from_slice(&chunk[..n]);
+    }
+
+    let head = String::from_utf8_lossy(&buf);
+    let mut parts = head.lines().next()?.split_whitespace();
+    match (parts.next(), parts.next()) {
+        (Some("GET"), Some(target)) => Some(target.to_string()),
+        _ => None,
+    }
+}
+
+async fn respond(stream: &mut TcpStream, status: &str, body: &str) {
+    let response = format!(
+        "HTTP/1.1 {}\r\nContent-Type: text/html; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    );
+    // The browser tab is informational only; a failed write doesn't affect login
+    let _ = stream.write_all(response.as_bytes()).await;
+    let _ = stream.shutdown().await;
+}
+
+fn random_string(len: usize) -> String {
+    OsRng
+        .sample_iter(&Alphanumeric)
+        .take(len)
+        .map(char::from)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pkce_challenge_matches_verifier() {
+        let pkce = PkceParams::generate();
+
+        assert_eq!(pkce.code_verifier.len(), 64);
+        assert_eq!(
+            pkce.code_challenge,
+            URL_SAFE_NO_PAD.encode(Sha256::digest(pkce.code_verifier.as_bytes()))
+        );
+        assert_ne!(pkce.state, pkce.code_verifier);
+    }
+
+    #[test]
+    fn test_build_authorize_url() {
+        let pkce = PkceParams {
+            code_verifier: "verifier".to_string(),
+            code_challenge: "challenge".to_string(),
+            state: "state123".to_string(),
+        };
+
+        let url = build_authorize_url(
+            "https://vault.example.com/",
+            "http://localhost:8065",
+            &pkce,
+            Some("acme"),
+        );
+
+        assert_eq!(
+            url,
+            "https://vault.example.com/#/sso?clientId=cli&redirectUri=http%3A%2F%2Flocalhost%3A8065\
+             &state=state123&codeChallenge=challenge&identifier=acme"
+        );
+    }
+}
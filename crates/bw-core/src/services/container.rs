@@ -1,13 +1,14 @@
 use super::{
-    api::{BitwardenApiClient, Environment},
+    api::{BitwardenApiClient, Environment, EnvironmentService},
     create_sdk_client,
     sdk::Client,
-    storage::JsonFileStorage,
+    storage::{AccountManager, JsonFileStorage},
 };
-use anyhow::Result;
+use anyhow::{Result, anyhow};
 use std::path::PathBuf;
 use std::sync::Arc;
 use tokio::sync::Mutex;
+use tracing::warn;
 
 /// Service container for dependency injection
 ///
@@ -30,8 +31,8 @@ impl ServiceContainer {
     /// Create a new service container
     ///
     /// # Arguments
-    /// * `api_url` - Optional API server URL
-    /// * `identity_url` - Optional Identity server URL
+    /// * `api_url` - Optional API server URL, overriding the configured server
+    /// * `identity_url` - Optional Identity server URL, overriding the configured server
     /// * `storage_path` - Optional custom storage directory path
     /// * `timeout_seconds` - Optional API request timeout
     pub fn new(
@@ -40,23 +41,68 @@ impl ServiceContainer {
         storage_path: Option<PathBuf>,
         timeout_seconds: Option<u64>,
     ) -> Result<Self> {
-        let sdk = create_sdk_client(api_url.clone(), identity_url.clone())?;
+        Self::for_account(api_url, identity_url, storage_path, timeout_seconds, None)
+    }
 
-        // Create storage wrapped in Mutex since Storage trait methods need &mut self
-        let storage = Arc::new(Mutex::new(JsonFileStorage::new(storage_path)?));
+    /// Create a service container scoped to an account
+    ///
+    /// With `account` (an email or user ID), services act for that account
+    /// instead of the active one, without switching the active account.
+    pub fn for_account(
+        api_url: Option<String>,
+        identity_url: Option<String>,
+        storage_path: Option<PathBuf>,
+        timeout_seconds: Option<u64>,
+        account: Option<&str>,
+    ) -> Result<Self> {
+        let mut storage = JsonFileStorage::new(storage_path)?;
+
+        // Scope to the account before anything reads the active account
+        if let Some(account) = account {
+            let user_id = AccountManager::find_account_in(&storage, account)?.ok_or_else(|| {
+                anyhow!(
+                    "Account '{}' not found. Run 'bw account list' to see your accounts.",
+                    account
+                )
+            })?;
+            storage.scope_to_account(Some(user_id));
+        }
 
         // Determine environment URLs
-        // Use default cloud environment if no custom URLs provided
+        // Use the configured server (default cloud if none) if no custom URLs provided.
+        // Invalid saved URLs fall back to the default cloud so `bw config server` can
+        // still replace them.
         let environment = match (&api_url, &identity_url) {
-            (None, None) => Environment::default_cloud(),
+            (None, None) => EnvironmentService::load(&storage).unwrap_or_else(|e| {
+                warn!(
+                    "Ignoring saved server URLs, using the default cloud: {:#}",
+                    e
+                );
+                Environment::default_cloud()
+            }),
             _ => {
                 let base_url = api_url
-                    .or(identity_url)
+                    .clone()
+                    .or(identity_url.clone())
                     .unwrap_or_else(|| "https://vault.bitwarden.com".to_string());
                 Environment::from_base_url(&base_url)?
             }
         };
 
+        // The SDK uses its cloud defaults unless another server is configured
+        let sdk = if api_url.is_none() && identity_url.is_none() && !environment.is_default_cloud()
+        {
+            create_sdk_client(
+                Some(environment.api_url().to_string()),
+                Some(environment.identity_url().to_string()),
+            )?
+        } else {
+            create_sdk_client(api_url, identity_url)?
+        };
+
+        // Create storage wrapped in Mutex since Storage trait methods need &mut self
+        let storage = Arc::new(Mutex::new(storage));
+
         // Initialize API client (shares the same storage instance)
         let api_client = Arc::new(BitwardenApiClient::new(
             environment,
@@ -103,4 +149,25 @@ mod tests {
         let container = ServiceContainer::new(None, None, None, None);
         assert!(container.is_ok(), "Should create service container");
     }
+
+    #[tokio::test]
+    async fn test_invalid_saved_environment_falls_back_to_default_cloud() {
+        use crate::services::storage::{Storage, StorageKey};
+
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut storage = JsonFileStorage::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        storage
+            .set(
+                &StorageKey::GlobalEnvironment.format(None),
+                &serde_json::json!({ "base": "http://vault.example.com" }),
+            )
+            .await
+            .unwrap();
+        storage.flush().await.unwrap();
+
+        let container =
+            ServiceContainer::new(None, None, Some(temp_dir.path().to_path_buf()), None);
+
+        assert!(container.is_ok(), "Should ignore the invalid server URLs");
+    }
 }
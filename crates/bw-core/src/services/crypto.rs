@@ -1,9 +1,17 @@
 //! SDK-backed cryptographic operations
 //!
 //! This module provides thin wrappers around the Bitwarden SDK's crypto operations.
-//! All cryptographic functions delegate to the SDK - no custom crypto is implemented here.
+//! All cryptographic functions delegate to the SDK, except RSA decryption of
+//! arbitrary data, which the SDK no longer exposes; that uses the same `rsa`
+//! crate the SDK is built on.
 
-use bitwarden_crypto::{CryptoError, EncString, HashPurpose, Kdf, MasterKey, SymmetricCryptoKey};
+use base64::{Engine, engine::general_purpose::STANDARD};
+use bitwarden_crypto::{
+    AsymmetricCryptoKey, AsymmetricPublicCryptoKey, CryptoError, DeviceKey, EncString, HashPurpose,
+    Kdf, KeyDecryptable, KeyEncryptable, MasterKey, PinKey, Pkcs8PrivateKeyBytes,
+    SpkiPublicKeyBytes, SymmetricCryptoKey, UnsignedSharedKey,
+};
+use rsa::{Oaep, RsaPrivateKey, pkcs8::DecodePrivateKey};
 
 /// Derive a master key from password, email, and KDF configuration
 ///
@@ -57,11 +65,245 @@ pub fn decrypt_user_key(
     master_key.decrypt_user_key(enc_string)
 }
 
+/// Load a master key from its base64-encoded bytes
+///
+/// Key Connector users have no master password; the Key Connector stores
+/// the master key itself and releases it after SSO authentication.
+///
+/// # Arguments
+/// * `key_b64` - Base64-encoded 32-byte master key
+///
+/// # Returns
+/// The master key, or an error if the bytes are not a valid key
+pub fn master_key_from_b64(key_b64: &str) -> Result<MasterKey, CryptoError> {
+    let mut bytes = STANDARD
+        .decode(key_b64)
+        .map_err(|_| CryptoError::InvalidKey)?;
+
+    MasterKey::try_from(bytes.as_mut_slice())
+}
+
+/// Decrypt the user's symmetric key on a trusted device
+///
+/// The server holds the device's private key encrypted with the locally
+/// stored device key, and the user key encrypted with the device public key.
+///
+/// # Arguments
+/// * `device_key_b64` - Base64-encoded device key from local storage
+/// * `encrypted_private_key` - Device private key (EncString)
+/// * `encrypted_user_key` - User key encrypted to the device public key
+///
+/// # Returns
+/// The decrypted symmetric crypto key, or an error if decryption fails
+pub fn decrypt_user_key_with_device_key(
+    device_key_b64: &str,
+    encrypted_private_key: &str,
+    encrypted_user_key: &str,
+) -> Result<SymmetricCryptoKey, CryptoError> {
+    let device_key = DeviceKey::try_from(device_key_b64.to_string())?;
+    let private_key: EncString = encrypted_private_key
+        .parse()
+        .map_err(|_| CryptoError::InvalidKey)?;
+    let user_key: UnsignedSharedKey = encrypted_user_key
+        .parse()
+        .map_err(|_| CryptoError::InvalidKey)?;
+
+    device_key.decrypt_user_key(private_key, user_key)
+}
+
+/// Derive a PIN key from a PIN, email, and KDF configuration
+///
+/// The PIN is stretched with the account's KDF like a master password, with
+/// the normalized email as salt.
+///
+/// # Arguments
+/// * `pin` - The user's PIN
+/// * `email` - The user's email address (used as salt)
+/// * `kdf` - The account's KDF configuration
+pub fn derive_pin_key(pin: &str, email: &str, kdf: &Kdf) -> Result<PinKey, CryptoError> {
+    let email = email.trim().to_lowercase();
+
+    PinKey::derive(pin.as_bytes(), email.as_bytes(), kdf)
+}
+
+/// Encrypt the user's symmetric key with a PIN key
+///
+/// # Returns
+/// The encrypted user key as an EncString
+pub fn encrypt_user_key_with_pin(
+    pin_key: &PinKey,
+    user_key: &SymmetricCryptoKey,
+) -> Result<String, CryptoError> {
+    Ok(pin_key.encrypt_user_key(user_key)?.to_string())
+}
+
+/// Decrypt the user's symmetric key with a PIN key
+///
+/// # Arguments
+/// * `pin_key` - The key derived from the entered PIN
+/// * `encrypted_key` - The PIN-protected user key in EncString format
+///
+/// # Returns
+/// The decrypted symmetric crypto key, or an error if the PIN is wrong
+pub fn decrypt_user_key_with_pin(
+    pin_key: &PinKey,
+    encrypted_key: &str,
+) -> Result<SymmetricCryptoKey, CryptoError> {
+    let enc_string: EncString = encrypted_key.parse().map_err(|_| CryptoError::InvalidKey)?;
+
+    pin_key.decrypt_user_key(enc_string)
+}
+
+/// Decrypt the user's RSA private key with their user key
+///
+/// # Arguments
+/// * `user_key` - The decrypted user key
+/// * `encrypted_private_key` - The private key as stored after sync (EncString)
+///
+/// # Returns
+/// The RSA private key, or an error if decryption fails
+pub fn decrypt_private_key(
+    user_key: &SymmetricCryptoKey,
+    encrypted_private_key: &str,
+) -> Result<AsymmetricCryptoKey, CryptoError> {
+    let enc_string: EncString = encrypted_private_key.parse()?;
+    let der: Vec<u8> = enc_string.decrypt_with_key(user_key)?;
+
+    AsymmetricCryptoKey::from_der(&Pkcs8PrivateKeyBytes::from(der))
+}
+
+/// Decrypt a key shared with the user via their public key
+///
+/// Organization keys are delivered this way (RSA EncString types 3-6).
+///
+/// # Arguments
+/// * `private_key` - The user's decrypted RSA private key
+/// * `encrypted_key` - The RSA-encrypted key
+///
+/// # Returns
+/// The decrypted symmetric crypto key, or an error if decryption fails
+pub fn decrypt_shared_key(
+    private_key: &AsymmetricCryptoKey,
+    encrypted_key: &str,
+) -> Result<SymmetricCryptoKey, CryptoError> {
+    let shared_key: UnsignedSharedKey = encrypted_key.parse()?;
+
+    shared_key.decapsulate_key_unsigned(private_key)
+}
+
+/// Encrypt a key to a user's public key
+///
+/// Used to share an organization key with a member (RSA-OAEP).
+///
+/// # Arguments
+/// * `key` - The symmetric key to share
+/// * `public_key_b64` - The recipient's public key (base64 SPKI DER)
+///
+/// # Returns
+/// The encrypted key as an RSA EncString
+pub fn encapsulate_key(
+    key: &SymmetricCryptoKey,
+    public_key_b64: &str,
+) -> Result<String, CryptoError> {
+    let public_key = public_key_from_b64(public_key_b64)?;
+
+    Ok(UnsignedSharedKey::encapsulate_key_unsigned(key, &public_key)?.to_string())
+}
+
+/// Compute the fingerprint phrase of a user's public key
+///
+/// Users compare this phrase out of band to verify a public key before
+/// trusting it with an organization key.
+///
+/// # Arguments
+/// * `user_id` - The key owner's user ID (fingerprint material)
+/// * `public_key_b64` - The public key (base64 SPKI DER)
+pub fn fingerprint_phrase(user_id: &str, public_key_b64: &str) -> Result<String, CryptoError> {
+    let public_key = STANDARD
+        .decode(public_key_b64)
+        .map_err(|_| CryptoError::InvalidKey)?;
+
+    bitwarden_crypto::fingerprint(user_id, &public_key)
+}
+
+fn public_key_from_b64(public_key_b64: &str) -> Result<AsymmetricPublicCryptoKey, CryptoError> {
+    let der = STANDARD
+        .decode(public_key_b64)
+        .map_err(|_| CryptoError::InvalidKey)?;
+
+    AsymmetricPublicCryptoKey::from_der(&SpkiPublicKeyBytes::from(der))
+}
+
+/// Encrypt a string with a symmetric key
+///
+/// # Returns
+/// The ciphertext as an EncString (type 2, AES-CBC-HMAC)
+pub fn encrypt_string(plaintext: &str, key: &SymmetricCryptoKey) -> Result<String, CryptoError> {
+    Ok(plaintext.to_string().encrypt_with_key(key)?.to_string())
+}
+
+/// Whether an EncString is RSA-encrypted (types 3-6) rather than symmetric
+pub fn is_rsa_enc_string(encrypted: &str) -> bool {
+    encrypted.parse::<EncString>().is_err() && encrypted.parse::<UnsignedSharedKey>().is_ok()
+}
+
+/// Decrypt RSA EncString data (types 3 and 4) with a private key
+///
+/// The deprecated types 5 and 6 carry a MAC that can't be checked without
+/// the sender's key, so they are rejected.
+fn decrypt_rsa(private_key: &AsymmetricCryptoKey, encrypted: &str) -> Result<Vec<u8>, CryptoError> {
+    let rsa_key = RsaPrivateKey::from_pkcs8_der(private_key.to_der()?.as_ref())
+        .map_err(|_| CryptoError::InvalidKey)?;
+
+    let result = match encrypted.parse::<UnsignedSharedKey>()? {
+        UnsignedSharedKey::Rsa2048_OaepSha256_B64 { data } => {
+            rsa_key.decrypt(Oaep::new::<sha2::Sha256>(), &data)
+        }
+        UnsignedSharedKey::Rsa2048_OaepSha1_B64 { data } => {
+            rsa_key.decrypt(Oaep::new::<sha1::Sha1>(), &data)
+        }
+        _ => return Err(CryptoError::InvalidKey),
+    };
+    result.map_err(|_| CryptoError::InvalidKey)
+}
+
+/// Decrypt an EncString to text
+///
+/// Symmetric EncStrings are decrypted with `key`. RSA EncStrings are
+/// decrypted with `private_key`; data that isn't UTF-8 text, such as a
+/// shared organization key, is returned base64-encoded.
+///
+/// # Arguments
+/// * `encrypted` - The EncString to decrypt
+/// * `key` - User or organization key for symmetric EncStrings
+/// * `private_key` - The user's private key, needed for RSA EncStrings
+pub fn decrypt_string(
+    encrypted: &str,
+    key: &SymmetricCryptoKey,
+    private_key: Option<&AsymmetricCryptoKey>,
+) -> Result<String, CryptoError> {
+    match encrypted.parse::<EncString>() {
+        Ok(enc_string) => enc_string.decrypt_with_key(key),
+        Err(e) => {
+            // Not a symmetric EncString; try the RSA types before giving up
+            if !is_rsa_enc_string(encrypted) {
+                return Err(e);
+            }
+            let private_key = private_key.ok_or(CryptoError::InvalidKey)?;
+            match String::from_utf8(decrypt_rsa(private_key, encrypted)?) {
+                Ok(text) => Ok(text),
+                Err(e) => Ok(STANDARD.encode(e.into_bytes())),
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::num::NonZeroU32;
 
     use super::*;
+    use bitwarden_crypto::PublicKeyEncryptionAlgorithm;
 
     #[test]
     fn test_derive_master_key_pbkdf2() {
@@ -152,4 +394,122 @@ mod tests {
         let result = decrypt_user_key(&master_key, "invalid");
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_pin_protected_user_key_roundtrip() {
+        let kdf = Kdf::PBKDF2 {
+            iterations: NonZeroU32::new(100_000).unwrap(),
+        };
+        let user_key = SymmetricCryptoKey::make_aes256_cbc_hmac_key();
+
+        let pin_key =
+            derive_pin_key("1234", "test@bitwarden.com", &kdf).expect("Should derive key");
+        let encrypted = encrypt_user_key_with_pin(&pin_key, &user_key).expect("Should encrypt");
+
+        // The email is normalized like for the master key
+        let same_pin_key =
+            derive_pin_key("1234", " TEST@bitwarden.com", &kdf).expect("Should derive key");
+        let decrypted =
+            decrypt_user_key_with_pin(&same_pin_key, &encrypted).expect("Should decrypt");
+        assert_eq!(
+            decrypted.to_base64().to_string(),
+            user_key.to_base64().to_string()
+        );
+
+        let wrong_pin_key =
+            derive_pin_key("4321", "test@bitwarden.com", &kdf).expect("Should derive key");
+        assert!(decrypt_user_key_with_pin(&wrong_pin_key, &encrypted).is_err());
+    }
+
+    #[test]
+    fn test_encrypt_decrypt_string_roundtrip() {
+        let key = SymmetricCryptoKey::make_aes256_cbc_hmac_key();
+
+        let encrypted = encrypt_string("secret value", &key).expect("Should encrypt");
+        assert!(encrypted.starts_with("2."));
+
+        let decrypted = decrypt_string(&encrypted, &key, None).expect("Should decrypt");
+        assert_eq!(decrypted, "secret value");
+    }
+
+    #[test]
+    fn test_decrypt_string_wrong_key_fails() {
+        let key = SymmetricCryptoKey::make_aes256_cbc_hmac_key();
+        let other_key = SymmetricCryptoKey::make_aes256_cbc_hmac_key();
+
+        let encrypted = encrypt_string("secret value", &key).expect("Should encrypt");
+
+        assert!(decrypt_string(&encrypted, &other_key, None).is_err());
+        assert!(decrypt_string("not-an-enc-string", &key, None).is_err());
+    }
+
+    #[test]
+    fn test_encapsulate_key_roundtrip() {
+        let private_key = AsymmetricCryptoKey::make(PublicKeyEncryptionAlgorithm::RsaOaepSha1);
+        let public_key = STANDARD.encode(private_key.to_public_key().to_der().unwrap());
+        let org_key = SymmetricCryptoKey::make_aes256_cbc_hmac_key();
+
+        let encrypted = encapsulate_key(&org_key, &public_key).expect("Should encrypt");
+        let decrypted = decrypt_shared_key(&private_key, &encrypted).expect("Should decrypt");
+        assert_eq!(
+            decrypted.to_base64().to_string(),
+            org_key.to_base64().to_string()
+        );
+
+        assert!(is_rsa_enc_string(&encrypted));
+        assert!(!is_rsa_enc_string(
+            &encrypt_string("secret value", &org_key).unwrap()
+        ));
+
+        let phrase = fingerprint_phrase("user-id", &public_key).expect("Should fingerprint");
+        assert_eq!(phrase.split('-').count(), 5);
+        assert_eq!(phrase, fingerprint_phrase("user-id", &public_key).unwrap());
+    }
+
+    /// RSA-OAEP-SHA1 EncString (type 4) of `data` for `private_key`
+    fn rsa_enc_string(private_key: &AsymmetricCryptoKey, data: &[u8]) -> String {
+        use rsa::{RsaPublicKey, pkcs8::DecodePublicKey};
+
+        let public_key = RsaPublicKey::from_public_key_der(
+            private_key.to_public_key().to_der().unwrap().as_ref(),
+        )
+        .unwrap();
+        let encrypted = public_key
+            .encrypt(&mut rand::thread_rng(), Oaep::new::<sha1::Sha1>(), data)
+            .unwrap();
+        format!("4.{}", STANDARD.encode(encrypted))
+    }
+
+    #[test]
+    fn test_decrypt_rsa_string_to_text() {
+        let private_key = AsymmetricCryptoKey::make(PublicKeyEncryptionAlgorithm::RsaOaepSha1);
+        let key = SymmetricCryptoKey::make_aes256_cbc_hmac_key();
+        let encrypted = rsa_enc_string(&private_key, "secret value".as_bytes());
+
+        assert!(is_rsa_enc_string(&encrypted));
+        assert_eq!(
+            decrypt_string(&encrypted, &key, Some(&private_key)).expect("Should decrypt"),
+            "secret value"
+        );
+
+        // Without the private key, RSA EncStrings can't be decrypted
+        assert!(decrypt_string(&encrypted, &key, None).is_err());
+    }
+
+    #[test]
+    fn test_decrypt_rsa_shared_key_falls_back_to_base64() {
+        let private_key = AsymmetricCryptoKey::make(PublicKeyEncryptionAlgorithm::RsaOaepSha1);
+        let public_key = STANDARD.encode(private_key.to_public_key().to_der().unwrap());
+        let org_key = SymmetricCryptoKey::make_aes256_cbc_hmac_key();
+
+        // Key bytes aren't UTF-8, so they come back base64-encoded
+        let encrypted = encapsulate_key(&org_key, &public_key).expect("Should encrypt");
+        assert_eq!(
+            decrypt_string(&encrypted, &org_key, Some(&private_key)).expect("Should decrypt"),
+            org_key.to_base64().to_string()
+        );
+
+        let other_key = AsymmetricCryptoKey::make(PublicKeyEncryptionAlgorithm::RsaOaepSha1);
+        assert!(decrypt_string(&encrypted, &org_key, Some(&other_key)).is_err());
+    }
 }
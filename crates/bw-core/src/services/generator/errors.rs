@@ -0,0 +1,60 @@
+use crate::models::state::ForwarderService;
+use crate::services::key_service::KeyServiceError;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum GeneratorError {
+    #[error("Invalid input: {0}")]
+    InvalidInput(String),
+
+    #[error(
+        "No character sets enabled. Enable at least one of: lowercase, uppercase, numbers, or special characters"
+    )]
+    NoCharacterSetEnabled,
+
+    #[error(
+        "Invalid password length. Length must be at least 4 and greater than the sum of minimum character requirements"
+    )]
+    InvalidLength,
+
+    #[error("Invalid word count. Number of words must be between {minimum} and {maximum}")]
+    InvalidNumWords { minimum: u8, maximum: u8 },
+
+    #[error("Invalid wordlist: {0}")]
+    Wordlist(String),
+
+    #[error(
+        "Passphrase entropy of {bits:.1} bits is below the required {required:.1} bits. Use more words or a larger wordlist"
+    )]
+    InsufficientEntropy { bits: f64, required: f64 },
+
+    #[error("Not authenticated. Run 'bw login' first.")]
+    NotAuthenticated,
+
+    #[error("Vault is locked. Run 'bw unlock' first.")]
+    Locked,
+
+    #[error("No API token configured for {0}. Run 'bw config forwarder {0} <token>' first.")]
+    ForwarderNotConfigured(ForwarderService),
+
+    #[error("{service} rejected the API token")]
+    InvalidApiToken { service: ForwarderService },
+
+    #[error("{service} error: {message}")]
+    Forwarder {
+        service: ForwarderService,
+        message: String,
+    },
+
+    #[error("Generation failed: {0}")]
+    Generation(String),
+
+    #[error("Encryption error: {0}")]
+    Encryption(String),
+
+    #[error("Storage error: {0}")]
+    StorageError(String),
+
+    #[error(transparent)]
+    Key(#[from] KeyServiceError),
+}
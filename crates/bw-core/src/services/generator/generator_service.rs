@@ -0,0 +1,515 @@
+//! Password, passphrase and username generation and generator settings
+
+use super::errors::GeneratorError;
+use super::wordlist::{
+    EFF_WORDLIST_SIZE, MAX_PASSPHRASE_WORDS, MIN_PASSPHRASE_ENTROPY_BITS, MIN_PASSPHRASE_WORDS,
+    Wordlist, passphrase_entropy,
+};
+use crate::models::state::{ForwarderService, ForwarderSettings, GeneratorOptions, GeneratorType};
+use crate::services::crypto;
+use crate::services::key_service::KeyService;
+use crate::services::storage::{AccountManager, JsonFileStorage, Storage, StorageKey};
+use bitwarden_core::Client;
+use bitwarden_generators::{
+    AppendType, ForwarderServiceType, GeneratorClientsExt, PassphraseError,
+    PassphraseGeneratorRequest, PasswordError, PasswordGeneratorRequest, UsernameError,
+    UsernameGeneratorRequest,
+};
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+/// Kind of username to generate
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UsernameRequest {
+    /// Random word
+    Word {
+        capitalize: bool,
+        include_number: bool,
+    },
+    /// Plus-addressed email (`user+abc123@example.com`)
+    Subaddress {
+        email: String,
+        website: Option<String>,
+    },
+    /// Address on a catch-all domain (`abc123@example.com`)
+    Catchall {
+        domain: String,
+        website: Option<String>,
+    },
+    /// Alias created by an email forwarding service
+    Forwarded {
+        service: ForwarderService,
+        website: Option<String>,
+    },
+}
+
+/// A generated passphrase and its entropy
+#[derive(Debug, Clone)]
+pub struct GeneratedPassphrase {
+    pub passphrase: String,
+    /// Entropy in bits
+    pub entropy: f64,
+}
+
+/// Service for generating passwords, passphrases and usernames
+pub struct GeneratorService {
+    storage: Arc<Mutex<JsonFileStorage>>,
+    account_manager: Arc<AccountManager>,
+    key_service: KeyService,
+}
+
+impl GeneratorService {
+    /// Create new generator service
+    pub fn new(storage: Arc<Mutex<JsonFileStorage>>, account_manager: Arc<AccountManager>) -> Self {
+        let key_service = KeyService::new(Arc::clone(&storage), Arc::clone(&account_manager));
+        Self {
+            storage,
+            account_manager,
+            key_service,
+        }
+    }
+
+    /// Generate a password or passphrase, depending on `options.generator_type`
+    pub fn generate(&self, options: &GeneratorOptions) -> Result<String, GeneratorError> {
+        match options.generator_type {
+            GeneratorType::Password => self.generate_password(options),
+            GeneratorType::Passphrase => self
+                .generate_passphrase(options)
+                .map(|generated| generated.passphrase),
+        }
+    }
+
+    /// Generate a password from the password options
+    pub fn generate_password(&self, options: &GeneratorOptions) -> Result<String, GeneratorError> {
+        let request = PasswordGeneratorRequest {
+            length: options.length,
+            lowercase: options.lowercase,
+            uppercase: options.uppercase,
+            numbers: options.numbers,
+            special: options.special,
+            avoid_ambiguous: options.avoid_ambiguous,
+            min_lowercase: options.min_lowercase,
+            min_uppercase: options.min_uppercase,
+            min_number: options.min_number,
+            min_special: options.min_special,
+        };
+
+        // The SDK generator uses a thread-local CSPRNG seeded from the OS
+        let client = Client::new(None);
+        client.generator().password(request).map_err(|e| match e {
+            PasswordError::NoCharacterSetEnabled => GeneratorError::NoCharacterSetEnabled,
+            PasswordError::InvalidLength => GeneratorError::InvalidLength,
+        })
+    }
+
+    /// Generate a passphrase from the passphrase options
+    ///
+    /// Words come from `options.wordlist` when set, otherwise from the SDK's
+    /// built-in EFF list. The passphrase must reach `options.min_entropy`
+    /// bits, or [`MIN_PASSPHRASE_ENTROPY_BITS`] when unset, so a small
+    /// wordlist can't silently produce a weak passphrase.
+    pub fn generate_passphrase(
+        &self,
+        options: &GeneratorOptions,
+    ) -> Result<GeneratedPassphrase, GeneratorError> {
+        // 1. Validate the word count and load the custom wordlist, if any
+        if !(MIN_PASSPHRASE_WORDS..=MAX_PASSPHRASE_WORDS).contains(&options.num_words) {
+            return Err(GeneratorError::InvalidNumWords {
+                minimum: MIN_PASSPHRASE_WORDS,
+                maximum: MAX_PASSPHRASE_WORDS,
+            });
+        }
+        let wordlist = match &options.wordlist {
+            Some(path) => Some(Wordlist::load(Path::new(path))?),
+            None => None,
+        };
+
+        // 2. Check the entropy before generating anything
+        let list_size = wordlist.as_ref().map_or(EFF_WORDLIST_SIZE, Wordlist::len);
+        let entropy = passphrase_entropy(list_size, options.num_words, options.include_number);
+        let required = options.min_entropy.unwrap_or(MIN_PASSPHRASE_ENTROPY_BITS);
+        if entropy < required {
+            return Err(GeneratorError::InsufficientEntropy {
+                bits: entropy,
+                required,
+            });
+        }
+
+        // 3. Generate from the custom list or with the SDK
+        let passphrase = match wordlist {
+            Some(wordlist) => wordlist.generate(
+                options.num_words,
+                &options.word_separator,
+                options.capitalize,
+                options.include_number,
+            )?,
+            None => {
+                let request = PassphraseGeneratorRequest {
+                    num_words: options.num_words,
+                    word_separator: options.word_separator.clone(),
+                    capitalize: options.capitalize,
+                    include_number: options.include_number,
+                };
+
+                let client = Client::new(None);
+                client
+                    .generator()
+                    .passphrase(request)
+                    .map_err(|e| match e {
+                        PassphraseError::InvalidNumWords { minimum, maximum } => {
+                            GeneratorError::InvalidNumWords { minimum, maximum }
+                        }
+                    })?
+            }
+        };
+
+        Ok(GeneratedPassphrase {
+            passphrase,
+            entropy,
+        })
+    }
+
+    /// Get the active account's generator defaults
+    ///
+    /// Falls back to the built-in defaults when nothing is saved or no
+    /// account is logged in.
+    pub async fn get_options(&self) -> Result<GeneratorOptions, GeneratorError> {
+        let Some(user_id) = self.active_user_id().await? else {
+            return Ok(GeneratorOptions::default());
+        };
+
+        let storage = self.storage.lock().await;
+        Ok(storage
+            .get(&StorageKey::UserGeneratorOptions.format(Some(&user_id)))
+            .map_err(|e| GeneratorError::StorageError(e.to_string()))?
+            .unwrap_or_default())
+    }
+
+    /// Save the active account's generator defaults
+    ///
+    /// Both the password and passphrase options must be usable, so a saved
+    /// default can't break later `bw generate` calls.
+    pub async fn set_options(&self, options: &GeneratorOptions) -> Result<(), GeneratorError> {
+        let user_id = self
+            .active_user_id()
+            .await?
+            .ok_or(GeneratorError::NotAuthenticated)?;
+
+        self.generate_password(options)?;
+        self.generate_passphrase(options)?;
+
+        let mut storage = self.storage.lock().await;
+        storage
+            .set(
+                &StorageKey::UserGeneratorOptions.format(Some(&user_id)),
+                options,
+            )
+            .await
+            .map_err(|e| GeneratorError::StorageError(e.to_string()))
+    }
+
+    /// Generate a username
+    ///
+    /// Forwarded aliases are created on the forwarding service using the
+    /// credentials saved with [`GeneratorService::set_forwarder`], which
+    /// need the session to decrypt. Other usernames don't.
+    pub async fn generate_username(
+        &self,
+        request: UsernameRequest,
+        session: Option<&str>,
+    ) -> Result<String, GeneratorError> {
+        // 1. Build the SDK request; forwarded aliases are created separately
+        let request = match request {
+            UsernameRequest::Word {
+                capitalize,
+                include_number,
+            } => UsernameGeneratorRequest::Word {
+                capitalize,
+                include_number,
+            },
+            UsernameRequest::Subaddress { email, website } => {
+                if !email.contains('@') {
+                    return Err(GeneratorError::InvalidInput(format!(
+                        "'{}' is not an email address",
+                        email
+                    )));
+                }
+                UsernameGeneratorRequest::Subaddress {
+                    r#type: append_type(website),
+                    email,
+                }
+            }
+            UsernameRequest::Catchall { domain, website } => {
+                if domain.trim().is_empty() {
+                    return Err(GeneratorError::InvalidInput(
+                        "A catch-all domain is required".to_string(),
+                    ));
+                }
+                UsernameGeneratorRequest::Catchall {
+                    r#type: append_type(website),
+                    domain,
+                }
+            }
+            UsernameRequest::Forwarded { service, website } => {
+                let session = session.ok_or(GeneratorError::Locked)?;
+                let settings = self
+                    .get_forwarder(service, session)
+                    .await?
+                    .ok_or(GeneratorError::ForwarderNotConfigured(service))?;
+                return create_alias(service, settings, website).await;
+            }
+        };
+
+        // 2. Generate with the SDK
+        let client = Client::new(None);
+        client
+            .generator()
+            .username(request)
+            .await
+            .map_err(|e| GeneratorError::Generation(e.to_string()))
+    }
+
+    /// Get the active account's credentials for a forwarding service
+    ///
+    /// The API token is returned decrypted.
+    pub async fn get_forwarder(
+        &self,
+        service: ForwarderService,
+        session: &str,
+    ) -> Result<Option<ForwarderSettings>, GeneratorError> {
+        let user_id = self
+            .active_user_id()
+            .await?
+            .ok_or(GeneratorError::NotAuthenticated)?;
+
+        let Some(mut settings) = self
+            .get_forwarders(&user_id)
+            .await?
+            .remove(service.as_str())
+        else {
+            return Ok(None);
+        };
+        let user_key = self.key_service.get_user_key(session).await?;
+        settings.api_token = crypto::decrypt_string(&settings.api_token, &user_key, None)
+            .map_err(|e| GeneratorError::Encryption(e.to_string()))?;
+        Ok(Some(settings))
+    }
+
+    /// Save the active account's credentials for a forwarding service
+    ///
+    /// The API token is encrypted with the user key before it's stored.
+    pub async fn set_forwarder(
+        &self,
+        service: ForwarderService,
+        mut settings: ForwarderSettings,
+        session: &str,
+    ) -> Result<(), GeneratorError> {
+        if settings.api_token.trim().is_empty() {
+            return Err(GeneratorError::InvalidInput(
+                "API token must not be empty".to_string(),
+            ));
+        }
+        if service.requires_domain() && settings.domain.is_none() {
+            return Err(GeneratorError::InvalidInput(format!(
+                "{} requires an alias domain",
+                service
+            )));
+        }
+        if settings.base_url.is_some() && service.default_base_url().is_none() {
+            return Err(GeneratorError::InvalidInput(format!(
+                "{} doesn't support a custom API URL",
+                service
+            )));
+        }
+
+        let user_id = self
+            .active_user_id()
+            .await?
+            .ok_or(GeneratorError::NotAuthenticated)?;
+        let user_key = self.key_service.get_user_key(session).await?;
+        settings.api_token = crypto::encrypt_string(&settings.api_token, &user_key)
+            .map_err(|e| GeneratorError::Encryption(e.to_string()))?;
+
+        let mut forwarders = self.get_forwarders(&user_id).await?;
+        forwarders.insert(service.as_str().to_string(), settings);
+
+        let mut storage = self.storage.lock().await;
+        storage
+            .set(
+                &StorageKey::UserForwarders.format(Some(&user_id)),
+                &forwarders,
+            )
+            .await
+            .map_err(|e| GeneratorError::StorageError(e.to_string()))
+    }
+
+    async fn active_user_id(&self) -> Result<Option<String>, GeneratorError> {
+        self.account_manager
+            .get_active_user_id()
+            .await
+            .map_err(|e| GeneratorError::StorageError(e.to_string()))
+    }
+
+    /// Saved forwarder settings, with the API tokens still encrypted
+    async fn get_forwarders(
+        &self,
+        user_id: &str,
+    ) -> Result<HashMap<String, ForwarderSettings>, GeneratorError> {
+        let storage = self.storage.lock().await;
+        Ok(storage
+            .get(&StorageKey::UserForwarders.format(Some(user_id)))
+            .map_err(|e| GeneratorError::StorageError(e.to_string()))?
+            .unwrap_or_default())
+    }
+}
+
+/// Append the website name when one is given, random characters otherwise
+fn append_type(website: Option<String>) -> AppendType {
+    match website {
+        Some(website) => AppendType::WebsiteName { website },
+        None => AppendType::Random,
+    }
+}
+
+/// Create an alias on a forwarding service
+async fn create_alias(
+    service: ForwarderService,
+    settings: ForwarderSettings,
+    website: Option<String>,
+) -> Result<String, GeneratorError> {
+    let request = UsernameGeneratorRequest::Forwarded {
+        service: forwarder_type(service, settings)?,
+        website,
+    };
+    let client = Client::new(None);
+    client
+        .generator()
+        .username(request)
+        .await
+        .map_err(|e| match e {
+            UsernameError::InvalidApiKey => GeneratorError::InvalidApiToken { service },
+            e => GeneratorError::Forwarder {
+                service,
+                message: e.to_string(),
+            },
+        })
+}
+
+/// SDK forwarder for a service and its saved settings
+fn forwarder_type(
+    service: ForwarderService,
+    settings: ForwarderSettings,
+) -> Result<ForwarderServiceType, GeneratorError> {
+    let ForwarderSettings {
+        api_token,
+        domain,
+        base_url,
+    } = settings;
+    // The SDK only takes an API URL for the services that can be self-hosted
+    let base_url = base_url
+        .as_deref()
+        .or(service.default_base_url())
+        .unwrap_or_default()
+        .trim_end_matches('/')
+        .to_string();
+    let domain = || {
+        domain.clone().ok_or_else(|| {
+            GeneratorError::InvalidInput(format!("{} requires an alias domain", service))
+        })
+    };
+
+    Ok(match service {
+        ForwarderService::SimpleLogin => ForwarderServiceType::SimpleLogin {
+            api_key: api_token,
+            base_url,
+        },
+        ForwarderService::AddyIo => ForwarderServiceType::AddyIo {
+            api_token,
+            domain: domain()?,
+            base_url,
+        },
+        ForwarderService::Firefox => ForwarderServiceType::Firefox { api_token },
+        ForwarderService::Fastmail => ForwarderServiceType::Fastmail { api_token },
+        ForwarderService::DuckDuckGo => ForwarderServiceType::DuckDuckGo { token: api_token },
+        ForwarderService::ForwardEmail => ForwarderServiceType::ForwardEmail {
+            api_token,
+            domain: domain()?,
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settings(domain: Option<&str>, base_url: Option<&str>) -> ForwarderSettings {
+        ForwarderSettings {
+            api_token: "token".to_string(),
+            domain: domain.map(str::to_string),
+            base_url: base_url.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_forwarder_type_uses_default_base_url() {
+        let forwarder = forwarder_type(ForwarderService::SimpleLogin, settings(None, None));
+
+        assert!(matches!(
+            forwarder,
+            Ok(ForwarderServiceType::SimpleLogin { base_url, .. })
+                if base_url == "https://app.simplelogin.io"
+        ));
+    }
+
+    #[test]
+    fn test_forwarder_type_uses_saved_base_url() {
+        let forwarder = forwarder_type(
+            ForwarderService::AddyIo,
+            settings(Some("anonaddy.me"), Some("https://addy.example.com/")),
+        );
+
+        assert!(matches!(
+            forwarder,
+            Ok(ForwarderServiceType::AddyIo { base_url, domain, .. })
+                if base_url == "https://addy.example.com" && domain == "anonaddy.me"
+        ));
+    }
+
+    #[test]
+    fn test_forwarder_type_for_every_service() {
+        for service in ForwarderService::ALL {
+            let forwarder = forwarder_type(service, settings(Some("example.org"), None)).unwrap();
+
+            let matches = match service {
+                ForwarderService::SimpleLogin => {
+                    matches!(forwarder, ForwarderServiceType::SimpleLogin { .. })
+                }
+                ForwarderService::AddyIo => {
+                    matches!(forwarder, ForwarderServiceType::AddyIo { .. })
+                }
+                ForwarderService::Firefox => {
+                    matches!(forwarder, ForwarderServiceType::Firefox { .. })
+                }
+                ForwarderService::Fastmail => {
+                    matches!(forwarder, ForwarderServiceType::Fastmail { .. })
+                }
+                ForwarderService::DuckDuckGo => {
+                    matches!(forwarder, ForwarderServiceType::DuckDuckGo { .. })
+                }
+                ForwarderService::ForwardEmail => {
+                    matches!(forwarder, ForwarderServiceType::ForwardEmail { .. })
+                }
+            };
+            assert!(matches, "{} mapped to the wrong SDK forwarder", service);
+        }
+    }
+
+    #[test]
+    fn test_forwarder_type_requires_domain() {
+        let result = forwarder_type(ForwarderService::ForwardEmail, settings(None, None));
+
+        assert!(matches!(result, Err(GeneratorError::InvalidInput(_))));
+    }
+}
@@ -0,0 +1,23 @@
+//! Generator service module
+//!
+//! Passwords, passphrases and usernames are generated by the SDK's
+//! `bitwarden_generators`, including aliases created through email
+//! forwarding services. Each account's forwarder credentials, with the API
+//! token encrypted by the user key, and generator defaults are kept in local
+//! storage so they don't have to be passed on every call.
+//!
+//! Passphrases can also be drawn from a custom wordlist, with the entropy of
+//! the result checked against a minimum.
+
+mod errors;
+mod generator_service;
+mod wordlist;
+
+pub use errors::GeneratorError;
+pub use generator_service::{GeneratedPassphrase, GeneratorService, UsernameRequest};
+pub use wordlist::{EFF_WORDLIST_SIZE, MIN_PASSPHRASE_ENTROPY_BITS, Wordlist, passphrase_entropy};
+
+// Re-export generator settings models for convenience
+pub use crate::models::state::{
+    ForwarderService, ForwarderSettings, GeneratorOptions, GeneratorType,
+};
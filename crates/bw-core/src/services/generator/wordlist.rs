@@ -0,0 +1,212 @@
+//! Passphrase wordlists and entropy estimation
+
+use super::errors::GeneratorError;
+use rand::{Rng, rngs::OsRng, seq::SliceRandom};
+use std::collections::HashSet;
+use std::path::Path;
+
+/// Number of words in the SDK's built-in EFF long wordlist
+pub const EFF_WORDLIST_SIZE: usize = 7776;
+
+/// Entropy a passphrase must reach unless a different minimum is requested
+///
+/// Slightly below three words from the built-in list (about 38.8 bits), so
+/// the shortest built-in passphrase passes and a custom list has to match it.
+pub const MIN_PASSPHRASE_ENTROPY_BITS: f64 = 38.0;
+
+/// Word count limits, matching the SDK's passphrase generator
+pub const MIN_PASSPHRASE_WORDS: u8 = 3;
+pub const MAX_PASSPHRASE_WORDS: u8 = 20;
+
+/// Largest wordlist file that will be read
+const MAX_WORDLIST_FILE_SIZE: u64 = 10 * 1024 * 1024;
+
+/// Entropy in bits of a passphrase of `num_words` words from a list of
+/// `list_size` unique words
+///
+/// `include_number` adds a digit to one of the words, so it contributes the
+/// choice of digit and of the word it's appended to. Capitalization is
+/// applied to every word and adds nothing.
+pub fn passphrase_entropy(list_size: usize, num_words: u8, include_number: bool) -> f64 {
+    if list_size == 0 || num_words == 0 {
+        return 0.0;
+    }
+
+    let mut bits = f64::from(num_words) * (list_size as f64).log2();
+    if include_number {
+        bits += (10.0 * f64::from(num_words)).log2();
+    }
+    bits
+}
+
+/// A custom passphrase wordlist
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Wordlist {
+    words: Vec<String>,
+}
+
+impl Wordlist {
+    /// Parse a newline-delimited or diceware-formatted wordlist
+    ///
+    /// Diceware lines start with the dice roll (`11111 abacus`); the roll is
+    /// dropped. Blank lines and `#` comments are skipped, and duplicates are
+    /// removed so they can't inflate the entropy estimate.
+    pub fn parse(contents: &str) -> Result<Self, GeneratorError> {
+        let mut seen = HashSet::new();
+        let mut words = Vec::new();
+
+        for (index, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut tokens = line.split_whitespace();
+            let word = match (tokens.next(), tokens.next(), tokens.next()) {
+                (Some(word), None, None) => word,
+                (Some(roll), Some(word), None) if roll.chars().all(|c| c.is_ascii_digit()) => word,
+                _ => {
+                    return Err(GeneratorError::Wordlist(format!(
+                        "line {} has more than one word",
+                        index + 1
+                    )));
+                }
+            };
+
+            if seen.insert(word) {
+                words.push(word.to_string());
+            }
+        }
+
+        if words.is_empty() {
+            return Err(GeneratorError::Wordlist("no words found".to_string()));
+        }
+
+        Ok(Self { words })
+    }
+
+    /// Read and parse a wordlist file
+    pub fn load(path: &Path) -> Result<Self, GeneratorError> {
+        let read_error =
+            |e: std::io::Error| GeneratorError::Wordlist(format!("{}: {}", path.display(), e));
+
+        let size = std::fs::metadata(path).map_err(read_error)?.len();
+        if size > MAX_WORDLIST_FILE_SIZE {
+            return Err(GeneratorError::Wordlist(format!(
+                "{} is larger than {} MB",
+                path.display(),
+                MAX_WORDLIST_FILE_SIZE / (1024 * 1024)
+            )));
+        }
+
+        let contents = std::fs::read_to_string(path).map_err(read_error)?;
+        Self::parse(&contents)
+    }
+
+    /// Number of unique words
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    /// Generate a passphrase, following the SDK's format
+    ///
+    /// Words are picked uniformly with the OS random number generator. A
+    /// random digit is appended to one random word when `include_number` is
+    /// set.
+    pub fn generate(
+        &self,
+        num_words: u8,
+        word_separator: &str,
+        capitalize: bool,
+        include_number: bool,
+    ) -> Result<String, GeneratorError> {
+        if !(MIN_PASSPHRASE_WORDS..=MAX_PASSPHRASE_WORDS).contains(&num_words) {
+            return Err(GeneratorError::InvalidNumWords {
+                minimum: MIN_PASSPHRASE_WORDS,
+                maximum: MAX_PASSPHRASE_WORDS,
+            });
+        }
+
+        let mut rng = OsRng;
+        let mut words: Vec<String> = (0..num_words)
+            .filter_map(|_| self.words.choose(&mut rng))
+            .map(|word| {
+                if capitalize {
+                    capitalize_first(word)
+                } else {
+                    word.clone()
+                }
+            })
+            .collect();
+
+        if include_number {
+            let index = rng.gen_range(0..words.len());
+            words[index].push_str(&rng.gen_range(0..10).to_string());
+        }
+
+        Ok(words.join(word_separator))
+    }
+}
+
+fn capitalize_first(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_newline_delimited() {
+        let wordlist = Wordlist::parse("apple\nbanana\n\n# fruit\ncherry\n").unwrap();
+
+        assert_eq!(wordlist.len(), 3);
+    }
+
+    #[test]
+    fn test_parse_diceware_removes_rolls_and_duplicates() {
+        let wordlist = Wordlist::parse("11111\tabacus\n11112 abdomen\n11113\tabacus\n").unwrap();
+
+        assert_eq!(wordlist.words, vec!["abacus", "abdomen"]);
+    }
+
+    #[test]
+    fn test_parse_rejects_phrases() {
+        let result = Wordlist::parse("apple\nbanana split\n");
+
+        assert!(matches!(result, Err(GeneratorError::Wordlist(_))));
+    }
+
+    #[test]
+    fn test_passphrase_entropy() {
+        let bits = passphrase_entropy(EFF_WORDLIST_SIZE, 3, false);
+        assert!((bits - 38.77).abs() < 0.01);
+        assert!(bits >= MIN_PASSPHRASE_ENTROPY_BITS);
+
+        let bits = passphrase_entropy(1024, 4, true);
+        assert!((bits - (40.0 + 40f64.log2())).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_generate_uses_list_words() {
+        let wordlist = Wordlist::parse("alpha\nbravo\ncharlie\n").unwrap();
+
+        let passphrase = wordlist.generate(4, ".", true, false).unwrap();
+
+        let words: Vec<&str> = passphrase.split('.').collect();
+        assert_eq!(words.len(), 4);
+        assert!(
+            words
+                .iter()
+                .all(|w| ["Alpha", "Bravo", "Charlie"].contains(w))
+        );
+    }
+}
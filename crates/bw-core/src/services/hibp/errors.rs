@@ -0,0 +1,16 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum HibpError {
+    #[error("Invalid configuration: {0}")]
+    Configuration(String),
+
+    #[error("Could not reach Have I Been Pwned: {0}")]
+    Network(String),
+
+    #[error("Have I Been Pwned returned status {status}")]
+    Server { status: u16 },
+
+    #[error("Invalid response from Have I Been Pwned: {0}")]
+    InvalidResponse(String),
+}
@@ -0,0 +1,164 @@
+//! Pwned Passwords range API client
+
+use super::errors::HibpError;
+use reqwest::Client as ReqwestClient;
+use sha1::{Digest, Sha1};
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// Base URL of the public Pwned Passwords API
+pub const DEFAULT_HIBP_URL: &str = "https://api.pwnedpasswords.com";
+
+/// Length of the hash prefix sent to the API
+const PREFIX_LEN: usize = 5;
+
+/// Client for the Pwned Passwords range API
+pub struct HibpClient {
+    http_client: ReqwestClient,
+    base_url: String,
+}
+
+impl HibpClient {
+    /// Create new client
+    ///
+    /// # Arguments
+    /// * `base_url` - API base URL (default: [`DEFAULT_HIBP_URL`])
+    pub fn new(base_url: Option<&str>) -> Result<Self, HibpError> {
+        let http_client = ReqwestClient::builder()
+            .timeout(Duration::from_secs(30))
+            .user_agent(format!(
+                "Bitwarden_CLI/{} (Rust)",
+                env!("CARGO_PKG_VERSION")
+            ))
+            .use_rustls_tls()
+            .build()
+            .map_err(|e| HibpError::Configuration(e.to_string()))?;
+
+        Ok(Self {
+            http_client,
+            base_url: base_url
+                .unwrap_or(DEFAULT_HIBP_URL)
+                .trim_end_matches('/')
+                .to_string(),
+        })
+    }
+
+    /// Number of times a password appears in known breaches
+    pub async fn breach_count(&self, password: &str) -> Result<u64, HibpError> {
+        let counts = self.breach_counts(&[password]).await?;
+        Ok(counts[0])
+    }
+
+    /// Breach counts for several passwords, in the same order
+    ///
+    /// Each distinct hash prefix is requested once, so duplicate passwords
+    /// and passwords sharing a prefix don't cause extra requests.
+    pub async fn breach_counts(&self, passwords: &[&str]) -> Result<Vec<u64>, HibpError> {
+        // 1. Hash every password
+        let hashes: Vec<String> = passwords.iter().map(|p| sha1_hex(p)).collect();
+
+        // 2. Fetch each distinct prefix once
+        let mut ranges: HashMap<&str, HashMap<String, u64>> = HashMap::new();
+        for hash in &hashes {
+            let prefix = &hash[..PREFIX_LEN];
+            if !ranges.contains_key(prefix) {
+                let range = self.fetch_range(prefix).await?;
+                ranges.insert(prefix, range);
+            }
+        }
+
+        // 3. Look up each suffix in its range
+        Ok(hashes
+            .iter()
+            .map(|hash| {
+                let (prefix, suffix) = hash.split_at(PREFIX_LEN);
+                ranges[prefix].get(suffix).copied().unwrap_or(0)
+            })
+            .collect())
+    }
+
+    /// Fetch the suffixes and counts for a hash prefix
+    async fn fetch_range(&self, prefix: &str) -> Result<HashMap<String, u64>, HibpError> {
+        let url = format!("{}/range/{}", self.base_url, prefix);
+
+        // Padding hides the real number of suffixes for the prefix
+        let response = self
+            .http_client
+            .get(&url)
+            .header("Add-Padding", "true")
+            .send()
+            .await
+            .map_err(|e| HibpError::Network(e.to_string()))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(HibpError::Server {
+                status: status.as_u16(),
+            });
+        }
+
+        let body = response
+            .text()
+            .await
+            .map_err(|e| HibpError::Network(e.to_string()))?;
+        parse_range(&body)
+    }
+}
+
+/// Uppercase hex SHA-1 of a password, as used by the API
+fn sha1_hex(password: &str) -> String {
+    Sha1::digest(password.as_bytes())
+        .iter()
+        .map(|b| format!("{:02X}", b))
+        .collect()
+}
+
+/// Parse `SUFFIX:COUNT` lines, dropping padding entries (count 0)
+fn parse_range(body: &str) -> Result<HashMap<String, u64>, HibpError> {
+    let mut range = HashMap::new();
+    for line in body.lines().map(str::trim).filter(|l| !l.is_empty()) {
+        let (suffix, count) = line
+            .split_once(':')
+            .ok_or_else(|| HibpError::InvalidResponse(format!("unexpected line '{}'", line)))?;
+        let count: u64 = count
+            .parse()
+            .map_err(|_| HibpError::InvalidResponse(format!("invalid count in '{}'", line)))?;
+
+        if count > 0 {
+            range.insert(suffix.to_ascii_uppercase(), count);
+        }
+    }
+    Ok(range)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sha1_hex() {
+        assert_eq!(
+            sha1_hex("password"),
+            "5BAA61E4C9B93F3F0682250B6CF8331B7EE68FD8"
+        );
+    }
+
+    #[test]
+    fn test_parse_range_skips_padding() {
+        let body = "1E4C9B93F3F0682250B6CF8331B7EE68FD8:3730471\r\n\
+                    0018A45C4D1DEF81644B54AB7F969B88D65:0\r\n";
+
+        let range = parse_range(body).unwrap();
+
+        assert_eq!(range.len(), 1);
+        assert_eq!(range["1E4C9B93F3F0682250B6CF8331B7EE68FD8"], 3730471);
+    }
+
+    #[test]
+    fn test_parse_range_rejects_garbage() {
+        assert!(matches!(
+            parse_range("<html>"),
+            Err(HibpError::InvalidResponse(_))
+        ));
+    }
+}
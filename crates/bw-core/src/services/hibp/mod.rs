@@ -0,0 +1,12 @@
+//! Have I Been Pwned password checks
+//!
+//! Passwords are checked against the Pwned Passwords range API using
+//! k-anonymity: only the first five hex characters of the password's SHA-1
+//! hash are sent, and the matching suffixes are compared locally. Responses
+//! are padded so their size doesn't reveal the prefix either.
+
+mod errors;
+mod hibp_client;
+
+pub use errors::HibpError;
+pub use hibp_client::{DEFAULT_HIBP_URL, HibpClient};
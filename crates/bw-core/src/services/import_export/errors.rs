@@ -28,6 +28,9 @@ pub enum ExportError {
     #[error("Failed to decrypt vault: {0}")]
     DecryptionError(String),
 
+    #[error("Failed to encrypt export: {0}")]
+    EncryptionError(String),
+
     #[error("I/O error: {0}")]
     IoError(#[from] std::io::Error),
 
@@ -62,6 +65,12 @@ pub enum ImportError {
     #[error("Password required for encrypted import")]
     PasswordRequired,
 
+    #[error("Invalid file password")]
+    InvalidPassword,
+
+    #[error("This export was encrypted by a different account and cannot be imported")]
+    AccountMismatch,
+
     #[error("Failed to import items: {0}")]
     ImportFailed(String),
 
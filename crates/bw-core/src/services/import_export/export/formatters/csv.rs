@@ -1,6 +1,6 @@
 //! CSV export formatter
 
-use crate::models::vault::{CipherType, CipherView, FolderId};
+use crate::models::vault::{CipherType, CipherView, CollectionId, FolderId};
 use crate::services::import_export::errors::ExportError;
 use crate::services::import_export::export::{ExportData, ExportFormatter, ExportOptions};
 use async_trait::async_trait;
@@ -16,9 +16,12 @@ impl CsvFormatter {
 
     /// Convert cipher to CSV record
     /// Returns a record with exactly 34 columns to match the Bitwarden CSV format
-    fn cipher_to_record(&self, cipher: &CipherView, folder_name: &str) -> Vec<String> {
+    ///
+    /// The first column holds the folder name, or the comma-separated
+    /// collection names for organization exports.
+    fn cipher_to_record(&self, cipher: &CipherView, container: &str) -> Vec<String> {
         let mut record = vec![
-            folder_name.to_string(),
+            container.to_string(),
             if cipher.favorite { "1" } else { "0" }.to_string(),
             Self::type_to_string(cipher.r#type),
             cipher.name.clone(),
@@ -156,13 +159,18 @@ impl ExportFormatter for CsvFormatter {
     async fn format(
         &self,
         data: &ExportData,
-        _options: &ExportOptions,
+        options: &ExportOptions,
     ) -> Result<Vec<u8>, ExportError> {
         let mut wtr = WriterBuilder::new().from_writer(vec![]);
+        let organization = options.organization_id.is_some();
 
         // Write header with all columns for all item types
         wtr.write_record(&[
-            "folder",
+            if organization {
+                "collections"
+            } else {
+                "folder"
+            },
             "favorite",
             "type",
             "name",
@@ -208,17 +216,34 @@ impl ExportFormatter for CsvFormatter {
             .filter_map(|f| f.id.clone().map(|id| (id, f.name.clone())))
             .collect();
 
+        // Build collection name map - CollectionView.id is Option<CollectionId>
+        let collection_map: std::collections::HashMap<CollectionId, String> = data
+            .collections
+            .iter()
+            .filter_map(|c| c.id.clone().map(|id| (id, c.name.clone())))
+            .collect();
+
         // Write ciphers
         for cipher in &data.ciphers {
-            // cipher.folder_id is Option<FolderId>
-            let folder_name = cipher
-                .folder_id
-                .as_ref()
-                .and_then(|id| folder_map.get(id))
-                .cloned()
-                .unwrap_or_default();
-
-            let record = self.cipher_to_record(cipher, &folder_name);
+            let container = if organization {
+                cipher
+                    .collection_ids
+                    .iter()
+                    .filter_map(|id| collection_map.get(id))
+                    .cloned()
+                    .collect::<Vec<_>>()
+                    .join(",")
+            } else {
+                // cipher.folder_id is Option<FolderId>
+                cipher
+                    .folder_id
+                    .as_ref()
+                    .and_then(|id| folder_map.get(id))
+                    .cloned()
+                    .unwrap_or_default()
+            };
+
+            let record = self.cipher_to_record(cipher, &container);
             wtr.write_record(&record)?;
         }
 
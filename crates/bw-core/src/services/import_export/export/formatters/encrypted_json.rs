@@ -1,22 +1,59 @@
 //! Encrypted JSON export formatter
 //!
-//! Note: This is a placeholder implementation. Full encryption requires
-//! the Bitwarden SDK to be integrated for proper key derivation and encryption.
+//! Produces the two encrypted formats the Bitwarden clients understand:
+//!
+//! - **Password protected**: the plaintext JSON export encrypted with a key
+//!   derived from a file password, a random salt, and the account's KDF
+//!   settings. Can be imported into any account that knows the password.
+//! - **Account restricted**: the vault's encrypted ciphers and folders as
+//!   stored on the server, plus a validation string encrypted with the user
+//!   key. Can only be imported back into the same account.
 
+use crate::models::state::{KdfConfig, KdfType};
+use crate::models::vault::{Cipher, Folder};
 use crate::services::import_export::errors::ExportError;
+use crate::services::import_export::export::formatters::json::JsonFormatter;
 use crate::services::import_export::export::{ExportData, ExportFormatter, ExportOptions};
 use async_trait::async_trait;
+use base64::{Engine, engine::general_purpose::STANDARD};
+use bitwarden_crypto::{EncString, Kdf, KeyEncryptable, PinKey, SymmetricCryptoKey};
+use rand::{RngCore, rngs::OsRng};
+use secrecy::ExposeSecret;
 use serde::{Deserialize, Serialize};
 
-/// Encrypted JSON export structure
+/// Default PBKDF2 iterations when no KDF settings are supplied
+const DEFAULT_PBKDF2_ITERATIONS: u32 = 600_000;
+
+/// Password-protected JSON export structure
 #[derive(Debug, Serialize, Deserialize)]
-pub struct EncryptedJsonExport {
+#[serde(rename_all = "camelCase")]
+pub struct PasswordProtectedJsonExport {
     pub encrypted: bool,
+    pub password_protected: bool,
+    /// Base64-encoded random salt used for key derivation
+    pub salt: String,
+    pub kdf_type: KdfType,
+    pub kdf_iterations: u32,
+    pub kdf_memory: Option<u32>,
+    pub kdf_parallelism: Option<u32>,
     #[serde(rename = "encKeyValidation_DO_NOT_EDIT")]
     pub enc_key_validation: String,
+    /// Plaintext JSON export encrypted as an EncString
     pub data: String,
 }
 
+/// Account-restricted JSON export structure
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountRestrictedJsonExport {
+    pub encrypted: bool,
+    #[serde(rename = "encKeyValidation_DO_NOT_EDIT")]
+    pub enc_key_validation: String,
+    #[serde(default)]
+    pub folders: Vec<Folder>,
+    pub items: Vec<Cipher>,
+}
+
 /// Encrypted JSON formatter
 pub struct EncryptedJsonFormatter;
 
@@ -24,6 +61,94 @@ impl EncryptedJsonFormatter {
     pub fn new() -> Self {
         Self
     }
+
+    /// Encrypt the plaintext JSON export with a password-derived key
+    async fn format_password_protected(
+        &self,
+        data: &ExportData,
+        options: &ExportOptions,
+        password: &str,
+    ) -> Result<Vec<u8>, ExportError> {
+        // 1. Build the plaintext export (same shape as the json format)
+        let plaintext = JsonFormatter::new().format(data, options).await?;
+        let plaintext = String::from_utf8(plaintext)
+            .map_err(|e| ExportError::EncryptionError(e.to_string()))?;
+
+        // 2. Resolve KDF settings
+        let kdf_config = options.kdf.clone().unwrap_or(KdfConfig {
+            kdf_type: KdfType::PBKDF2SHA256,
+            iterations: Some(DEFAULT_PBKDF2_ITERATIONS),
+            memory: None,
+            parallelism: None,
+        });
+        let kdf =
+            Kdf::try_from(&kdf_config).map_err(|e| ExportError::EncryptionError(e.to_string()))?;
+
+        // 3. Derive the file key from password and a random salt
+        let salt = generate_salt();
+        let key = derive_file_key(password, &salt, &kdf).await?;
+
+        // 4. Encrypt validation string and data
+        let enc_key_validation = encrypt(uuid::Uuid::new_v4().to_string(), &key)?;
+        let encrypted_data = encrypt(plaintext, &key)?;
+
+        let (kdf_iterations, kdf_memory, kdf_parallelism) = match kdf {
+            Kdf::PBKDF2 { iterations } => (iterations.get(), None, None),
+            Kdf::Argon2id {
+                iterations,
+                memory,
+                parallelism,
+            } => (
+                iterations.get(),
+                Some(memory.get()),
+                Some(parallelism.get()),
+            ),
+        };
+
+        let export = PasswordProtectedJsonExport {
+            encrypted: true,
+            password_protected: true,
+            salt,
+            kdf_type: kdf_config.kdf_type,
+            kdf_iterations,
+            kdf_memory,
+            kdf_parallelism,
+            enc_key_validation: enc_key_validation.to_string(),
+            data: encrypted_data.to_string(),
+        };
+
+        Ok(serde_json::to_vec_pretty(&export)?)
+    }
+
+    /// Export the vault's encrypted data, tied to the account's user key
+    fn format_account_restricted(
+        &self,
+        data: &ExportData,
+        options: &ExportOptions,
+        user_key: &SymmetricCryptoKey,
+    ) -> Result<Vec<u8>, ExportError> {
+        // Organization items are encrypted with the organization key, not the user key
+        if options.organization_id.is_some() {
+            return Err(ExportError::EncryptionError(
+                "Account-restricted exports are not supported for organizations. Use --password instead."
+                    .to_string(),
+            ));
+        }
+
+        let enc_key_validation = uuid::Uuid::new_v4()
+            .to_string()
+            .encrypt_with_key(user_key)
+            .map_err(|e| ExportError::EncryptionError(e.to_string()))?;
+
+        let export = AccountRestrictedJsonExport {
+            encrypted: true,
+            enc_key_validation: enc_key_validation.to_string(),
+            folders: data.encrypted_folders.clone(),
+            items: data.encrypted_ciphers.clone(),
+        };
+
+        Ok(serde_json::to_vec_pretty(&export)?)
+    }
 }
 
 #[async_trait]
@@ -38,21 +163,22 @@ impl ExportFormatter for EncryptedJsonFormatter {
 
     async fn format(
         &self,
-        _data: &ExportData,
+        data: &ExportData,
         options: &ExportOptions,
     ) -> Result<Vec<u8>, ExportError> {
-        // Verify password is provided
-        let _password = options
-            .password
+        // A file password takes precedence over the account key
+        if let Some(password) = &options.password {
+            return self
+                .format_password_protected(data, options, password.expose_secret())
+                .await;
+        }
+
+        let user_key = options
+            .user_key
             .as_ref()
             .ok_or(ExportError::PasswordRequired)?;
 
-        // TODO: Implement actual encryption using Bitwarden SDK
-        // For now, return an error indicating this feature requires SDK integration
-        Err(ExportError::DecryptionError(
-            "Encrypted JSON export requires Bitwarden SDK integration (not yet available)"
-                .to_string(),
-        ))
+        self.format_account_restricted(data, options, user_key)
     }
 
     fn requires_password(&self) -> bool {
@@ -63,3 +189,127 @@ impl ExportFormatter for EncryptedJsonFormatter {
         true
     }
 }
+
+/// Generate a random base64-encoded 16-byte salt
+fn generate_salt() -> String {
+    let mut salt = [0u8; 16];
+    OsRng.fill_bytes(&mut salt);
+    STANDARD.encode(salt)
+}
+
+/// Derive the export file key from password and salt
+///
+/// Uses the same derivation as PIN keys (KDF then HKDF stretch), which is
+/// what the Bitwarden clients use for password-protected exports.
+pub(crate) async fn derive_file_key(
+    password: &str,
+    salt: &str,
+    kdf: &Kdf,
+) -> Result<PinKey, ExportError> {
+    let password = password.to_string();
+    let salt = salt.to_string();
+    let kdf = kdf.clone();
+
+    // Run KDF in blocking task (CPU-intensive)
+    tokio::task::spawn_blocking(move || PinKey::derive(password.as_bytes(), salt.as_bytes(), &kdf))
+        .await
+        .map_err(|e| ExportError::EncryptionError(format!("KDF task failed: {}", e)))?
+        .map_err(|e| ExportError::EncryptionError(format!("Key derivation failed: {}", e)))
+}
+
+fn encrypt(plain: String, key: &PinKey) -> Result<EncString, ExportError> {
+    plain
+        .encrypt_with_key(key)
+        .map_err(|e| ExportError::EncryptionError(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bitwarden_crypto::KeyDecryptable;
+    use secrecy::Secret;
+
+    fn empty_data() -> ExportData {
+        ExportData {
+            folders: vec![],
+            ciphers: vec![],
+            collections: vec![],
+            encrypted_folders: vec![],
+            encrypted_ciphers: vec![],
+        }
+    }
+
+    fn fast_kdf() -> KdfConfig {
+        KdfConfig {
+            kdf_type: KdfType::PBKDF2SHA256,
+            iterations: Some(5_000),
+            memory: None,
+            parallelism: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_password_protected_export_roundtrip() {
+        let options = ExportOptions {
+            password: Some(Secret::new("file-password".to_string())),
+            kdf: Some(fast_kdf()),
+            ..Default::default()
+        };
+
+        let bytes = EncryptedJsonFormatter::new()
+            .format(&empty_data(), &options)
+            .await
+            .unwrap();
+        let export: PasswordProtectedJsonExport = serde_json::from_slice(&bytes).unwrap();
+
+        assert!(export.encrypted);
+        assert!(export.password_protected);
+        assert_eq!(export.kdf_type, KdfType::PBKDF2SHA256);
+        assert_eq!(export.kdf_iterations, 5_000);
+
+        // The data decrypts back to the plaintext json export
+        let kdf = Kdf::try_from(&fast_kdf()).unwrap();
+        let key = derive_file_key("file-password", &export.salt, &kdf)
+            .await
+            .unwrap();
+        let enc: EncString = export.data.parse().unwrap();
+        let plaintext: String = enc.decrypt_with_key(&key).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&plaintext).unwrap();
+        assert_eq!(json["encrypted"], false);
+    }
+
+    #[tokio::test]
+    async fn test_account_restricted_export() {
+        let user_key = SymmetricCryptoKey::make_aes256_cbc_hmac_key();
+        let options = ExportOptions {
+            user_key: Some(user_key.clone()),
+            ..Default::default()
+        };
+
+        let bytes = EncryptedJsonFormatter::new()
+            .format(&empty_data(), &options)
+            .await
+            .unwrap();
+        let export: AccountRestrictedJsonExport = serde_json::from_slice(&bytes).unwrap();
+
+        assert!(export.encrypted);
+        let validation: EncString = export.enc_key_validation.parse().unwrap();
+        let decrypted: String = validation.decrypt_with_key(&user_key).unwrap();
+        assert!(uuid::Uuid::parse_str(&decrypted).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_account_restricted_rejects_organization() {
+        let options = ExportOptions {
+            organization_id: Some("org-id".to_string()),
+            user_key: Some(SymmetricCryptoKey::make_aes256_cbc_hmac_key()),
+            ..Default::default()
+        };
+
+        let result = EncryptedJsonFormatter::new()
+            .format(&empty_data(), &options)
+            .await;
+
+        assert!(matches!(result, Err(ExportError::EncryptionError(_))));
+    }
+}
@@ -1,6 +1,6 @@
 //! JSON export formatter
 
-use crate::models::vault::{CipherView, FolderView};
+use crate::models::vault::{CipherView, CollectionView, FolderView};
 use crate::services::import_export::errors::ExportError;
 use crate::services::import_export::export::{ExportData, ExportFormatter, ExportOptions};
 use async_trait::async_trait;
@@ -14,10 +14,20 @@ pub struct JsonExport<'a> {
     pub items: &'a [CipherView],
 }
 
+/// Organization JSON export structure (collections instead of folders)
+#[derive(Debug, Serialize)]
+pub struct JsonOrgExport<'a> {
+    pub encrypted: bool,
+    pub collections: &'a [CollectionView],
+    pub items: &'a [CipherView],
+}
+
 /// Owned JSON export structure (for deserialization during import)
 #[derive(Debug, Serialize, Deserialize)]
 pub struct JsonExportOwned {
     pub encrypted: bool,
+    /// Absent from organization exports
+    #[serde(default)]
     pub folders: Vec<FolderView>,
     pub items: Vec<CipherView>,
 }
@@ -44,16 +54,22 @@ impl ExportFormatter for JsonFormatter {
     async fn format(
         &self,
         data: &ExportData,
-        _options: &ExportOptions,
+        options: &ExportOptions,
     ) -> Result<Vec<u8>, ExportError> {
-        let export = JsonExport {
-            encrypted: false,
-            folders: &data.folders,
-            items: &data.ciphers,
-        };
-
         // Pretty-print JSON with 2-space indentation
-        let json = serde_json::to_vec_pretty(&export)?;
+        let json = if options.organization_id.is_some() {
+            serde_json::to_vec_pretty(&JsonOrgExport {
+                encrypted: false,
+                collections: &data.collections,
+                items: &data.ciphers,
+            })?
+        } else {
+            serde_json::to_vec_pretty(&JsonExport {
+                encrypted: false,
+                folders: &data.folders,
+                items: &data.ciphers,
+            })?
+        };
         Ok(json)
     }
 
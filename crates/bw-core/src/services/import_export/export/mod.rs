@@ -2,25 +2,39 @@
 
 pub mod formatters;
 
-use crate::models::vault::{CipherView, FolderView};
+use crate::models::state::KdfConfig;
+use crate::models::vault::{Cipher, CipherView, CollectionView, Folder, FolderView};
 use crate::services::import_export::errors::ExportError;
 use async_trait::async_trait;
+use bitwarden_crypto::SymmetricCryptoKey;
 use secrecy::Secret;
 use std::collections::HashMap;
 use std::sync::Arc;
 
 /// Export data structure (decrypted vault items)
+///
+/// Personal exports carry folders; organization exports carry collections.
+/// Account-restricted exports use the still-encrypted cipher and folder
+/// data as cached from the last sync.
 #[derive(Debug)]
 pub struct ExportData {
     pub folders: Vec<FolderView>,
     pub ciphers: Vec<CipherView>,
+    pub collections: Vec<CollectionView>,
+    pub encrypted_folders: Vec<Folder>,
+    pub encrypted_ciphers: Vec<Cipher>,
 }
 
 /// Export options
 #[derive(Debug, Clone)]
 pub struct ExportOptions {
+    /// File password for password-protected exports
     pub password: Option<Secret<String>>,
     pub organization_id: Option<String>,
+    /// KDF settings used to derive the file key (default: PBKDF2, 600,000 iterations)
+    pub kdf: Option<KdfConfig>,
+    /// User key for account-restricted exports
+    pub user_key: Option<SymmetricCryptoKey>,
 }
 
 impl Default for ExportOptions {
@@ -28,10 +42,20 @@ impl Default for ExportOptions {
         Self {
             password: None,
             organization_id: None,
+            kdf: None,
+            user_key: None,
         }
     }
 }
 
+impl ExportOptions {
+    /// Whether an encrypted export should use the account's user key
+    /// instead of a file password
+    pub fn is_account_restricted(&self) -> bool {
+        self.password.is_none() && self.user_key.is_some()
+    }
+}
+
 /// Export result
 #[derive(Debug)]
 pub struct ExportResult {
@@ -105,16 +129,26 @@ impl ExportService {
             .get(format)
             .ok_or_else(|| ExportError::UnsupportedFormat(format.to_string()))?;
 
-        // Validate password requirement
-        if formatter.requires_password() && options.password.is_none() {
+        // Validate password requirement (account-restricted exports use the user key)
+        if formatter.requires_password() && options.password.is_none() && options.user_key.is_none()
+        {
             return Err(ExportError::PasswordRequired);
         }
 
         // Format data
         let formatted = formatter.format(&data, &options).await?;
 
+        // Resolve directories to a timestamped file name
+        let output_path = output_path.map(|path| {
+            resolve_output_path(
+                path,
+                formatter.file_extension(),
+                options.organization_id.is_some(),
+            )
+        });
+
         // Write to file or return for stdout
-        if let Some(path) = output_path {
+        if let Some(path) = &output_path {
             std::fs::write(path, &formatted)
                 .map_err(|e| ExportError::FileWriteError(format!("{}: {}", path, e)))?;
         } else {
@@ -125,10 +159,16 @@ impl ExportService {
                 .map_err(ExportError::IoError)?;
         }
 
+        let item_count = if formatter.is_encrypted() && options.is_account_restricted() {
+            data.encrypted_ciphers.len()
+        } else {
+            data.ciphers.len()
+        };
+
         Ok(ExportResult {
-            item_count: data.ciphers.len(),
+            item_count,
             format: format.to_string(),
-            output_path: output_path.map(String::from),
+            output_path,
             encrypted: formatter.is_encrypted(),
         })
     }
@@ -141,8 +181,73 @@ impl ExportService {
     }
 }
 
+/// Resolve an `--output` value to a file path
+///
+/// Existing directories (or paths ending in a separator) get a default file
+/// name matching the TypeScript CLI: `bitwarden_export_YYYYMMDDHHMMSS.ext`,
+/// or `bitwarden_org_export_...` for organization exports.
+pub fn resolve_output_path(path: &str, extension: &str, organization: bool) -> String {
+    let is_dir = path.ends_with('/')
+        || path.ends_with(std::path::MAIN_SEPARATOR)
+        || std::path::Path::new(path).is_dir();
+
+    if !is_dir {
+        return path.to_string();
+    }
+
+    let prefix = if organization {
+        "bitwarden_org_export"
+    } else {
+        "bitwarden_export"
+    };
+    let file_name = format!(
+        "{}_{}.{}",
+        prefix,
+        chrono::Local::now().format("%Y%m%d%H%M%S"),
+        extension
+    );
+
+    std::path::Path::new(path)
+        .join(file_name)
+        .to_string_lossy()
+        .into_owned()
+}
+
 impl Default for ExportService {
     fn default() -> Self {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_output_path_keeps_file_names() {
+        assert_eq!(
+            resolve_output_path("vault.json", "json", false),
+            "vault.json"
+        );
+    }
+
+    #[test]
+    fn test_resolve_output_path_expands_directories() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let resolved = resolve_output_path(dir.path().to_str().unwrap(), "csv", false);
+
+        let file_name = std::path::Path::new(&resolved)
+            .file_name()
+            .unwrap()
+            .to_string_lossy()
+            .into_owned();
+        assert!(file_name.starts_with("bitwarden_export_"));
+        assert!(file_name.ends_with(".csv"));
+    }
+
+    #[test]
+    fn test_resolve_output_path_org_prefix() {
+        let resolved = resolve_output_path("exports/", "json", true);
+        assert!(resolved.contains("bitwarden_org_export_"));
+    }
+}
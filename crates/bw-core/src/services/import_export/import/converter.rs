@@ -0,0 +1,252 @@
+//! Conversion from parsed import data to SDK vault views
+//!
+//! Builds the same camelCase JSON shape the CLI accepts for `bw create item`
+//! and deserializes it into `CipherView`, so imported items go through the
+//! exact path user-created items do.
+
+use crate::models::vault::{CipherView, FolderView};
+use crate::services::import_export::errors::ImportError;
+use crate::services::import_export::import::{ImportData, ImportItem, ImportItemType};
+use chrono::Utc;
+use serde_json::{Value, json};
+use std::collections::HashMap;
+
+/// Import data converted to views, with item-to-folder links by index
+#[derive(Debug)]
+pub struct ConvertedImport {
+    pub folders: Vec<FolderView>,
+    pub ciphers: Vec<CipherView>,
+    /// (cipher index, folder index) pairs
+    pub folder_relationships: Vec<(usize, usize)>,
+}
+
+/// Convert parsed import data into cipher and folder views
+///
+/// Folders referenced by items but missing from the folder list are added,
+/// so every relationship points at a folder in the result.
+pub fn convert(
+    data: &ImportData,
+    organization_id: Option<&str>,
+) -> Result<ConvertedImport, ImportError> {
+    let now = Utc::now();
+
+    let mut folders: Vec<FolderView> = Vec::new();
+    let mut folder_index: HashMap<String, usize> = HashMap::new();
+    let mut add_folder = |name: &str, folders: &mut Vec<FolderView>| -> usize {
+        *folder_index.entry(name.to_string()).or_insert_with(|| {
+            folders.push(FolderView {
+                id: None,
+                name: name.to_string(),
+                revision_date: now,
+            });
+            folders.len() - 1
+        })
+    };
+
+    for folder in &data.folders {
+        add_folder(&folder.name, &mut folders);
+    }
+
+    let mut ciphers = Vec::with_capacity(data.items.len());
+    let mut folder_relationships = Vec::new();
+
+    for (index, item) in data.items.iter().enumerate() {
+        if let Some(folder_name) = item.folder_name.as_deref().filter(|n| !n.is_empty()) {
+            let folder = add_folder(folder_name, &mut folders);
+            folder_relationships.push((index, folder));
+        }
+
+        ciphers.push(to_cipher_view(item, organization_id)?);
+    }
+
+    Ok(ConvertedImport {
+        folders,
+        ciphers,
+        folder_relationships,
+    })
+}
+
+/// Convert a single import item into a `CipherView`
+pub fn to_cipher_view(
+    item: &ImportItem,
+    organization_id: Option<&str>,
+) -> Result<CipherView, ImportError> {
+    let now = Utc::now().to_rfc3339();
+
+    let cipher_type = match item.item_type {
+        ImportItemType::Login => 1,
+        ImportItemType::SecureNote => 2,
+        ImportItemType::Card => 3,
+        ImportItemType::Identity => 4,
+    };
+
+    let fields: Vec<Value> = item
+        .fields
+        .iter()
+        .map(|f| {
+            json!({
+                "name": f.name,
+                "value": f.value,
+                "type": f.field_type,
+                "linkedId": null,
+            })
+        })
+        .collect();
+
+    let login = match (item.item_type, &item.login) {
+        (ImportItemType::Login, Some(login)) => json!({
+            "username": login.username,
+            "password": login.password,
+            "passwordRevisionDate": null,
+            "uris": login
+                .uris
+                .iter()
+                .map(|uri| json!({ "uri": uri, "match": null, "uriChecksum": null }))
+                .collect::<Vec<_>>(),
+            "totp": login.totp,
+            "autofillOnPageLoad": null,
+            "fido2Credentials": null,
+        }),
+        // Logins without credentials are still valid items (e.g. URL bookmarks)
+        (ImportItemType::Login, None) => json!({
+            "username": null,
+            "password": null,
+            "passwordRevisionDate": null,
+            "uris": [],
+            "totp": null,
+            "autofillOnPageLoad": null,
+            "fido2Credentials": null,
+        }),
+        _ => Value::Null,
+    };
+
+    let secure_note = match item.item_type {
+        ImportItemType::SecureNote => json!({ "type": 0 }),
+        _ => Value::Null,
+    };
+
+    let card = match (item.item_type, &item.card) {
+        (ImportItemType::Card, Some(card)) => json!({
+            "cardholderName": card.cardholder_name,
+            "brand": card.brand,
+            "number": card.number,
+            "expMonth": card.exp_month,
+            "expYear": card.exp_year,
+            "code": card.code,
+        }),
+        _ => Value::Null,
+    };
+
+    let identity = match (item.item_type, &item.identity) {
+        (ImportItemType::Identity, Some(identity)) => json!({
+            "title": identity.title,
+            "firstName": identity.first_name,
+            "middleName": identity.middle_name,
+            "lastName": identity.last_name,
+            "address1": identity.address1,
+            "address2": identity.address2,
+            "address3": identity.address3,
+            "city": identity.city,
+            "state": identity.state,
+            "postalCode": identity.postal_code,
+            "country": identity.country,
+            "company": null,
+            "email": identity.email,
+            "phone": identity.phone,
+            "ssn": identity.ssn,
+            "username": identity.username,
+            "passportNumber": identity.passport_number,
+            "licenseNumber": identity.license_number,
+        }),
+        _ => Value::Null,
+    };
+
+    let value = json!({
+        "id": null,
+        "organizationId": organization_id,
+        "folderId": null,
+        "collectionIds": [],
+        "key": null,
+        "name": item.name,
+        "notes": item.notes,
+        "type": cipher_type,
+        "login": login,
+        "identity": identity,
+        "card": card,
+        "secureNote": secure_note,
+        "sshKey": null,
+        "favorite": item.favorite,
+        "reprompt": 0,
+        "organizationUseTotp": false,
+        "edit": true,
+        "permissions": null,
+        "viewPassword": true,
+        "localData": null,
+        "attachments": null,
+        "fields": fields,
+        "passwordHistory": null,
+        "creationDate": now,
+        "deletedDate": null,
+        "revisionDate": now,
+        "archivedDate": null,
+    });
+
+    serde_json::from_value(value)
+        .map_err(|e| ImportError::ParseError(format!("{}: {}", item.name, e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::import_export::import::{ImportFolder, ImportLogin};
+
+    fn login_item(name: &str, folder: Option<&str>) -> ImportItem {
+        ImportItem {
+            item_type: ImportItemType::Login,
+            folder_name: folder.map(String::from),
+            favorite: false,
+            name: name.to_string(),
+            notes: None,
+            fields: vec![],
+            login: Some(ImportLogin {
+                username: Some("user".to_string()),
+                password: Some("pass".to_string()),
+                uris: vec!["https://example.com".to_string()],
+                totp: None,
+            }),
+            card: None,
+            identity: None,
+        }
+    }
+
+    #[test]
+    fn test_convert_preserves_folder_relationships() {
+        let data = ImportData {
+            folders: vec![ImportFolder {
+                name: "Work".to_string(),
+            }],
+            items: vec![
+                login_item("GitHub", Some("Work")),
+                login_item("Email", None),
+                login_item("Bank", Some("Personal")),
+            ],
+        };
+
+        let converted = convert(&data, None).unwrap();
+
+        assert_eq!(converted.ciphers.len(), 3);
+        assert_eq!(converted.folders.len(), 2);
+        assert_eq!(converted.folders[1].name, "Personal");
+        assert_eq!(converted.folder_relationships, vec![(0, 0), (2, 1)]);
+    }
+
+    #[test]
+    fn test_to_cipher_view_maps_login_fields() {
+        let view = to_cipher_view(&login_item("GitHub", None), None).unwrap();
+
+        assert_eq!(view.name, "GitHub");
+        let login = view.login.expect("login data");
+        assert_eq!(login.username.as_deref(), Some("user"));
+        assert_eq!(login.password.as_deref(), Some("pass"));
+    }
+}
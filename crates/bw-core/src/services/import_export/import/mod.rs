@@ -1,13 +1,25 @@
 //! Import service and parsers
 
+pub mod converter;
 pub mod parsers;
 pub mod validator;
 
+use crate::models::api::{
+    ImportCiphersRequest, ImportCollectionRequest, ImportFolderRequest,
+    ImportOrganizationCiphersRequest, ImportRelationship,
+};
+use crate::models::vault::{CipherRequestModel, FolderView};
+use crate::services::api::{ApiClient, BitwardenApiClient, endpoints};
+use crate::services::crypto;
 use crate::services::import_export::errors::ImportError;
+use crate::services::storage::JsonFileStorage;
+use crate::services::vault::{CipherService, SyncService};
 use async_trait::async_trait;
+use bitwarden_crypto::SymmetricCryptoKey;
 use secrecy::Secret;
 use std::collections::HashMap;
 use std::sync::Arc;
+use tokio::sync::Mutex;
 
 /// Import item type
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -98,8 +110,13 @@ pub struct ImportData {
 /// Import options
 #[derive(Debug, Clone)]
 pub struct ImportOptions {
+    /// File password for password-protected exports
     pub password: Option<Secret<String>>,
     pub organization_id: Option<String>,
+    /// User key for verifying account-restricted exports
+    pub user_key: Option<SymmetricCryptoKey>,
+    /// Organization key for encrypting collection names
+    pub organization_key: Option<SymmetricCryptoKey>,
 }
 
 impl Default for ImportOptions {
@@ -107,6 +124,8 @@ impl Default for ImportOptions {
         Self {
             password: None,
             organization_id: None,
+            user_key: None,
+            organization_key: None,
         }
     }
 }
@@ -115,6 +134,7 @@ impl Default for ImportOptions {
 #[derive(Debug)]
 pub struct ImportResult {
     pub items_created: usize,
+    /// Folders created, or collections for an organization import
     pub folders_created: usize,
     pub format: String,
 }
@@ -143,13 +163,55 @@ pub trait ImportParser: Send + Sync {
     fn requires_password(&self) -> bool;
 }
 
+/// Services needed to write imported items into the vault
+struct VaultTarget {
+    api_client: Arc<BitwardenApiClient>,
+    cipher_service: Arc<CipherService>,
+    sync_service: SyncService,
+}
+
 /// Service for importing data into vault
 pub struct ImportService {
     parsers: HashMap<String, Arc<dyn ImportParser>>,
+    vault: Option<VaultTarget>,
 }
 
 impl ImportService {
+    /// Create an import service that writes into the vault
+    ///
+    /// Items are encrypted with `cipher_service` and sent to the server's bulk
+    /// import endpoint; the local cache is refreshed with a sync afterwards.
+    pub fn with_vault(
+        api_client: Arc<BitwardenApiClient>,
+        storage: Arc<Mutex<JsonFileStorage>>,
+        cipher_service: Arc<CipherService>,
+    ) -> Self {
+        let sync_service = SyncService::new(Arc::clone(&api_client), storage);
+
+        let mut service = Self::new();
+
+        // Account-restricted exports are decrypted with the account's keys
+        service.parsers.insert(
+            "bitwardenjson".to_string(),
+            Arc::new(
+                parsers::bitwarden_json::BitwardenJsonParser::with_cipher_service(Arc::clone(
+                    &cipher_service,
+                )),
+            ),
+        );
+
+        service.vault = Some(VaultTarget {
+            api_client,
+            cipher_service,
+            sync_service,
+        });
+        service
+    }
+
     /// Create a new import service with all parsers
+    ///
+    /// Without a vault target, `import` only parses and validates the file.
+    /// Use [`ImportService::with_vault`] to actually create the items.
     pub fn new() -> Self {
         let mut parsers: HashMap<String, Arc<dyn ImportParser>> = HashMap::new();
 
@@ -175,7 +237,10 @@ impl ImportService {
             Arc::new(parsers::chrome::ChromeParser::new()),
         );
 
-        Self { parsers }
+        Self {
+            parsers,
+            vault: None,
+        }
     }
 
     /// Import data from file
@@ -211,11 +276,100 @@ impl ImportService {
         // Validate
         validator::validate(&import_data)?;
 
-        // TODO: Transform to CipherView and create in vault
-        // For now, just return a placeholder result
+        // Parse-only mode: report what would be imported
+        let Some(vault) = &self.vault else {
+            return Ok(ImportResult {
+                items_created: import_data.items.len(),
+                folders_created: import_data.folders.len(),
+                format: format.to_string(),
+            });
+        };
+
+        // Convert to SDK views, keeping item-to-folder links
+        let converted = converter::convert(&import_data, options.organization_id.as_deref())?;
+
+        // Encrypt ciphers
+        let ciphers = converted
+            .ciphers
+            .into_iter()
+            .map(|view| {
+                vault
+                    .cipher_service
+                    .encrypt_cipher(view)
+                    .map(CipherRequestModel::from)
+                    .map_err(|e| ImportError::ImportFailed(e.to_string()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let items_created = ciphers.len();
+
+        let folders_created = match options.organization_id.as_deref() {
+            // Organization vaults have no folders; they become collections
+            Some(org_id) => {
+                let org_key = options.organization_key.as_ref().ok_or_else(|| {
+                    ImportError::ImportFailed("Organization key not available".to_string())
+                })?;
+                let request = organization_request(
+                    ciphers,
+                    &converted.folders,
+                    converted.folder_relationships,
+                    org_key,
+                )?;
+                let collections_created = request.collections.len();
+                vault
+                    .api_client
+                    .post_with_auth_no_response(
+                        &endpoints::api::ciphers::import_organization(org_id),
+                        &request,
+                    )
+                    .await
+                    .map_err(|e| ImportError::ImportFailed(e.to_string()))?;
+                collections_created
+            }
+            None => {
+                let folders = converted
+                    .folders
+                    .into_iter()
+                    .map(|view| {
+                        vault
+                            .cipher_service
+                            .encrypt_folder(view)
+                            .map(|folder| ImportFolderRequest {
+                                name: folder.name.to_string(),
+                                id: None,
+                            })
+                            .map_err(|e| ImportError::ImportFailed(e.to_string()))
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                let folders_created = folders.len();
+
+                let request = ImportCiphersRequest {
+                    ciphers,
+                    folders,
+                    folder_relationships: converted
+                        .folder_relationships
+                        .into_iter()
+                        .map(|(key, value)| ImportRelationship { key, value })
+                        .collect(),
+                };
+                vault
+                    .api_client
+                    .post_with_auth_no_response(endpoints::api::ciphers::IMPORT, &request)
+                    .await
+                    .map_err(|e| ImportError::ImportFailed(e.to_string()))?;
+                folders_created
+            }
+        };
+
+        // The import endpoint returns no data, so refresh the cache from the server
+        vault
+            .sync_service
+            .sync(true)
+            .await
+            .map_err(|e| ImportError::ImportFailed(format!("Imported, but sync failed: {}", e)))?;
+
         Ok(ImportResult {
-            items_created: import_data.items.len(),
-            folders_created: import_data.folders.len(),
+            items_created,
+            folders_created,
             format: format.to_string(),
         })
     }
@@ -252,8 +406,81 @@ impl ImportService {
     }
 }
 
+/// Build an organization import request, creating a collection per folder
+///
+/// Collection names are encrypted with the organization key, and each
+/// item-to-folder link becomes an item-to-collection link.
+fn organization_request(
+    ciphers: Vec<CipherRequestModel>,
+    folders: &[FolderView],
+    folder_relationships: Vec<(usize, usize)>,
+    org_key: &SymmetricCryptoKey,
+) -> Result<ImportOrganizationCiphersRequest, ImportError> {
+    let collections = folders
+        .iter()
+        .map(|folder| {
+            crypto::encrypt_string(&folder.name, org_key)
+                .map(|name| ImportCollectionRequest { name, id: None })
+                .map_err(|e| ImportError::EncryptionError(e.to_string()))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(ImportOrganizationCiphersRequest {
+        ciphers,
+        collections,
+        collection_relationships: folder_relationships
+            .into_iter()
+            .map(|(key, value)| ImportRelationship { key, value })
+            .collect(),
+    })
+}
+
 impl Default for ImportService {
     fn default() -> Self {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use serde_json::json;
+
+    fn folder(name: &str) -> FolderView {
+        FolderView {
+            id: None,
+            name: name.to_string(),
+            revision_date: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_organization_request_maps_folders_to_collections() {
+        let key = SymmetricCryptoKey::make_aes256_cbc_hmac_key();
+        let folders = vec![folder("Work"), folder("Personal")];
+
+        let request =
+            organization_request(vec![], &folders, vec![(0, 0), (1, 0), (2, 1)], &key).unwrap();
+        let body = serde_json::to_value(&request).unwrap();
+
+        // Collection names are encrypted with the organization key
+        let collections = body["collections"].as_array().unwrap();
+        assert_eq!(collections.len(), 2);
+        for (collection, expected) in collections.iter().zip(["Work", "Personal"]) {
+            let name = collection["name"].as_str().unwrap();
+            assert_eq!(crypto::decrypt_string(name, &key, None).unwrap(), expected);
+            assert!(collection.get("id").is_none());
+        }
+
+        assert_eq!(
+            body["collectionRelationships"],
+            json!([
+                { "key": 0, "value": 0 },
+                { "key": 1, "value": 0 },
+                { "key": 2, "value": 1 },
+            ])
+        );
+        assert_eq!(body["ciphers"], json!([]));
+    }
+}
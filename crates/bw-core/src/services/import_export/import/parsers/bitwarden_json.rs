@@ -1,17 +1,43 @@
 //! Bitwarden JSON import parser
+//!
+//! Handles plaintext exports as well as both encrypted variants:
+//! password-protected exports (decrypted with a key derived from the file
+//! password) and account-restricted exports (decrypted with the unlocked
+//! account's keys).
 
-use crate::models::vault::{CipherType, FolderId};
+use crate::models::state::KdfType;
+use crate::models::vault::{CipherType, CipherView, FolderId, FolderView};
 use crate::services::import_export::errors::ImportError;
+use crate::services::import_export::export::formatters::encrypted_json::{
+    AccountRestrictedJsonExport, PasswordProtectedJsonExport, derive_file_key,
+};
 use crate::services::import_export::export::formatters::json::JsonExportOwned;
 use crate::services::import_export::import::*;
+use crate::services::vault::CipherService;
 use async_trait::async_trait;
+use bitwarden_crypto::{EncString, Kdf, KeyDecryptable};
+use secrecy::ExposeSecret;
+use std::num::NonZeroU32;
+use std::sync::Arc;
 
 /// Bitwarden JSON parser
-pub struct BitwardenJsonParser;
+pub struct BitwardenJsonParser {
+    /// Decrypts account-restricted exports; unavailable in parse-only mode
+    cipher_service: Option<Arc<CipherService>>,
+}
 
 impl BitwardenJsonParser {
     pub fn new() -> Self {
-        Self
+        Self {
+            cipher_service: None,
+        }
+    }
+
+    /// Create a parser that can decrypt account-restricted exports
+    pub fn with_cipher_service(cipher_service: Arc<CipherService>) -> Self {
+        Self {
+            cipher_service: Some(cipher_service),
+        }
     }
 
     fn cipher_type_to_import_type(cipher_type: CipherType) -> ImportItemType {
@@ -23,31 +49,112 @@ impl BitwardenJsonParser {
             CipherType::SshKey => ImportItemType::SecureNote, // Map SSH keys to SecureNote for now
         }
     }
-}
 
-#[async_trait]
-impl ImportParser for BitwardenJsonParser {
-    fn format_name(&self) -> &str {
-        "bitwardenjson"
+    /// Parse a plaintext (unencrypted) export
+    fn parse_plaintext(&self, data: &[u8]) -> Result<ImportData, ImportError> {
+        let export: JsonExportOwned = serde_json::from_slice(data)?;
+        Ok(Self::views_to_import_data(&export.folders, &export.items))
     }
 
-    async fn parse(
+    /// Decrypt a password-protected export and parse the plaintext inside
+    async fn parse_password_protected(
         &self,
-        data: &[u8],
-        _options: &ImportOptions,
+        export: PasswordProtectedJsonExport,
+        options: &ImportOptions,
     ) -> Result<ImportData, ImportError> {
-        let export: JsonExportOwned = serde_json::from_slice(data)?;
+        // 1. Derive the file key with the export's KDF settings
+        let password = options
+            .password
+            .as_ref()
+            .ok_or(ImportError::PasswordRequired)?;
+
+        let kdf = Self::export_kdf(&export)?;
+        let key = derive_file_key(password.expose_secret(), &export.salt, &kdf)
+            .await
+            .map_err(|e| ImportError::ParseError(e.to_string()))?;
 
-        // Check it's not encrypted
-        if export.encrypted {
-            return Err(ImportError::ParseError(
-                "This is an encrypted export. Use the encrypted_json format instead".to_string(),
-            ));
+        // 2. Verify the password against the validation string
+        let validation: EncString = export
+            .enc_key_validation
+            .parse()
+            .map_err(|_| ImportError::ParseError("Invalid encKeyValidation_DO_NOT_EDIT".into()))?;
+        let _: String = validation
+            .decrypt_with_key(&key)
+            .map_err(|_| ImportError::InvalidPassword)?;
+
+        // 3. Decrypt the payload and parse it as a plaintext export
+        let data: EncString = export
+            .data
+            .parse()
+            .map_err(|_| ImportError::ParseError("Invalid encrypted data".into()))?;
+        let plaintext: String = data
+            .decrypt_with_key(&key)
+            .map_err(|_| ImportError::InvalidPassword)?;
+
+        self.parse_plaintext(plaintext.as_bytes())
+    }
+
+    /// Decrypt an account-restricted export with the unlocked account's keys
+    fn parse_account_restricted(
+        &self,
+        export: AccountRestrictedJsonExport,
+        options: &ImportOptions,
+    ) -> Result<ImportData, ImportError> {
+        let (Some(user_key), Some(cipher_service)) = (&options.user_key, &self.cipher_service)
+        else {
+            return Err(ImportError::NotAuthenticated);
+        };
+
+        // 1. Verify the export belongs to this account
+        let validation: EncString = export
+            .enc_key_validation
+            .parse()
+            .map_err(|_| ImportError::ParseError("Invalid encKeyValidation_DO_NOT_EDIT".into()))?;
+        let _: String = validation
+            .decrypt_with_key(user_key)
+            .map_err(|_| ImportError::AccountMismatch)?;
+
+        // 2. Decrypt folders and items
+        let folders = cipher_service
+            .decrypt_folders(export.folders)
+            .map_err(|e| ImportError::ParseError(e.to_string()))?;
+
+        let items = export
+            .items
+            .into_iter()
+            .map(|cipher| {
+                cipher_service
+                    .decrypt_cipher(cipher)
+                    .map_err(|e| ImportError::ParseError(e.to_string()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self::views_to_import_data(&folders, &items))
+    }
+
+    /// Build SDK KDF settings from an export's header
+    fn export_kdf(export: &PasswordProtectedJsonExport) -> Result<Kdf, ImportError> {
+        let non_zero = |value: u32, name: &str| {
+            NonZeroU32::new(value)
+                .ok_or_else(|| ImportError::ParseError(format!("Invalid KDF {}", name)))
+        };
+
+        match export.kdf_type {
+            KdfType::PBKDF2SHA256 => Ok(Kdf::PBKDF2 {
+                iterations: non_zero(export.kdf_iterations, "iterations")?,
+            }),
+            KdfType::Argon2id => Ok(Kdf::Argon2id {
+                iterations: non_zero(export.kdf_iterations, "iterations")?,
+                memory: non_zero(export.kdf_memory.unwrap_or_default(), "memory")?,
+                parallelism: non_zero(export.kdf_parallelism.unwrap_or_default(), "parallelism")?,
+            }),
         }
+    }
 
+    /// Convert decrypted folders and ciphers into import data
+    fn views_to_import_data(folders: &[FolderView], items: &[CipherView]) -> ImportData {
         // Convert folders
-        let folders = export
-            .folders
+        let import_folders = folders
             .iter()
             .map(|f| ImportFolder {
                 name: f.name.clone(),
@@ -55,15 +162,13 @@ impl ImportParser for BitwardenJsonParser {
             .collect();
 
         // Build folder ID to name map - FolderView.id is Option<FolderId>
-        let folder_map: std::collections::HashMap<FolderId, String> = export
-            .folders
+        let folder_map: std::collections::HashMap<FolderId, String> = folders
             .iter()
             .filter_map(|f| f.id.clone().map(|id| (id, f.name.clone())))
             .collect();
 
         // Convert items
-        let items = export
-            .items
+        let items = items
             .iter()
             .map(|cipher| {
                 // cipher.folder_id is Option<FolderId>
@@ -145,21 +250,52 @@ impl ImportParser for BitwardenJsonParser {
             })
             .collect();
 
-        Ok(ImportData { folders, items })
+        ImportData {
+            folders: import_folders,
+            items,
+        }
+    }
+}
+
+#[async_trait]
+impl ImportParser for BitwardenJsonParser {
+    fn format_name(&self) -> &str {
+        "bitwardenjson"
+    }
+
+    async fn parse(&self, data: &[u8], options: &ImportOptions) -> Result<ImportData, ImportError> {
+        let value: serde_json::Value = serde_json::from_slice(data)?;
+
+        if value.get("encrypted") != Some(&serde_json::Value::Bool(true)) {
+            return self.parse_plaintext(data);
+        }
+
+        if value.get("passwordProtected") == Some(&serde_json::Value::Bool(true)) {
+            let export: PasswordProtectedJsonExport = serde_json::from_value(value)?;
+            self.parse_password_protected(export, options).await
+        } else {
+            let export: AccountRestrictedJsonExport = serde_json::from_value(value)?;
+            self.parse_account_restricted(export, options)
+        }
     }
 
     fn can_parse(&self, data: &[u8]) -> bool {
         if let Ok(json) = serde_json::from_slice::<serde_json::Value>(data) {
-            // Check for Bitwarden JSON structure
-            json.get("items").is_some()
-                && json.get("folders").is_some()
-                && json.get("encrypted") == Some(&serde_json::Value::Bool(false))
+            // Check for Bitwarden JSON structure (plaintext or encrypted)
+            match json.get("encrypted") {
+                Some(serde_json::Value::Bool(false)) => json.get("items").is_some(),
+                Some(serde_json::Value::Bool(true)) => {
+                    json.get("encKeyValidation_DO_NOT_EDIT").is_some()
+                }
+                _ => false,
+            }
         } else {
             false
         }
     }
 
     fn requires_password(&self) -> bool {
-        false
+        // Password-protected exports need the file password
+        true
     }
 }
@@ -3,11 +3,13 @@
 //! This module provides functions for retrieving and managing the user key
 //! for vault decryption operations.
 
+use crate::services::crypto;
 use crate::services::storage::{
-    AccountManager, JsonFileStorage, Storage, make_protected_key, parse_session_key,
+    AccountManager, JsonFileStorage, Storage, StorageKey, make_protected_key, parse_session_key,
     user_key_protected_storage_key,
 };
-use bitwarden_crypto::SymmetricCryptoKey;
+use bitwarden_crypto::{AsymmetricCryptoKey, SymmetricCryptoKey};
+use std::collections::HashMap;
 use std::sync::Arc;
 use thiserror::Error;
 use tokio::sync::Mutex;
@@ -27,6 +29,12 @@ pub enum KeyServiceError {
     #[error("Failed to decrypt user key: {0}")]
     DecryptionFailed(String),
 
+    #[error("Private key not found. Run 'bw sync' first.")]
+    PrivateKeyNotFound,
+
+    #[error("Organization key not found for {0}. Run 'bw sync' first.")]
+    OrganizationKeyNotFound(String),
+
     #[error("Storage error: {0}")]
     StorageError(String),
 }
@@ -95,6 +103,80 @@ impl KeyService {
             .map_err(|e| KeyServiceError::DecryptionFailed(e.to_string()))
     }
 
+    /// Get the user's RSA private key
+    ///
+    /// The encrypted private key is stored during sync and decrypted with
+    /// the user key.
+    ///
+    /// # Arguments
+    /// * `session_str` - Base64-encoded session key (from BW_SESSION or --session)
+    pub async fn get_private_key(
+        &self,
+        session_str: &str,
+    ) -> Result<AsymmetricCryptoKey, KeyServiceError> {
+        let user_key = self.get_user_key(session_str).await?;
+        self.decrypt_private_key(&user_key).await
+    }
+
+    /// Get an organization's symmetric key
+    ///
+    /// Organization keys are stored during sync, encrypted with the user's
+    /// public key.
+    ///
+    /// # Arguments
+    /// * `session_str` - Base64-encoded session key (from BW_SESSION or --session)
+    /// * `organization_id` - The organization's ID
+    pub async fn get_organization_key(
+        &self,
+        session_str: &str,
+        organization_id: &str,
+    ) -> Result<SymmetricCryptoKey, KeyServiceError> {
+        let user_key = self.get_user_key(session_str).await?;
+        let user_id = self
+            .account_manager
+            .get_active_user_id()
+            .await?
+            .ok_or(KeyServiceError::NoActiveUser)?;
+
+        let org_keys: HashMap<String, String> = self
+            .storage
+            .lock()
+            .await
+            .get(&StorageKey::UserOrganizationKeys.format(Some(&user_id)))
+            .map_err(|e| KeyServiceError::StorageError(e.to_string()))?
+            .unwrap_or_default();
+
+        let encrypted_org_key = org_keys
+            .get(organization_id)
+            .ok_or_else(|| KeyServiceError::OrganizationKeyNotFound(organization_id.to_string()))?;
+
+        let private_key = self.decrypt_private_key(&user_key).await?;
+        crypto::decrypt_shared_key(&private_key, encrypted_org_key)
+            .map_err(|e| KeyServiceError::DecryptionFailed(e.to_string()))
+    }
+
+    async fn decrypt_private_key(
+        &self,
+        user_key: &SymmetricCryptoKey,
+    ) -> Result<AsymmetricCryptoKey, KeyServiceError> {
+        let user_id = self
+            .account_manager
+            .get_active_user_id()
+            .await?
+            .ok_or(KeyServiceError::NoActiveUser)?;
+
+        let encrypted_private_key: String = self
+            .storage
+            .lock()
+            .await
+            .get(&StorageKey::UserPrivateKey.format(Some(&user_id)))
+            .map_err(|e| KeyServiceError::StorageError(e.to_string()))?
+            .ok_or(KeyServiceError::PrivateKeyNotFound)?;
+
+        crypto::decrypt_private_key(user_key, &encrypted_private_key)
+            .map_err(|e| KeyServiceError::DecryptionFailed(e.to_string()))
+    }
+
     /// Store the user key in protected storage
     ///
     /// Called during login/unlock after decrypting user key with master key.
@@ -272,6 +354,37 @@ mod tests {
         assert!(matches!(result, Err(KeyServiceError::UserKeyNotFound)));
     }
 
+    #[tokio::test]
+    async fn test_organization_key_not_synced() {
+        let (service, _temp) = create_test_key_service().await;
+
+        service
+            .account_manager
+            .register_account("user-123", "test@example.com")
+            .await
+            .unwrap();
+        service
+            .account_manager
+            .set_active_user_id("user-123")
+            .await
+            .unwrap();
+
+        let session_key = generate_session_key();
+        let user_key = generate_session_key();
+        service
+            .store_user_key("user-123", &user_key, &session_key)
+            .await
+            .unwrap();
+
+        // No sync has stored organization keys yet
+        let session_str = crate::services::storage::format_session_key(&session_key);
+        let result = service.get_organization_key(&session_str, "org-1").await;
+        assert!(matches!(
+            result,
+            Err(KeyServiceError::OrganizationKeyNotFound(id)) if id == "org-1"
+        ));
+    }
+
     #[tokio::test]
     async fn test_clear_user_key() {
         let (service, _temp) = create_test_key_service().await;
@@ -2,6 +2,7 @@ mod container;
 mod crypto;
 pub mod key_service;
 mod sdk;
+mod strength;
 
 // Storage module
 pub mod storage;
@@ -21,7 +22,17 @@ pub mod send;
 // Import/Export module
 pub mod import_export;
 
+// Generator module
+pub mod generator;
+
+// Have I Been Pwned module
+pub mod hibp;
+
 pub use container::ServiceContainer;
-pub use crypto::{decrypt_user_key, derive_master_key, hash_password_for_auth};
+pub use crypto::{
+    decrypt_private_key, decrypt_shared_key, decrypt_string, decrypt_user_key, derive_master_key,
+    encapsulate_key, encrypt_string, fingerprint_phrase, hash_password_for_auth, is_rsa_enc_string,
+};
 pub use key_service::{KeyService, KeyServiceError};
 pub use sdk::{Client, ClientSettings, DeviceType, create_sdk_client, get_device_type};
+pub use strength::{PasswordStrength, estimate_strength};
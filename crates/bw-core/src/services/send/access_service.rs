@@ -0,0 +1,260 @@
+//! Public Send access
+//!
+//! Receiving a Send needs no account: the access URL carries both the access
+//! ID and the key seed, and the server only ever sees the access ID and the
+//! hash of the access password.
+
+use super::crypto;
+use super::errors::SendError;
+use crate::models::api::SendFileDownloadDataResponse;
+use crate::models::send::{
+    SendAccess, SendAccessRequest, SendAccessView, SendFileView, SendTextView, SendType,
+};
+use crate::services::api::{ApiClient, ApiError, BitwardenApiClient, endpoints};
+use bitwarden_crypto::SymmetricCryptoKey;
+use reqwest::StatusCode;
+use std::sync::Arc;
+use url::Url;
+
+/// Host of the Bitwarden cloud's short Send links
+const CLOUD_SEND_HOST: &str = "send.bitwarden.com";
+
+/// Web vault host that `CLOUD_SEND_HOST` links resolve against
+const CLOUD_VAULT_HOST: &str = "vault.bitwarden.com";
+
+/// Access ID and key parsed from a Send URL
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SendUrl {
+    pub access_id: String,
+    /// Send key seed, URL-safe base64
+    pub url_key: String,
+}
+
+/// Parse a Send URL (`{web_vault}/#/send/{accessId}/{urlKey}`)
+///
+/// The URL must point at the configured web vault, so a recipient can't be
+/// sent to a different server than the one they trust.
+pub fn parse_send_url(url: &str, web_vault_url: &str) -> Result<SendUrl, SendError> {
+    let invalid = |reason: &str| SendError::InvalidUrl(reason.to_string());
+
+    let url = Url::parse(url.trim()).map_err(|_| invalid("Not a valid URL"))?;
+    let vault = Url::parse(web_vault_url).map_err(|_| invalid("Invalid web vault URL"))?;
+
+    let on_vault = url.origin() == vault.origin()
+        && url.path().trim_end_matches('/') == vault.path().trim_end_matches('/');
+    let on_cloud_send_host =
+        url.host_str() == Some(CLOUD_SEND_HOST) && vault.host_str() == Some(CLOUD_VAULT_HOST);
+    if !on_vault && !on_cloud_send_host {
+        return Err(SendError::InvalidUrl(format!(
+            "{} is not a Send link for {}",
+            url,
+            web_vault_url.trim_end_matches('/')
+        )));
+    }
+
+    // 1. Fragment is "/send/{accessId}/{urlKey}" ("{accessId}/{urlKey}" on the short host)
+    let fragment = url.fragment().unwrap_or_default().trim_start_matches('/');
+    let fragment = fragment.strip_prefix("send/").unwrap_or(fragment);
+
+    // 2. Both segments are required; the key can't be recovered from the server
+    let mut segments = fragment.split('/').filter(|s| !s.is_empty());
+    match (segments.next(), segments.next(), segments.next()) {
+        (Some(access_id), Some(url_key), None) => Ok(SendUrl {
+            access_id: access_id.to_string(),
+            url_key: url_key.to_string(),
+        }),
+        _ => Err(invalid(
+            "Expected a link ending in /#/send/{accessId}/{key}",
+        )),
+    }
+}
+
+/// Service for receiving Sends
+pub struct SendAccessService {
+    api_client: Arc<BitwardenApiClient>,
+}
+
+impl SendAccessService {
+    /// Create new Send access service
+    pub fn new(api_client: Arc<BitwardenApiClient>) -> Self {
+        Self { api_client }
+    }
+
+    /// Parse a Send URL against the configured environment
+    pub fn parse_url(&self, url: &str) -> Result<SendUrl, SendError> {
+        parse_send_url(url, self.api_client.environment().web_vault_url())
+    }
+
+    /// Access and decrypt a Send
+    ///
+    /// Each successful call counts towards the Send's maximum access count.
+    pub async fn access_send(
+        &self,
+        send_url: &SendUrl,
+        password: Option<&str>,
+    ) -> Result<SendAccessView, SendError> {
+        // 1. Derive the Send key from the URL
+        let seed = crypto::seed_from_url_key(&send_url.url_key)?;
+        let send_key = crypto::derive_send_key(&seed)?;
+
+        // 2. Request access with the password hash
+        let request = access_request(password, &seed);
+        let access: SendAccess = self
+            .api_client
+            .post(
+                &endpoints::api::sends::access(&send_url.access_id),
+                &request,
+            )
+            .await
+            .map_err(|e| map_access_error(e, &send_url.access_id, password.is_some()))?;
+
+        // 3. Decrypt
+        decrypt_access(&access, &send_key)
+    }
+
+    /// Download and decrypt a file Send's contents
+    pub async fn download_file(
+        &self,
+        send_url: &SendUrl,
+        access: &SendAccessView,
+        password: Option<&str>,
+    ) -> Result<Vec<u8>, SendError> {
+        let file_id = access
+            .file
+            .as_ref()
+            .and_then(|f| f.id.as_deref())
+            .ok_or_else(|| SendError::InvalidInput("Send has no file to download".to_string()))?;
+
+        let seed = crypto::seed_from_url_key(&send_url.url_key)?;
+        let send_key = crypto::derive_send_key(&seed)?;
+
+        // 1. Exchange the password hash for a short-lived download URL
+        let request = access_request(password, &seed);
+        let download: SendFileDownloadDataResponse = self
+            .api_client
+            .post(
+                &endpoints::api::sends::access_file(&access.id, file_id),
+                &request,
+            )
+            .await
+            .map_err(|e| map_access_error(e, &send_url.access_id, password.is_some()))?;
+
+        // 2. Download and decrypt the contents
+        let encrypted = self
+            .api_client
+            .download(&download.url)
+            .await
+            .map_err(|e| SendError::Api(e.to_string()))?;
+
+        crypto::decrypt_bytes(&encrypted, &send_key)
+    }
+}
+
+fn access_request(password: Option<&str>, seed: &[u8]) -> SendAccessRequest {
+    SendAccessRequest {
+        password: password.map(|p| crypto::hash_password(p, seed)),
+    }
+}
+
+/// Map API errors to the reasons a Send can't be accessed
+///
+/// The server answers 401 when a password is required, 400 when it is wrong,
+/// and 404 for Sends that are missing, expired, disabled or used up.
+fn map_access_error(error: anyhow::Error, access_id: &str, password_given: bool) -> SendError {
+    match error.downcast_ref::<ApiError>() {
+        Some(ApiError::Authentication { .. }) if !password_given => SendError::PasswordRequired,
+        Some(ApiError::Authentication { .. }) => SendError::InvalidPassword,
+        Some(ApiError::Client { status, .. })
+            if *status == StatusCode::BAD_REQUEST && password_given =>
+        {
+            SendError::InvalidPassword
+        }
+        Some(ApiError::NotFound { .. }) => SendError::NotFound(access_id.to_string()),
+        _ => SendError::Api(error.to_string()),
+    }
+}
+
+fn decrypt_access(
+    access: &SendAccess,
+    send_key: &SymmetricCryptoKey,
+) -> Result<SendAccessView, SendError> {
+    let text = match (&access.send_type, &access.text) {
+        (SendType::Text, Some(text)) => Some(SendTextView {
+            text: text
+                .text
+                .as_deref()
+                .map(|t| crypto::decrypt_str(t, send_key))
+                .transpose()?,
+            hidden: text.hidden,
+        }),
+        _ => None,
+    };
+
+    let file = match (&access.send_type, &access.file) {
+        (SendType::File, Some(file)) => Some(SendFileView {
+            id: file.id.clone(),
+            file_name: crypto::decrypt_str(&file.file_name, send_key)?,
+            size: file.size.clone(),
+            size_name: file.size_name.clone(),
+        }),
+        _ => None,
+    };
+
+    Ok(SendAccessView {
+        id: access.id.clone(),
+        send_type: access.send_type,
+        name: crypto::decrypt_str(&access.name, send_key)?,
+        text,
+        file,
+        expiration_date: access.expiration_date.clone(),
+        creator_identifier: access.creator_identifier.clone(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VAULT: &str = "https://vault.example.com";
+
+    #[test]
+    fn test_parse_send_url() {
+        let parsed = parse_send_url("https://vault.example.com/#/send/abc123/a2V5", VAULT).unwrap();
+
+        assert_eq!(
+            parsed,
+            SendUrl {
+                access_id: "abc123".to_string(),
+                url_key: "a2V5".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_send_url_cloud_short_link() {
+        let parsed = parse_send_url(
+            "https://send.bitwarden.com/#abc123/a2V5",
+            "https://vault.bitwarden.com",
+        )
+        .unwrap();
+
+        assert_eq!(parsed.access_id, "abc123");
+        assert_eq!(parsed.url_key, "a2V5");
+    }
+
+    #[test]
+    fn test_parse_send_url_rejects_other_server() {
+        let result = parse_send_url("https://evil.example.net/#/send/abc123/a2V5", VAULT);
+        assert!(matches!(result, Err(SendError::InvalidUrl(_))));
+
+        let result = parse_send_url("https://send.bitwarden.com/#abc123/a2V5", VAULT);
+        assert!(matches!(result, Err(SendError::InvalidUrl(_))));
+    }
+
+    #[test]
+    fn test_parse_send_url_requires_key() {
+        let result = parse_send_url("https://vault.example.com/#/send/abc123", VAULT);
+
+        assert!(matches!(result, Err(SendError::InvalidUrl(_))));
+    }
+}
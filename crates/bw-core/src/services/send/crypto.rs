@@ -0,0 +1,150 @@
+//! Send key derivation and encryption
+//!
+//! Every Send has a random 16-byte seed. The Send key is derived from the
+//! seed with HKDF, so the seed alone is enough to decrypt: it is stored
+//! encrypted with the user key and shared as the last segment of the access
+//! URL.
+
+use super::errors::SendError;
+use base64::{
+    Engine,
+    engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD},
+};
+use bitwarden_crypto::{
+    CryptoError, EncString, KeyDecryptable, KeyEncryptable, SymmetricCryptoKey,
+    derive_shareable_key, pbkdf2,
+};
+use rand::{RngCore, rngs::OsRng};
+use zeroize::Zeroizing;
+
+/// Length of the Send key seed in bytes
+pub const SEND_KEY_SEED_LEN: usize = 16;
+
+/// PBKDF2 iterations for the Send access password hash
+const SEND_PASSWORD_ITERATIONS: u32 = 100_000;
+
+/// Generate a random Send key seed
+pub fn generate_seed() -> Vec<u8> {
+    let mut seed = vec![0u8; SEND_KEY_SEED_LEN];
+    OsRng.fill_bytes(&mut seed);
+    seed
+}
+
+/// Derive the Send key from its seed
+pub fn derive_send_key(seed: &[u8]) -> Result<SymmetricCryptoKey, SendError> {
+    let seed: [u8; SEND_KEY_SEED_LEN] = seed
+        .try_into()
+        .map_err(|_| SendError::Encryption("Invalid Send key length".to_string()))?;
+
+    let key = derive_shareable_key(Zeroizing::new(seed), "send", Some("send"));
+    Ok(SymmetricCryptoKey::Aes256CbcHmacKey(key))
+}
+
+/// Encode a seed for the access URL
+pub fn seed_to_url_key(seed: &[u8]) -> String {
+    URL_SAFE_NO_PAD.encode(seed)
+}
+
+/// Decode the seed from the access URL
+pub fn seed_from_url_key(url_key: &str) -> Result<Vec<u8>, SendError> {
+    URL_SAFE_NO_PAD
+        .decode(url_key.trim_end_matches('='))
+        .map_err(|_| SendError::InvalidUrl("Invalid Send key".to_string()))
+}
+
+/// Hash an access password the way the server expects
+///
+/// The server stores and compares this hash, never the password itself.
+pub fn hash_password(password: &str, seed: &[u8]) -> String {
+    STANDARD.encode(pbkdf2(password.as_bytes(), seed, SEND_PASSWORD_ITERATIONS))
+}
+
+/// Encrypt a seed with the user key for the Send's `key` field
+pub fn encrypt_seed(seed: &[u8], user_key: &SymmetricCryptoKey) -> Result<String, SendError> {
+    seed.encrypt_with_key(user_key)
+        .map(|enc: EncString| enc.to_string())
+        .map_err(|e| SendError::Encryption(e.to_string()))
+}
+
+/// Decrypt the seed from a Send's `key` field
+pub fn decrypt_seed(
+    encrypted_seed: &str,
+    user_key: &SymmetricCryptoKey,
+) -> Result<Vec<u8>, SendError> {
+    let enc: EncString = encrypted_seed
+        .parse()
+        .map_err(|e: CryptoError| SendError::Encryption(e.to_string()))?;
+    enc.decrypt_with_key(user_key)
+        .map_err(|e| SendError::Encryption(e.to_string()))
+}
+
+pub(crate) fn encrypt_str(value: &str, key: &SymmetricCryptoKey) -> Result<String, SendError> {
+    value
+        .to_string()
+        .encrypt_with_key(key)
+        .map(|enc| enc.to_string())
+        .map_err(|e| SendError::Encryption(e.to_string()))
+}
+
+pub(crate) fn decrypt_str(value: &str, key: &SymmetricCryptoKey) -> Result<String, SendError> {
+    let enc: EncString = value
+        .parse()
+        .map_err(|e: CryptoError| SendError::Encryption(e.to_string()))?;
+    enc.decrypt_with_key(key)
+        .map_err(|e| SendError::Encryption(e.to_string()))
+}
+
+pub(crate) fn encrypt_bytes(value: &[u8], key: &SymmetricCryptoKey) -> Result<Vec<u8>, SendError> {
+    value
+        .encrypt_with_key(key)
+        .and_then(|enc: EncString| enc.to_buffer())
+        .map_err(|e| SendError::Encryption(e.to_string()))
+}
+
+pub(crate) fn decrypt_bytes(value: &[u8], key: &SymmetricCryptoKey) -> Result<Vec<u8>, SendError> {
+    EncString::from_buffer(value)
+        .and_then(|enc| enc.decrypt_with_key(key))
+        .map_err(|e| SendError::Encryption(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_url_key_roundtrip() {
+        let seed = generate_seed();
+        let url_key = seed_to_url_key(&seed);
+
+        assert!(!url_key.contains('='));
+        assert_eq!(seed_from_url_key(&url_key).unwrap(), seed);
+    }
+
+    #[test]
+    fn test_send_key_encrypts_and_decrypts() {
+        let seed = generate_seed();
+        let key = derive_send_key(&seed).unwrap();
+
+        let encrypted = encrypt_str("shared secret", &key).unwrap();
+        let same_key = derive_send_key(&seed).unwrap();
+        assert_eq!(decrypt_str(&encrypted, &same_key).unwrap(), "shared secret");
+
+        let contents = encrypt_bytes(b"file contents", &key).unwrap();
+        assert_eq!(
+            decrypt_bytes(&contents, &same_key).unwrap(),
+            b"file contents"
+        );
+    }
+
+    #[test]
+    fn test_hash_password_depends_on_seed() {
+        let seed = generate_seed();
+
+        assert_eq!(hash_password("pw", &seed), hash_password("pw", &seed));
+        assert_ne!(
+            hash_password("pw", &seed),
+            hash_password("pw", &generate_seed())
+        );
+        assert!(derive_send_key(&[0u8; 8]).is_err());
+    }
+}
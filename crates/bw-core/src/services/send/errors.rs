@@ -1,3 +1,4 @@
+use crate::services::key_service::KeyServiceError;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -11,18 +12,42 @@ pub enum SendError {
     #[error("Send not found: {0}")]
     NotFound(String),
 
+    #[error("More than one Send matches: {0}")]
+    MultipleResults(String),
+
     #[error("Send expired")]
     Expired,
 
     #[error("Send access limit exceeded")]
     AccessLimitExceeded,
 
+    #[error("This Send is password protected. Use --password to provide it.")]
+    PasswordRequired,
+
     #[error("Invalid password")]
     InvalidPassword,
 
     #[error("Invalid Send URL: {0}")]
     InvalidUrl(String),
 
+    #[error("Invalid input: {0}")]
+    InvalidInput(String),
+
+    #[error("File is too large. Maximum Send file size is {} MB.", .max / (1024 * 1024))]
+    FileTooLarge { size: u64, max: u64 },
+
+    #[error("Not authenticated. Run 'bw login' first.")]
+    NotAuthenticated,
+
+    #[error("Sends not synced. Run 'bw sync' first.")]
+    NotSynced,
+
+    #[error("Storage error: {0}")]
+    StorageError(String),
+
+    #[error(transparent)]
+    Key(#[from] KeyServiceError),
+
     #[error("File error: {0}")]
     FileError(#[from] std::io::Error),
 
@@ -1,19 +1,17 @@
-// Send service module
-//
-// This module provides Send operations including:
-// - Creating and managing Sends
-// - Encrypting/decrypting Send content
-// - Accessing public Sends
-//
-// TODO: Full implementation requires:
-// 1. Send encryption service using Bitwarden SDK or rust-crypto
-// 2. Send API client methods
-// 3. Send service business logic
-// 4. Key management for Send encryption
+//! Send service module
+//!
+//! Sends share text or files through a public link. Content is encrypted
+//! with a per-Send key, so the server never sees it; the key travels in the
+//! link's URL fragment.
 
+mod access_service;
+pub mod crypto;
 mod errors;
+mod send_service;
 
+pub use access_service::{SendAccessService, SendUrl, parse_send_url};
 pub use errors::SendError;
+pub use send_service::{DEFAULT_DELETION_DAYS, MAX_SEND_FILE_SIZE, SendService};
 
 // Re-export Send models for convenience
-pub use crate::models::send::{Send, SendAccess, SendType};
+pub use crate::models::send::{Send, SendAccess, SendAccessView, SendType, SendView};
@@ -0,0 +1,509 @@
+//! Send service
+//!
+//! Creates, edits and deletes Sends through the API and reads them from the
+//! local cache written by `SyncService::sync`. Content is encrypted with a
+//! per-Send key (see [`super::crypto`]); the user key only protects the seed.
+
+use super::crypto;
+use super::errors::SendError;
+use crate::models::api::{FileUploadType, SendFileUploadDataResponse};
+use crate::models::send::{
+    Send, SendFileRequest, SendFileView, SendRequest, SendTextRequest, SendTextView, SendType,
+    SendView,
+};
+use crate::services::api::{ApiClient, BitwardenApiClient, endpoints};
+use crate::services::key_service::KeyService;
+use crate::services::storage::{AccountManager, JsonFileStorage, Storage, StorageKey};
+use bitwarden_crypto::SymmetricCryptoKey;
+use chrono::{DateTime, Duration, SecondsFormat, Utc};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+/// Largest file Send the server accepts (500 MB)
+pub const MAX_SEND_FILE_SIZE: u64 = 500 * 1024 * 1024;
+
+/// Days until a new Send is deleted when no deletion date is given
+pub const DEFAULT_DELETION_DAYS: i64 = 7;
+
+/// Service for Send operations
+pub struct SendService {
+    api_client: Arc<BitwardenApiClient>,
+    storage: Arc<Mutex<JsonFileStorage>>,
+    account_manager: Arc<AccountManager>,
+    key_service: KeyService,
+}
+
+impl SendService {
+    /// Create new Send service
+    pub fn new(
+        api_client: Arc<BitwardenApiClient>,
+        storage: Arc<Mutex<JsonFileStorage>>,
+        account_manager: Arc<AccountManager>,
+    ) -> Self {
+        let key_service = KeyService::new(Arc::clone(&storage), Arc::clone(&account_manager));
+
+        Self {
+            api_client,
+            storage,
+            account_manager,
+            key_service,
+        }
+    }
+
+    /// List all Sends from the local cache
+    pub async fn list_sends(&self, session: &str) -> Result<Vec<SendView>, SendError> {
+        let user_key = self.key_service.get_user_key(session).await?;
+        let sends = self.get_sends_data().await?;
+
+        let mut views = sends
+            .values()
+            .map(|send| self.decrypt_send(send, &user_key))
+            .collect::<Result<Vec<_>, _>>()?;
+        views.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()));
+
+        Ok(views)
+    }
+
+    /// Get a Send by ID, or by name if exactly one Send matches
+    pub async fn get_send(&self, id_or_search: &str, session: &str) -> Result<SendView, SendError> {
+        let user_key = self.key_service.get_user_key(session).await?;
+        let sends = self.get_sends_data().await?;
+
+        if let Some(send) = sends.get(id_or_search) {
+            return self.decrypt_send(send, &user_key);
+        }
+
+        // Names are encrypted, so searching means decrypting everything
+        let search = id_or_search.to_lowercase();
+        let mut matches = sends
+            .values()
+            .map(|send| self.decrypt_send(send, &user_key))
+            .filter(|view| {
+                view.as_ref()
+                    .map(|v| v.name.to_lowercase().contains(&search))
+                    .unwrap_or(true)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        match matches.len() {
+            0 => Err(SendError::NotFound(id_or_search.to_string())),
+            1 => Ok(matches.remove(0)),
+            _ => Err(SendError::MultipleResults(id_or_search.to_string())),
+        }
+    }
+
+    /// Create a new Send
+    ///
+    /// # Arguments
+    /// * `view` - Send contents and options; `file.file_name` names file Sends
+    /// * `file_contents` - Plaintext file contents (file Sends only)
+    /// * `session` - BW_SESSION key for the user key
+    pub async fn create_send(
+        &self,
+        view: SendView,
+        file_contents: Option<Vec<u8>>,
+        session: &str,
+    ) -> Result<SendView, SendError> {
+        let user_key = self.key_service.get_user_key(session).await?;
+
+        // 1. Check the file before doing any work
+        if view.send_type == SendType::File {
+            let contents = file_contents
+                .as_ref()
+                .ok_or_else(|| SendError::InvalidInput("File Sends require a file".to_string()))?;
+            let size = contents.len() as u64;
+            if size > MAX_SEND_FILE_SIZE {
+                return Err(SendError::FileTooLarge {
+                    size,
+                    max: MAX_SEND_FILE_SIZE,
+                });
+            }
+        }
+
+        // 2. Generate a fresh Send key and encrypt the contents
+        let seed = crypto::generate_seed();
+        let send_key = crypto::derive_send_key(&seed)?;
+        let mut request = self.build_request(&view, &seed, &send_key, &user_key)?;
+
+        // 3. Send to API (file Sends upload their contents separately)
+        let created = match view.send_type {
+            SendType::Text => self
+                .api_client
+                .post_with_auth(endpoints::api::sends::BASE, &request)
+                .await
+                .map_err(|e| SendError::Api(e.to_string()))?,
+            SendType::File => {
+                let contents =
+                    crypto::encrypt_bytes(&file_contents.unwrap_or_default(), &send_key)?;
+                request.file_length = Some(contents.len() as u64);
+                self.create_file_send(&request, contents).await?
+            }
+        };
+
+        // 4. Update local cache
+        self.add_send_to_cache(&created).await?;
+
+        self.decrypt_send(&created, &user_key)
+    }
+
+    /// Edit an existing Send
+    ///
+    /// The Send type and file can't change. A missing password keeps the
+    /// current one; use [`Self::remove_password`] to clear it.
+    pub async fn edit_send(
+        &self,
+        id: &str,
+        mut view: SendView,
+        session: &str,
+    ) -> Result<SendView, SendError> {
+        let user_key = self.key_service.get_user_key(session).await?;
+
+        // 1. Get the cached Send and its key
+        let existing = self.get_cached_send(id).await?;
+        if existing.send_type != view.send_type {
+            return Err(SendError::InvalidInput(
+                "A Send's type can't be changed".to_string(),
+            ));
+        }
+        let seed = crypto::decrypt_seed(&existing.key, &user_key)?;
+        let send_key = crypto::derive_send_key(&seed)?;
+        if existing.send_type == SendType::File && view.file.is_none() {
+            view.file = self.decrypt_send(&existing, &user_key)?.file;
+        }
+
+        // 2. Re-encrypt with the existing Send key
+        let mut request = self.build_request(&view, &seed, &send_key, &user_key)?;
+        request.key = existing.key.clone();
+        if existing.send_type == SendType::File {
+            request.file = existing.file.as_ref().map(|f| SendFileRequest {
+                file_name: f.file_name.clone(),
+            });
+        }
+
+        // 3. Send to API
+        let updated: Send = self
+            .api_client
+            .put_with_auth(&endpoints::api::sends::by_id(id), &request)
+            .await
+            .map_err(|e| SendError::Api(e.to_string()))?;
+
+        // 4. Update local cache
+        self.add_send_to_cache(&updated).await?;
+
+        self.decrypt_send(&updated, &user_key)
+    }
+
+    /// Remove the access password from a Send
+    pub async fn remove_password(&self, id: &str, session: &str) -> Result<SendView, SendError> {
+        let user_key = self.key_service.get_user_key(session).await?;
+        let mut send = self.get_cached_send(id).await?;
+
+        self.api_client
+            .put_with_auth_no_response(&endpoints::api::sends::remove_password(id))
+            .await
+            .map_err(|e| SendError::Api(e.to_string()))?;
+
+        send.password = None;
+        self.add_send_to_cache(&send).await?;
+
+        self.decrypt_send(&send, &user_key)
+    }
+
+    /// Permanently delete a Send
+    pub async fn delete_send(&self, id: &str) -> Result<(), SendError> {
+        // 1. Validate Send exists
+        self.get_cached_send(id).await?;
+
+        // 2. Send delete to API
+        self.api_client
+            .delete_with_auth(&endpoints::api::sends::by_id(id))
+            .await
+            .map_err(|e| SendError::Api(e.to_string()))?;
+
+        // 3. Update cache
+        let user_id = self.get_user_id().await?;
+        let mut sends = self.get_sends_data().await?;
+        sends.remove(id);
+        self.save_sends(&user_id, &sends).await
+    }
+
+    /// Request an upload slot and upload the encrypted file contents
+    async fn create_file_send(
+        &self,
+        request: &SendRequest,
+        contents: Vec<u8>,
+    ) -> Result<Send, SendError> {
+        let upload: SendFileUploadDataResponse = self
+            .api_client
+            .post_with_auth(endpoints::api::sends::FILE_V2, request)
+            .await
+            .map_err(|e| SendError::Api(e.to_string()))?;
+
+        let send = upload.send_response;
+        let file_id = send
+            .file
+            .as_ref()
+            .and_then(|f| f.id.clone())
+            .ok_or_else(|| SendError::Api("Server did not return a file ID".to_string()))?;
+
+        let result = match (upload.file_upload_type, upload.url) {
+            (FileUploadType::Azure, Some(url)) => self.api_client.put_blob(&url, contents).await,
+            _ => {
+                let file_name = request
+                    .file
+                    .as_ref()
+                    .map(|f| f.file_name.as_str())
+                    .unwrap_or_default();
+                self.api_client
+                    .post_multipart_with_auth(
+                        &endpoints::api::sends::file(&send.id, &file_id),
+                        file_name,
+                        contents,
+                    )
+                    .await
+            }
+        };
+
+        // Don't leave a Send without contents behind
+        if let Err(e) = result {
+            let _ = self
+                .api_client
+                .delete_with_auth(&endpoints::api::sends::by_id(&send.id))
+                .await;
+            return Err(SendError::Api(format!("File upload failed: {}", e)));
+        }
+
+        Ok(send)
+    }
+
+    /// Encrypt a Send view into an API request
+    fn build_request(
+        &self,
+        view: &SendView,
+        seed: &[u8],
+        send_key: &SymmetricCryptoKey,
+        user_key: &SymmetricCryptoKey,
+    ) -> Result<SendRequest, SendError> {
+        if view.name.trim().is_empty() {
+            return Err(SendError::InvalidInput("Send name is required".to_string()));
+        }
+
+        let text = match view.send_type {
+            SendType::Text => {
+                let text = view.text.as_ref().ok_or_else(|| {
+                    SendError::InvalidInput("Text Sends require text".to_string())
+                })?;
+                Some(SendTextRequest {
+                    text: crypto::encrypt_str(text.text.as_deref().unwrap_or_default(), send_key)?,
+                    hidden: text.hidden,
+                })
+            }
+            SendType::File => None,
+        };
+
+        let file = match view.send_type {
+            SendType::File => {
+                let file_name = view
+                    .file
+                    .as_ref()
+                    .map(|f| f.file_name.as_str())
+                    .filter(|name| !name.is_empty())
+                    .ok_or_else(|| {
+                        SendError::InvalidInput("File Sends require a file name".to_string())
+                    })?;
+                Some(SendFileRequest {
+                    file_name: crypto::encrypt_str(file_name, send_key)?,
+                })
+            }
+            SendType::Text => None,
+        };
+
+        let deletion_date = match view.deletion_date.as_deref() {
+            Some(date) => normalize_date(date)?,
+            None => format_date(Utc::now() + Duration::days(DEFAULT_DELETION_DAYS)),
+        };
+        let expiration_date = view
+            .expiration_date
+            .as_deref()
+            .map(normalize_date)
+            .transpose()?;
+
+        Ok(SendRequest {
+            send_type: view.send_type,
+            name: crypto::encrypt_str(&view.name, send_key)?,
+            notes: view
+                .notes
+                .as_deref()
+                .filter(|notes| !notes.is_empty())
+                .map(|notes| crypto::encrypt_str(notes, send_key))
+                .transpose()?,
+            text,
+            file,
+            file_length: None,
+            key: crypto::encrypt_seed(seed, user_key)?,
+            max_access_count: view.max_access_count,
+            expiration_date,
+            deletion_date,
+            password: view
+                .password
+                .as_deref()
+                .filter(|password| !password.is_empty())
+                .map(|password| crypto::hash_password(password, seed)),
+            disabled: view.disabled,
+            hide_email: Some(view.hide_email),
+        })
+    }
+
+    /// Decrypt a cached Send
+    fn decrypt_send(
+        &self,
+        send: &Send,
+        user_key: &SymmetricCryptoKey,
+    ) -> Result<SendView, SendError> {
+        let seed = crypto::decrypt_seed(&send.key, user_key)?;
+        let send_key = crypto::derive_send_key(&seed)?;
+        let url_key = crypto::seed_to_url_key(&seed);
+
+        let text = send
+            .text
+            .as_ref()
+            .map(|t| -> Result<_, SendError> {
+                Ok(SendTextView {
+                    text: t
+                        .text
+                        .as_deref()
+                        .map(|text| crypto::decrypt_str(text, &send_key))
+                        .transpose()?,
+                    hidden: t.hidden,
+                })
+            })
+            .transpose()?;
+
+        let file = send
+            .file
+            .as_ref()
+            .map(|f| -> Result<_, SendError> {
+                Ok(SendFileView {
+                    id: f.id.clone(),
+                    file_name: crypto::decrypt_str(&f.file_name, &send_key)?,
+                    size: f.size.clone(),
+                    size_name: f.size_name.clone(),
+                })
+            })
+            .transpose()?;
+
+        Ok(SendView {
+            id: Some(send.id.clone()),
+            access_id: Some(send.access_id.clone()),
+            send_type: send.send_type,
+            name: crypto::decrypt_str(&send.name, &send_key)?,
+            notes: send
+                .notes
+                .as_deref()
+                .map(|notes| crypto::decrypt_str(notes, &send_key))
+                .transpose()?,
+            text,
+            file,
+            access_url: Some(format!(
+                "{}/#/send/{}/{}",
+                self.api_client.environment().web_vault_url(),
+                send.access_id,
+                url_key
+            )),
+            key: Some(url_key),
+            max_access_count: send.max_access_count,
+            access_count: send.access_count,
+            expiration_date: send.expiration_date.clone(),
+            deletion_date: Some(send.deletion_date.clone()),
+            disabled: send.disabled,
+            password: None,
+            password_set: send.password.is_some(),
+            hide_email: send.hide_email.unwrap_or(false),
+            revision_date: Some(send.revision_date.clone()),
+        })
+    }
+
+    // ========== Cache Management ==========
+
+    async fn get_user_id(&self) -> Result<String, SendError> {
+        self.account_manager
+            .get_active_user_id()
+            .await
+            .map_err(|e| SendError::StorageError(e.to_string()))?
+            .ok_or(SendError::NotAuthenticated)
+    }
+
+    /// Get Sends from flat storage (stored as HashMap<id, Send>)
+    async fn get_sends_data(&self) -> Result<HashMap<String, Send>, SendError> {
+        let user_id = self.get_user_id().await?;
+        let storage = self.storage.lock().await;
+        storage
+            .get::<HashMap<String, Send>>(&StorageKey::UserSends.format(Some(&user_id)))
+            .map_err(|e| SendError::StorageError(e.to_string()))?
+            .ok_or(SendError::NotSynced)
+    }
+
+    async fn get_cached_send(&self, id: &str) -> Result<Send, SendError> {
+        self.get_sends_data()
+            .await?
+            .remove(id)
+            .ok_or_else(|| SendError::NotFound(id.to_string()))
+    }
+
+    async fn add_send_to_cache(&self, send: &Send) -> Result<(), SendError> {
+        let user_id = self.get_user_id().await?;
+        // A vault synced before Sends were cached has no entry yet
+        let mut sends = match self.get_sends_data().await {
+            Ok(sends) => sends,
+            Err(SendError::NotSynced) => HashMap::new(),
+            Err(e) => return Err(e),
+        };
+        sends.insert(send.id.clone(), send.clone());
+        self.save_sends(&user_id, &sends).await
+    }
+
+    async fn save_sends(
+        &self,
+        user_id: &str,
+        sends: &HashMap<String, Send>,
+    ) -> Result<(), SendError> {
+        let mut storage = self.storage.lock().await;
+        storage
+            .set(&StorageKey::UserSends.format(Some(user_id)), sends)
+            .await
+            .map_err(|e| SendError::StorageError(e.to_string()))?;
+        storage
+            .flush()
+            .await
+            .map_err(|e| SendError::StorageError(e.to_string()))
+    }
+}
+
+/// Parse a user-supplied date and format it the way the API expects
+fn normalize_date(date: &str) -> Result<String, SendError> {
+    DateTime::parse_from_rfc3339(date)
+        .map(|d| format_date(d.with_timezone(&Utc)))
+        .map_err(|_| SendError::InvalidInput(format!("Invalid date: {}", date)))
+}
+
+fn format_date(date: DateTime<Utc>) -> String {
+    date.to_rfc3339_opts(SecondsFormat::Millis, true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_date() {
+        assert_eq!(
+            normalize_date("2025-01-08T12:00:00+02:00").unwrap(),
+            "2025-01-08T10:00:00.000Z"
+        );
+        assert!(matches!(
+            normalize_date("next week"),
+            Err(SendError::InvalidInput(_))
+        ));
+    }
+}
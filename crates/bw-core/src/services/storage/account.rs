@@ -48,18 +48,11 @@ impl AccountManager {
     /// Get the active user ID, if any
     ///
     /// Returns `Ok(None)` if no user is logged in.
-    /// The active account ID is stored at `global_account_activeAccountId`.
+    /// The active account ID is stored at `global_account_activeAccountId`,
+    /// unless the storage is scoped to another account with `--account`.
     pub async fn get_active_user_id(&self) -> Result<Option<String>> {
         let storage = self.storage.lock().await;
-        let key = StorageKey::GlobalActiveAccountId.format(None);
-
-        // The value might be null (JSON null) or a string
-        let value: Option<serde_json::Value> = storage.get(&key)?;
-
-        match value {
-            Some(serde_json::Value::String(id)) if !id.is_empty() => Ok(Some(id)),
-            _ => Ok(None),
-        }
+        storage.active_user_id()
     }
 
     /// Set the active user ID
@@ -70,6 +63,11 @@ impl AccountManager {
         let key = StorageKey::GlobalActiveAccountId.format(None);
         storage.set(&key, &user_id.to_string()).await?;
         storage.flush().await?;
+
+        // A scoped process follows the account it just activated, e.g. on login
+        if storage.account_scope().is_some() {
+            storage.scope_to_account(Some(user_id.to_string()));
+        }
         Ok(())
     }
 
@@ -85,6 +83,42 @@ impl AccountManager {
         Ok(())
     }
 
+    /// Hand over the active account if it is the given user (on logout)
+    ///
+    /// Another logged in account becomes active, or none if there isn't one.
+    /// Logging out a scoped account that isn't the active one leaves the
+    /// active account alone.
+    pub async fn deactivate_account(&self, user_id: &str) -> Result<()> {
+        let is_active = {
+            let storage = self.storage.lock().await;
+            storage.stored_active_user_id()?.as_deref() == Some(user_id)
+        };
+        if !is_active {
+            return Ok(());
+        }
+
+        // Pick the remaining accounts in the order `bw account list` shows them
+        let mut accounts: Vec<(String, AccountInfo)> = self
+            .get_all_accounts()
+            .await?
+            .into_iter()
+            .filter(|(id, _)| id != user_id)
+            .collect();
+        accounts.sort_by(|a, b| a.1.email.cmp(&b.1.email));
+
+        for (next_user_id, _) in accounts {
+            if self.is_account_logged_in(&next_user_id).await? {
+                let mut storage = self.storage.lock().await;
+                let key = StorageKey::GlobalActiveAccountId.format(None);
+                storage.set(&key, &next_user_id).await?;
+                storage.flush().await?;
+                return Ok(());
+            }
+        }
+
+        self.clear_active_account().await
+    }
+
     /// Register an account in the global accounts registry
     ///
     /// Adds or updates the account entry. The registry is stored at
@@ -129,6 +163,30 @@ impl AccountManager {
         Ok(storage.get(&key)?.unwrap_or_default())
     }
 
+    /// Find a registered account by user ID or email
+    ///
+    /// Emails are compared case-insensitively.
+    pub async fn find_account(&self, email_or_id: &str) -> Result<Option<String>> {
+        let storage = self.storage.lock().await;
+        Self::find_account_in(&storage, email_or_id)
+    }
+
+    /// Find a registered account without going through an AccountManager
+    ///
+    /// Used at startup, before services are created.
+    pub fn find_account_in(storage: &JsonFileStorage, email_or_id: &str) -> Result<Option<String>> {
+        let key = StorageKey::GlobalAccounts.format(None);
+        let accounts: HashMap<String, AccountInfo> = storage.get(&key)?.unwrap_or_default();
+
+        if accounts.contains_key(email_or_id) {
+            return Ok(Some(email_or_id.to_string()));
+        }
+        Ok(accounts
+            .into_iter()
+            .find(|(_, account)| account.email.eq_ignore_ascii_case(email_or_id))
+            .map(|(user_id, _)| user_id))
+    }
+
     /// Remove an account from the registry
     ///
     /// This fully removes the account. Use `clear_active_account` for logout.
@@ -154,13 +212,16 @@ impl AccountManager {
     /// 1. There is an active account ID
     /// 2. The active account has an access token (not null)
     pub async fn is_logged_in(&self) -> Result<bool> {
-        let user_id = match self.get_active_user_id().await? {
-            Some(id) => id,
-            None => return Ok(false),
-        };
+        match self.get_active_user_id().await? {
+            Some(user_id) => self.is_account_logged_in(&user_id).await,
+            None => Ok(false),
+        }
+    }
 
+    /// Check if an account has tokens, i.e. hasn't logged out
+    pub async fn is_account_logged_in(&self, user_id: &str) -> Result<bool> {
         let storage = self.storage.lock().await;
-        let token_key = StorageKey::UserAccessToken.format(Some(&user_id));
+        let token_key = StorageKey::UserAccessToken.format(Some(user_id));
 
         // Check for access token (may be null if logged out)
         let token: Option<serde_json::Value> = storage.get(&token_key)?;
@@ -211,6 +272,105 @@ mod tests {
         assert!(user_id.is_none());
     }
 
+    #[tokio::test]
+    async fn test_account_scope_overrides_active_user() {
+        let (manager, _temp) = create_test_account_manager().await;
+        manager.set_active_user_id("user-1").await.unwrap();
+
+        manager
+            .storage
+            .lock()
+            .await
+            .scope_to_account(Some("user-2".to_string()));
+
+        assert_eq!(
+            manager.get_active_user_id().await.unwrap(),
+            Some("user-2".to_string())
+        );
+
+        // Logging out the scoped account keeps the stored active account
+        manager.deactivate_account("user-2").await.unwrap();
+        let stored = manager
+            .storage
+            .lock()
+            .await
+            .stored_active_user_id()
+            .unwrap();
+        assert_eq!(stored, Some("user-1".to_string()));
+
+        manager.deactivate_account("user-1").await.unwrap();
+        let stored = manager
+            .storage
+            .lock()
+            .await
+            .stored_active_user_id()
+            .unwrap();
+        assert!(stored.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_deactivate_account_activates_remaining_logged_in_account() {
+        let (manager, _temp) = create_test_account_manager().await;
+        for (user_id, email) in [
+            ("user-1", "a@example.com"),
+            ("user-2", "b@example.com"),
+            ("user-3", "c@example.com"),
+        ] {
+            manager.register_account(user_id, email).await.unwrap();
+        }
+        // user-2 has logged out, user-3 is still logged in
+        {
+            let mut storage = manager.storage.lock().await;
+            storage
+                .set(
+                    &StorageKey::UserAccessToken.format(Some("user-2")),
+                    &serde_json::Value::Null,
+                )
+                .await
+                .unwrap();
+            storage
+                .set(
+                    &StorageKey::UserAccessToken.format(Some("user-3")),
+                    &"access-token".to_string(),
+                )
+                .await
+                .unwrap();
+        }
+        manager.set_active_user_id("user-1").await.unwrap();
+
+        manager.deactivate_account("user-1").await.unwrap();
+
+        assert_eq!(
+            manager.get_active_user_id().await.unwrap(),
+            Some("user-3".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_find_account_by_email_or_id() {
+        let (manager, _temp) = create_test_account_manager().await;
+        manager
+            .register_account("user-1", "user1@example.com")
+            .await
+            .unwrap();
+
+        assert_eq!(
+            manager.find_account("USER1@example.com").await.unwrap(),
+            Some("user-1".to_string())
+        );
+        assert_eq!(
+            manager.find_account("user-1").await.unwrap(),
+            Some("user-1".to_string())
+        );
+        assert!(
+            manager
+                .find_account("nobody@example.com")
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+
     #[tokio::test]
     async fn test_register_and_get_account() {
         let (manager, _temp) = create_test_account_manager().await;
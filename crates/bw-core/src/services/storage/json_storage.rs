@@ -31,6 +31,10 @@ pub struct JsonFileStorage {
 
     /// Atomic writer for safe file operations
     writer: AtomicWriter,
+
+    /// Account selected with `--account`, overriding the stored active
+    /// account for this process only
+    account_scope: Option<String>,
 }
 
 impl JsonFileStorage {
@@ -59,7 +63,11 @@ impl JsonFileStorage {
             Arc::new(Mutex::new(HashMap::new()))
         };
 
-        Ok(Self { data, writer })
+        Ok(Self {
+            data,
+            writer,
+            account_scope: None,
+        })
     }
 
     /// Load storage from file
@@ -119,6 +127,39 @@ impl JsonFileStorage {
         Ok(data.get("stateVersion").and_then(|v| v.as_u64()))
     }
 
+    /// Scope this storage to an account instead of the stored active account
+    ///
+    /// The scope isn't persisted. `None` goes back to the stored active account.
+    pub fn scope_to_account(&mut self, user_id: Option<String>) {
+        self.account_scope = user_id;
+    }
+
+    /// Account this storage is scoped to, if any
+    pub fn account_scope(&self) -> Option<&str> {
+        self.account_scope.as_deref()
+    }
+
+    /// Get the user ID services act for
+    ///
+    /// This is the scoped account if set, otherwise the stored active account.
+    pub fn active_user_id(&self) -> Result<Option<String>> {
+        match &self.account_scope {
+            Some(user_id) => Ok(Some(user_id.clone())),
+            None => self.stored_active_user_id(),
+        }
+    }
+
+    /// Get the stored active user ID, ignoring any account scope
+    pub fn stored_active_user_id(&self) -> Result<Option<String>> {
+        // The value might be null (JSON null) or a string
+        let value: Option<Value> = self.get(&StorageKey::GlobalActiveAccountId.format(None))?;
+
+        match value {
+            Some(Value::String(id)) if !id.is_empty() => Ok(Some(id)),
+            _ => Ok(None),
+        }
+    }
+
     /// Get nested value by dot-separated path
     ///
     /// Example: "environmentUrls.api" accesses obj["environmentUrls"]["api"]
@@ -25,6 +25,12 @@ pub enum StorageKey {
     /// Currently active user ID (or null)
     GlobalActiveAccountId,
 
+    /// Remembered two-factor tokens: HashMap<Email, Token>
+    GlobalTwoFactorTokens,
+
+    /// Server URLs set by `bw config server`, copied to the account on login
+    GlobalEnvironment,
+
     // ============================================
     // User-namespaced keys (require user ID)
     // ============================================
@@ -37,6 +43,9 @@ pub enum StorageKey {
     /// Encrypted RSA private key
     UserPrivateKey,
 
+    /// Organization keys encrypted with the user's public key
+    UserOrganizationKeys,
+
     /// Master password hash
     UserMasterKeyHash,
 
@@ -55,6 +64,18 @@ pub enum StorageKey {
     /// Encrypted user key
     UserKey,
 
+    /// Device key for trusted device decryption (base64)
+    UserDeviceKey,
+
+    /// Saved `bw generate` defaults
+    UserGeneratorOptions,
+
+    /// Email forwarder credentials: HashMap<Service, ForwarderSettings>
+    UserForwarders,
+
+    /// PIN unlock settings and the PIN-protected user key
+    UserPinState,
+
     // ============================================
     // Vault data keys (user-namespaced)
     // ============================================
@@ -70,6 +91,9 @@ pub enum StorageKey {
     /// Organizations array
     UserOrganizations,
 
+    /// Encrypted Sends
+    UserSends,
+
     /// Last sync timestamp
     UserLastSync,
 
@@ -109,6 +133,10 @@ impl StorageKey {
             Self::GlobalAppId => "global_applicationId_appId".to_string(),
             Self::GlobalAccounts => "global_account_accounts".to_string(),
             Self::GlobalActiveAccountId => "global_account_activeAccountId".to_string(),
+            Self::GlobalTwoFactorTokens => {
+                "global_tokenDiskLocal_emailTwoFactorTokenRecord".to_string()
+            }
+            Self::GlobalEnvironment => "global_environment_environment".to_string(),
             Self::DeviceId => "global_deviceId".to_string(),
             Self::SessionKeyHint => "sessionKeyHint".to_string(),
 
@@ -125,6 +153,10 @@ impl StorageKey {
                 let uid = user_id.expect("UserPrivateKey requires user_id");
                 format!("user_{}_crypto_privateKey", uid)
             }
+            Self::UserOrganizationKeys => {
+                let uid = user_id.expect("UserOrganizationKeys requires user_id");
+                format!("user_{}_crypto_organizationKeys", uid)
+            }
             Self::UserMasterKeyHash => {
                 let uid = user_id.expect("UserMasterKeyHash requires user_id");
                 format!("user_{}_masterPassword_masterKeyHash", uid)
@@ -149,6 +181,22 @@ impl StorageKey {
                 let uid = user_id.expect("UserKey requires user_id");
                 format!("user_{}_masterPassword_masterKeyEncryptedUserKey", uid)
             }
+            Self::UserDeviceKey => {
+                let uid = user_id.expect("UserDeviceKey requires user_id");
+                format!("user_{}_deviceTrust_deviceKey", uid)
+            }
+            Self::UserGeneratorOptions => {
+                let uid = user_id.expect("UserGeneratorOptions requires user_id");
+                format!("user_{}_generator_options", uid)
+            }
+            Self::UserForwarders => {
+                let uid = user_id.expect("UserForwarders requires user_id");
+                format!("user_{}_generator_forwarders", uid)
+            }
+            Self::UserPinState => {
+                let uid = user_id.expect("UserPinState requires user_id");
+                format!("user_{}_pinUnlock_pinState", uid)
+            }
             Self::UserCiphers => {
                 let uid = user_id.expect("UserCiphers requires user_id");
                 format!("user_{}_ciphers_ciphers", uid)
@@ -165,6 +213,10 @@ impl StorageKey {
                 let uid = user_id.expect("UserOrganizations requires user_id");
                 format!("user_{}_organizations_organizations", uid)
             }
+            Self::UserSends => {
+                let uid = user_id.expect("UserSends requires user_id");
+                format!("user_{}_encryptedSend_sendUserEncrypted", uid)
+            }
             Self::UserLastSync => {
                 let uid = user_id.expect("UserLastSync requires user_id");
                 format!("user_{}_sync_lastSync", uid)
@@ -179,16 +231,22 @@ impl StorageKey {
             Self::UserAccessToken
                 | Self::UserRefreshToken
                 | Self::UserPrivateKey
+                | Self::UserOrganizationKeys
                 | Self::UserMasterKeyHash
                 | Self::UserEnvironment
                 | Self::UserVaultTimeout
                 | Self::UserVaultTimeoutAction
                 | Self::UserKdfConfig
                 | Self::UserKey
+                | Self::UserDeviceKey
+                | Self::UserGeneratorOptions
+                | Self::UserForwarders
+                | Self::UserPinState
                 | Self::UserCiphers
                 | Self::UserFolders
                 | Self::UserCollections
                 | Self::UserOrganizations
+                | Self::UserSends
                 | Self::UserLastSync
         )
     }
@@ -219,6 +277,10 @@ mod tests {
             StorageKey::GlobalActiveAccountId.format(None),
             "global_account_activeAccountId"
         );
+        assert_eq!(
+            StorageKey::GlobalEnvironment.format(None),
+            "global_environment_environment"
+        );
     }
 
     #[test]
@@ -237,10 +299,22 @@ mod tests {
             StorageKey::UserPrivateKey.format(Some(user_id)),
             "user_abc-123-def_crypto_privateKey"
         );
+        assert_eq!(
+            StorageKey::UserOrganizationKeys.format(Some(user_id)),
+            "user_abc-123-def_crypto_organizationKeys"
+        );
         assert_eq!(
             StorageKey::UserKdfConfig.format(Some(user_id)),
             "user_abc-123-def_kdfConfig_kdfConfig"
         );
+        assert_eq!(
+            StorageKey::UserPinState.format(Some(user_id)),
+            "user_abc-123-def_pinUnlock_pinState"
+        );
+        assert_eq!(
+            StorageKey::UserForwarders.format(Some(user_id)),
+            "user_abc-123-def_generator_forwarders"
+        );
     }
 
     #[test]
@@ -256,6 +330,7 @@ mod tests {
         assert!(!StorageKey::GlobalAppId.requires_user_id());
         assert!(!StorageKey::GlobalAccounts.requires_user_id());
         assert!(!StorageKey::GlobalActiveAccountId.requires_user_id());
+        assert!(!StorageKey::GlobalEnvironment.requires_user_id());
         assert!(!StorageKey::DeviceId.requires_user_id());
 
         // User keys
@@ -263,5 +338,7 @@ mod tests {
         assert!(StorageKey::UserRefreshToken.requires_user_id());
         assert!(StorageKey::UserPrivateKey.requires_user_id());
         assert!(StorageKey::UserKdfConfig.requires_user_id());
+        assert!(StorageKey::UserPinState.requires_user_id());
+        assert!(StorageKey::UserForwarders.requires_user_id());
     }
 }
@@ -0,0 +1,137 @@
+//! Password strength estimation
+//!
+//! Uses zxcvbn, like the web vault and the SDK. The account email and other
+//! context such as the item name are passed as user inputs, so passwords
+//! built from them are penalized.
+
+use serde::Serialize;
+use zxcvbn::zxcvbn;
+
+/// Inputs the web vault always penalizes
+const GLOBAL_INPUTS: [&str; 3] = ["bitwarden", "bit", "warden"];
+
+/// Strength estimate for a password
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PasswordStrength {
+    /// Score from 0 (too guessable) to 4 (very unguessable)
+    pub score: u8,
+    /// Estimated number of guesses needed, as a base 10 logarithm
+    pub guesses_log10: f64,
+    /// Estimated crack time against offline slow hashing, e.g. "3 hours"
+    pub crack_time: String,
+    pub crack_time_seconds: f64,
+    pub warning: Option<String>,
+    pub suggestions: Vec<String>,
+}
+
+impl PasswordStrength {
+    /// Whether the score is below 3, which the web vault reports as weak
+    pub fn is_weak(&self) -> bool {
+        self.score < 3
+    }
+}
+
+/// Estimate the strength of a password
+///
+/// # Arguments
+/// * `password` - Password to estimate
+/// * `email` - Account email; its local part is split into penalized words
+/// * `additional_inputs` - Other penalized words, e.g. the item name or username
+pub fn estimate_strength(
+    password: &str,
+    email: Option<&str>,
+    additional_inputs: &[&str],
+) -> PasswordStrength {
+    // 1. Collect the penalized inputs, lowercased and split into words
+    let mut inputs: Vec<String> = GLOBAL_INPUTS.iter().map(|s| s.to_string()).collect();
+    if let Some(email) = email {
+        inputs.extend(split_input(email_local_part(email)));
+    }
+    for input in additional_inputs {
+        inputs.extend(split_input(input));
+    }
+    let inputs: Vec<&str> = inputs.iter().map(String::as_str).collect();
+
+    // 2. Estimate
+    let entropy = zxcvbn(password, &inputs);
+    let crack_time = entropy.crack_times().offline_slow_hashing_1e4_per_second();
+    let (warning, suggestions) = match entropy.feedback() {
+        Some(feedback) => (
+            feedback.warning().map(|w| w.to_string()),
+            feedback
+                .suggestions()
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
+        ),
+        None => (None, Vec::new()),
+    };
+
+    PasswordStrength {
+        score: u8::from(entropy.score()),
+        guesses_log10: entropy.guesses_log10(),
+        crack_time: crack_time.to_string(),
+        crack_time_seconds: f64::from(crack_time),
+        warning,
+        suggestions,
+    }
+}
+
+fn email_local_part(email: &str) -> &str {
+    email.split_once('@').map_or(email, |(local, _)| local)
+}
+
+/// Lowercase an input and split it into words, keeping the whole input too
+fn split_input(input: &str) -> Vec<String> {
+    let input = input.trim().to_lowercase();
+    let mut words: Vec<String> = input
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_string)
+        .collect();
+    if words.len() > 1 {
+        words.push(input);
+    }
+    words
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_common_password_is_weak() {
+        let strength = estimate_strength("password123", None, &[]);
+
+        assert!(strength.is_weak());
+        assert!(strength.warning.is_some() || !strength.suggestions.is_empty());
+    }
+
+    #[test]
+    fn test_random_password_is_strong() {
+        let strength = estimate_strength("vT8#qLm2!xR9&wZp", None, &[]);
+
+        assert_eq!(strength.score, 4);
+        assert!(!strength.is_weak());
+    }
+
+    #[test]
+    fn test_email_and_item_name_are_penalized() {
+        let password = "jsmithgithub2024";
+        let unrelated = estimate_strength(password, Some("someone@example.com"), &["Email"]);
+
+        let related = estimate_strength(password, Some("j.smith@example.com"), &["GitHub"]);
+
+        assert!(related.guesses_log10 < unrelated.guesses_log10);
+    }
+
+    #[test]
+    fn test_split_input() {
+        assert_eq!(
+            split_input("John.Smith"),
+            vec!["john", "smith", "john.smith"]
+        );
+        assert_eq!(email_local_part("john@example.com"), "john");
+    }
+}
@@ -4,9 +4,10 @@
 
 use super::errors::VaultError;
 use bitwarden_collections::collection::{Collection, CollectionView};
-use bitwarden_core::Client;
+use bitwarden_core::{Client, OrganizationId};
 use bitwarden_vault::{
-    Cipher, CipherListView, CipherView, EncryptionContext, Folder, FolderView, VaultClientExt,
+    AttachmentEncryptResult, AttachmentView, Cipher, CipherListView, CipherView, EncryptionContext,
+    Folder, FolderView, VaultClientExt,
 };
 use std::sync::Arc;
 
@@ -71,6 +72,23 @@ impl CipherService {
             .map_err(|e| VaultError::EncryptionError(e.to_string()))
     }
 
+    /// Re-key a decrypted cipher for an organization
+    ///
+    /// Sets the organization ID and re-encrypts the cipher key and attachment
+    /// keys with the organization key. Fails for attachments without their
+    /// own key, which have to be re-uploaded first.
+    pub fn move_to_organization(
+        &self,
+        cipher_view: CipherView,
+        organization_id: OrganizationId,
+    ) -> Result<CipherView, VaultError> {
+        self.sdk_client
+            .vault()
+            .ciphers()
+            .move_to_organization(cipher_view, organization_id)
+            .map_err(|e| VaultError::EncryptionError(e.to_string()))
+    }
+
     /// Encrypt a folder view for API submission
     pub fn encrypt_folder(&self, folder_view: FolderView) -> Result<Folder, VaultError> {
         self.sdk_client
@@ -79,4 +97,35 @@ impl CipherService {
             .encrypt(folder_view)
             .map_err(|e| VaultError::EncryptionError(e.to_string()))
     }
+
+    /// Encrypt attachment contents with a new per-attachment key
+    ///
+    /// Returns the encrypted attachment metadata (file name and key) along
+    /// with the encrypted contents to upload.
+    pub fn encrypt_attachment(
+        &self,
+        cipher: Cipher,
+        attachment: AttachmentView,
+        contents: &[u8],
+    ) -> Result<AttachmentEncryptResult, VaultError> {
+        self.sdk_client
+            .vault()
+            .attachments()
+            .encrypt_buffer(cipher, attachment, contents)
+            .map_err(|e| VaultError::EncryptionError(e.to_string()))
+    }
+
+    /// Decrypt downloaded attachment contents with the attachment key
+    pub fn decrypt_attachment(
+        &self,
+        cipher: Cipher,
+        attachment: AttachmentView,
+        encrypted_contents: &[u8],
+    ) -> Result<Vec<u8>, VaultError> {
+        self.sdk_client
+            .vault()
+            .attachments()
+            .decrypt_buffer(cipher, attachment, encrypted_contents)
+            .map_err(|e| VaultError::DecryptionError(e.to_string()))
+    }
 }
@@ -23,6 +23,18 @@ impl ConfirmationService {
         self.prompt_yes_no("Are you sure you want to permanently delete this item? [y/N]: ")
     }
 
+    /// Confirm an organization member after showing their fingerprint phrase
+    pub fn confirm_member(&self, member: &str, fingerprint: &str) -> Result<bool, VaultError> {
+        if self.no_interaction {
+            return Ok(true); // Auto-confirm in non-interactive mode
+        }
+
+        println!("Fingerprint phrase for {}: {}", member, fingerprint);
+        self.prompt_yes_no(
+            "Confirm this member only if the phrase matches the one they see. Continue? [y/N]: ",
+        )
+    }
+
     /// Generic yes/no prompt
     fn prompt_yes_no(&self, message: &str) -> Result<bool, VaultError> {
         print!("{}", message);
@@ -1,6 +1,7 @@
 //! Vault service error types
 
 use crate::models::vault::ValidationError;
+use crate::services::key_service::KeyServiceError;
 use thiserror::Error;
 
 /// Vault service errors
@@ -27,9 +28,27 @@ pub enum VaultError {
     #[error("Folder not found")]
     FolderNotFound,
 
+    #[error("Collection not found")]
+    CollectionNotFound,
+
+    #[error("Organization not found: {0}")]
+    OrganizationNotFound(String),
+
+    #[error("Member not found")]
+    MemberNotFound,
+
+    #[error("Member is not in the accepted state")]
+    MemberNotAccepted,
+
     #[error("Item is not in trash")]
     ItemNotDeleted,
 
+    #[error("Attachment not found")]
+    AttachmentNotFound,
+
+    #[error("File is too large. Maximum attachment size is {} MB.", .max / (1024 * 1024))]
+    AttachmentTooLarge { size: u64, max: u64 },
+
     #[error("Validation error: {0}")]
     ValidationError(#[from] ValidationError),
 
@@ -56,4 +75,7 @@ pub enum VaultError {
 
     #[error("IO error: {0}")]
     IoError(String),
+
+    #[error(transparent)]
+    Key(#[from] KeyServiceError),
 }
@@ -2,20 +2,25 @@
 //!
 //! Provides high-level vault operations coordinating between storage, API client, and SDK.
 
+use crate::models::api::{AttachmentResponse, UserPublicKeyResponse};
 use crate::models::vault::{
-    Cipher, CipherListView, CipherView, Collection, CollectionView, Folder, FolderView,
+    Cipher, CipherListView, CipherType, CipherView, Collection, CollectionView, Folder, FolderView,
     Organization, OrganizationId,
 };
-use crate::services::api::BitwardenApiClient;
+use crate::services::api::{ApiClient, BitwardenApiClient, endpoints};
+use crate::services::crypto;
 use crate::services::storage::{AccountManager, JsonFileStorage, Storage, StorageKey};
 use bitwarden_core::Client;
 use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::Mutex;
+use tracing::warn;
 
 pub mod cipher_service;
 pub mod confirmation_service;
 pub mod errors;
+pub mod organization_service;
+pub mod report_service;
 pub mod search_service;
 pub mod sync_service;
 pub mod totp_service;
@@ -25,11 +30,16 @@ pub mod write_service;
 pub use cipher_service::CipherService;
 pub use confirmation_service::ConfirmationService;
 pub use errors::VaultError;
+pub use organization_service::{OrganizationService, PendingMemberConfirmation};
+pub use report_service::{
+    ExposedPasswordItem, OldPasswordItem, ReportItem, ReportService, ReusedPasswordGroup,
+    WeakPasswordItem,
+};
 pub use search_service::{ItemFilters, SearchService};
 pub use sync_service::SyncService;
 pub use totp_service::TotpService;
 pub use validation_service::ValidationService;
-pub use write_service::WriteService;
+pub use write_service::{MAX_ATTACHMENT_SIZE, WriteService};
 
 /// Field types for extraction
 #[derive(Debug, Clone, Copy)]
@@ -40,8 +50,16 @@ pub enum FieldType {
     Notes,
 }
 
+/// A decrypted attachment
+#[derive(Debug, Clone)]
+pub struct DownloadedAttachment {
+    pub file_name: String,
+    pub contents: Vec<u8>,
+}
+
 /// Main vault service coordinating all vault operations
 pub struct VaultService {
+    api_client: Arc<BitwardenApiClient>,
     sync_service: SyncService,
     cipher_service: CipherService,
     search_service: SearchService,
@@ -64,6 +82,7 @@ impl VaultService {
         let totp_service = TotpService::new();
 
         Self {
+            api_client,
             sync_service,
             cipher_service,
             search_service,
@@ -105,6 +124,81 @@ impl VaultService {
         self.cipher_service.decrypt_ciphers(cipher_vec)
     }
 
+    /// List fully decrypted logins matching the filters, for reports
+    ///
+    /// The cipher cache is loaded once for all items. A login that fails to
+    /// decrypt is skipped with a warning instead of failing the whole list.
+    ///
+    /// # Arguments
+    /// * `filters` - Filters to apply before decrypting
+    /// * `_session` - BW_SESSION key (SDK handles keys internally)
+    pub async fn list_login_views(
+        &self,
+        filters: &ItemFilters,
+        _session: &str,
+    ) -> Result<Vec<CipherView>, VaultError> {
+        let ciphers = self.get_ciphers().await?;
+        let filtered = self.search_service.filter_ciphers(&ciphers, filters);
+
+        Ok(filtered
+            .into_iter()
+            .filter(|(_, cipher)| cipher.r#type == CipherType::Login)
+            .filter_map(|(id, cipher)| {
+                self.cipher_service
+                    .decrypt_cipher(cipher)
+                    .inspect_err(|e| warn!("Skipping item {} that failed to decrypt: {}", id, e))
+                    .ok()
+            })
+            .collect())
+    }
+
+    /// List fully decrypted items for export
+    ///
+    /// Deleted items are excluded. Without an organization ID only personal
+    /// items are returned; with one, only that organization's items.
+    ///
+    /// # Arguments
+    /// * `organization_id` - Optional organization ID to scope the results
+    /// * `_session` - BW_SESSION key (SDK handles keys internally)
+    pub async fn list_item_views(
+        &self,
+        organization_id: Option<&str>,
+        _session: &str,
+    ) -> Result<Vec<CipherView>, VaultError> {
+        let ciphers = self.get_ciphers().await?;
+
+        ciphers
+            .into_values()
+            .filter(|c| c.deleted_date.is_none())
+            .filter(|c| match organization_id {
+                Some(org_id) => c
+                    .organization_id
+                    .as_ref()
+                    .is_some_and(|id| id.to_string().eq_ignore_ascii_case(org_id)),
+                None => c.organization_id.is_none(),
+            })
+            .map(|c| self.cipher_service.decrypt_cipher(c))
+            .collect()
+    }
+
+    /// List personal items as stored (still encrypted), for account-restricted export
+    ///
+    /// Deleted and organization-owned items are excluded.
+    pub async fn list_encrypted_items(&self) -> Result<Vec<Cipher>, VaultError> {
+        let ciphers = self.get_ciphers().await?;
+
+        Ok(ciphers
+            .into_values()
+            .filter(|c| c.deleted_date.is_none() && c.organization_id.is_none())
+            .collect())
+    }
+
+    /// List folders as stored (still encrypted), for account-restricted export
+    pub async fn list_encrypted_folders(&self) -> Result<Vec<Folder>, VaultError> {
+        let folders = self.get_folders().await?;
+        Ok(folders.into_values().collect())
+    }
+
     /// List all folders
     ///
     /// # Arguments
@@ -228,6 +322,106 @@ impl VaultService {
         self.totp_service.generate_code(totp_secret).await
     }
 
+    /// Download and decrypt an attachment
+    ///
+    /// # Arguments
+    /// * `item_id` - ID of the item owning the attachment
+    /// * `id_or_name` - Attachment ID, or file name (case-insensitive)
+    /// * `_session` - BW_SESSION key (SDK handles keys internally)
+    pub async fn get_attachment(
+        &self,
+        item_id: &str,
+        id_or_name: &str,
+        _session: &str,
+    ) -> Result<DownloadedAttachment, VaultError> {
+        // 1. Find the attachment on the decrypted item
+        let ciphers = self.get_ciphers().await?;
+        let cipher = ciphers
+            .get(item_id)
+            .cloned()
+            .ok_or(VaultError::ItemNotFound)?;
+        let cipher_view = self.cipher_service.decrypt_cipher(cipher.clone())?;
+
+        let attachments = cipher_view.attachments.unwrap_or_default();
+        let attachment = match attachments
+            .iter()
+            .find(|a| a.id.as_deref() == Some(id_or_name))
+        {
+            Some(attachment) => attachment.clone(),
+            None => {
+                let mut matches = attachments.iter().filter(|a| {
+                    a.file_name
+                        .as_deref()
+                        .is_some_and(|n| n.eq_ignore_ascii_case(id_or_name))
+                });
+                match (matches.next(), matches.next()) {
+                    (Some(attachment), None) => attachment.clone(),
+                    (Some(_), Some(_)) => {
+                        return Err(VaultError::InvalidInput(format!(
+                            "More than one attachment is named '{}'. Use the attachment ID instead.",
+                            id_or_name
+                        )));
+                    }
+                    _ => return Err(VaultError::AttachmentNotFound),
+                }
+            }
+        };
+        let attachment_id = attachment
+            .id
+            .clone()
+            .ok_or(VaultError::AttachmentNotFound)?;
+
+        // 2. Get a fresh download URL
+        let response: AttachmentResponse = self
+            .api_client
+            .get_with_auth(&endpoints::api::ciphers::attachment(
+                item_id,
+                &attachment_id,
+            ))
+            .await
+            .map_err(|e| VaultError::ApiError(e.to_string()))?;
+
+        // 3. Download and decrypt with the attachment key
+        let encrypted = self
+            .api_client
+            .download(&response.url)
+            .await
+            .map_err(|e| VaultError::ApiError(format!("Attachment download failed: {}", e)))?;
+        let file_name = attachment
+            .file_name
+            .clone()
+            .unwrap_or_else(|| attachment_id.clone());
+        let contents = self
+            .cipher_service
+            .decrypt_attachment(cipher, attachment, &encrypted)?;
+
+        Ok(DownloadedAttachment {
+            file_name,
+            contents,
+        })
+    }
+
+    /// Get the fingerprint phrase of a user's public key
+    ///
+    /// # Arguments
+    /// * `user_id` - User ID, or `me` for the active account
+    pub async fn get_fingerprint(&self, user_id: &str) -> Result<String, VaultError> {
+        let user_id = if user_id.eq_ignore_ascii_case("me") {
+            self.get_user_id().await?
+        } else {
+            user_id.to_string()
+        };
+
+        let response: UserPublicKeyResponse = self
+            .api_client
+            .get_with_auth(&endpoints::api::users::public_key(&user_id))
+            .await
+            .map_err(|e| VaultError::ApiError(e.to_string()))?;
+
+        crypto::fingerprint_phrase(&user_id, &response.public_key)
+            .map_err(|e| VaultError::EncryptionError(e.to_string()))
+    }
+
     // Helper methods
 
     /// Get the active user ID
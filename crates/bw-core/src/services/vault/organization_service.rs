@@ -0,0 +1,486 @@
+//! Organization administration service
+//!
+//! Manages organization collections and members through the organization
+//! endpoints, which (unlike sync) include collections the user can manage but
+//! isn't assigned to. Collection names are encrypted with the organization
+//! key; confirming a member shares that key with them.
+
+use super::VaultError;
+use crate::models::api::{
+    CollectionRequest, CollectionResponse, ListResponse, OrganizationUserConfirmRequest,
+    UserPublicKeyResponse,
+};
+use crate::models::vault::{Collection, OrgCollectionView, Organization, OrganizationMember};
+use crate::services::api::{ApiClient, ApiError, BitwardenApiClient, endpoints};
+use crate::services::crypto;
+use crate::services::key_service::KeyService;
+use crate::services::storage::{AccountManager, JsonFileStorage, Storage, StorageKey};
+use bitwarden_api_api::models::CollectionDetailsResponseModel;
+use bitwarden_crypto::SymmetricCryptoKey;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+/// An accepted member whose public key has been fetched but not yet trusted
+///
+/// Returned by `OrganizationService::prepare_member_confirmation` so the
+/// fingerprint phrase can be verified before calling
+/// `OrganizationService::confirm_member`.
+#[derive(Debug)]
+pub struct PendingMemberConfirmation {
+    member: OrganizationMember,
+    public_key: String,
+    fingerprint: String,
+}
+
+impl PendingMemberConfirmation {
+    /// The member being confirmed
+    pub fn member(&self) -> &OrganizationMember {
+        &self.member
+    }
+
+    /// Fingerprint phrase of the member's public key
+    pub fn fingerprint(&self) -> &str {
+        &self.fingerprint
+    }
+}
+
+/// Service for organization administration
+pub struct OrganizationService {
+    api_client: Arc<BitwardenApiClient>,
+    storage: Arc<Mutex<JsonFileStorage>>,
+    account_manager: Arc<AccountManager>,
+    key_service: KeyService,
+}
+
+impl OrganizationService {
+    /// Create new organization service
+    pub fn new(
+        api_client: Arc<BitwardenApiClient>,
+        storage: Arc<Mutex<JsonFileStorage>>,
+        account_manager: Arc<AccountManager>,
+    ) -> Self {
+        let key_service = KeyService::new(Arc::clone(&storage), Arc::clone(&account_manager));
+
+        Self {
+            api_client,
+            storage,
+            account_manager,
+            key_service,
+        }
+    }
+
+    // ========== Collection Operations ==========
+
+    /// List all collections in an organization, sorted by name
+    pub async fn list_collections(
+        &self,
+        organization_id: &str,
+        session: &str,
+    ) -> Result<Vec<OrgCollectionView>, VaultError> {
+        let org_key = self.get_organization_key(organization_id, session).await?;
+
+        let response: ListResponse<CollectionResponse> = self
+            .api_client
+            .get_with_auth(&endpoints::api::organizations::collections(organization_id))
+            .await
+            .map_err(|e| VaultError::ApiError(e.to_string()))?;
+
+        let mut views = response
+            .data
+            .iter()
+            .map(|c| decrypt_collection(c, &org_key))
+            .collect::<Result<Vec<_>, _>>()?;
+        views.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()));
+
+        Ok(views)
+    }
+
+    /// Create a collection, with optional group and member access
+    pub async fn create_collection(
+        &self,
+        organization_id: &str,
+        view: OrgCollectionView,
+        session: &str,
+    ) -> Result<OrgCollectionView, VaultError> {
+        // 1. Validate and encrypt
+        let org_key = self.get_organization_key(organization_id, session).await?;
+        let request = build_request(organization_id, view, &org_key)?;
+
+        // 2. Send to API
+        let response: serde_json::Value = self
+            .api_client
+            .post_with_auth(
+                &endpoints::api::organizations::collections(organization_id),
+                &request,
+            )
+            .await
+            .map_err(|e| VaultError::ApiError(e.to_string()))?;
+
+        // 3. Update cache and return decrypted view
+        self.save_collection_to_cache(organization_id, response.clone())
+            .await?;
+        decrypt_response(response, &org_key)
+    }
+
+    /// Replace a collection's name, external ID and access
+    pub async fn update_collection(
+        &self,
+        organization_id: &str,
+        id: &str,
+        view: OrgCollectionView,
+        session: &str,
+    ) -> Result<OrgCollectionView, VaultError> {
+        // 1. Validate and encrypt
+        let org_key = self.get_organization_key(organization_id, session).await?;
+        let request = build_request(organization_id, view, &org_key)?;
+
+        // 2. Send to API
+        let response: serde_json::Value = self
+            .api_client
+            .put_with_auth(
+                &endpoints::api::organizations::collection(organization_id, id),
+                &request,
+            )
+            .await
+            .map_err(map_collection_error)?;
+
+        // 3. Update cache and return decrypted view
+        self.save_collection_to_cache(organization_id, response.clone())
+            .await?;
+        decrypt_response(response, &org_key)
+    }
+
+    /// Delete a collection
+    ///
+    /// Items in the collection are not deleted.
+    pub async fn delete_collection(
+        &self,
+        organization_id: &str,
+        id: &str,
+    ) -> Result<(), VaultError> {
+        self.get_organization(organization_id).await?;
+
+        self.api_client
+            .delete_with_auth(&endpoints::api::organizations::collection(
+                organization_id,
+                id,
+            ))
+            .await
+            .map_err(map_collection_error)?;
+
+        self.remove_collection_from_cache(id).await
+    }
+
+    // ========== Member Operations ==========
+
+    /// List an organization's members
+    pub async fn list_members(
+        &self,
+        organization_id: &str,
+    ) -> Result<Vec<OrganizationMember>, VaultError> {
+        self.get_organization(organization_id).await?;
+
+        let response: ListResponse<OrganizationMember> = self
+            .api_client
+            .get_with_auth(&endpoints::api::organizations::users(organization_id))
+            .await
+            .map_err(|e| VaultError::ApiError(e.to_string()))?;
+
+        Ok(response.data)
+    }
+
+    /// Fetch an accepted member's public key and its fingerprint phrase
+    pub async fn prepare_member_confirmation(
+        &self,
+        organization_id: &str,
+        id: &str,
+    ) -> Result<PendingMemberConfirmation, VaultError> {
+        self.get_organization(organization_id).await?;
+
+        // 1. Only accepted members can be confirmed
+        let member: OrganizationMember = self
+            .api_client
+            .get_with_auth(&endpoints::api::organizations::user(organization_id, id))
+            .await
+            .map_err(|e| match e.downcast_ref::<ApiError>() {
+                Some(ApiError::NotFound { .. }) => VaultError::MemberNotFound,
+                _ => VaultError::ApiError(e.to_string()),
+            })?;
+        if member.status != OrganizationMember::STATUS_ACCEPTED {
+            return Err(VaultError::MemberNotAccepted);
+        }
+        let user_id = member
+            .user_id
+            .clone()
+            .ok_or(VaultError::MemberNotAccepted)?;
+
+        // 2. Fetch their public key and fingerprint it
+        let response: UserPublicKeyResponse = self
+            .api_client
+            .get_with_auth(&endpoints::api::users::public_key(&user_id))
+            .await
+            .map_err(|e| VaultError::ApiError(e.to_string()))?;
+        let fingerprint = crypto::fingerprint_phrase(&user_id, &response.public_key)
+            .map_err(|e| VaultError::EncryptionError(e.to_string()))?;
+
+        Ok(PendingMemberConfirmation {
+            member,
+            public_key: response.public_key,
+            fingerprint,
+        })
+    }
+
+    /// Confirm a member by encrypting the organization key to their public key
+    pub async fn confirm_member(
+        &self,
+        organization_id: &str,
+        pending: &PendingMemberConfirmation,
+        session: &str,
+    ) -> Result<(), VaultError> {
+        let org_key = self.get_organization_key(organization_id, session).await?;
+        let request = OrganizationUserConfirmRequest {
+            key: crypto::encapsulate_key(&org_key, &pending.public_key)
+                .map_err(|e| VaultError::EncryptionError(e.to_string()))?,
+        };
+
+        self.api_client
+            .post_with_auth_no_response(
+                &endpoints::api::organizations::confirm_user(organization_id, &pending.member.id),
+                &request,
+            )
+            .await
+            .map_err(|e| VaultError::ApiError(e.to_string()))
+    }
+
+    // ========== Keys ==========
+
+    async fn get_organization(&self, organization_id: &str) -> Result<Organization, VaultError> {
+        let user_id = self.get_user_id().await?;
+        let storage = self.storage.lock().await;
+
+        storage
+            .get::<HashMap<String, Organization>>(
+                &StorageKey::UserOrganizations.format(Some(&user_id)),
+            )
+            .map_err(|e| VaultError::StorageError(e.to_string()))?
+            .ok_or(VaultError::NotSynced)?
+            .remove(organization_id)
+            .ok_or_else(|| VaultError::OrganizationNotFound(organization_id.to_string()))
+    }
+
+    async fn get_organization_key(
+        &self,
+        organization_id: &str,
+        session: &str,
+    ) -> Result<SymmetricCryptoKey, VaultError> {
+        self.get_organization(organization_id).await?;
+        Ok(self
+            .key_service
+            .get_organization_key(session, organization_id)
+            .await?)
+    }
+
+    // ========== Cache Management ==========
+
+    async fn get_user_id(&self) -> Result<String, VaultError> {
+        self.account_manager
+            .get_active_user_id()
+            .await
+            .map_err(|e| VaultError::StorageError(e.to_string()))?
+            .ok_or(VaultError::NotAuthenticated)
+    }
+
+    /// Store a collection response in the synced collections
+    ///
+    /// Sync only returns collections the user is assigned to, so a collection
+    /// without the user in its member access is left out, or removed when an
+    /// edit took the user off it. Collections reached through a group come
+    /// back on the next sync.
+    async fn save_collection_to_cache(
+        &self,
+        organization_id: &str,
+        response: serde_json::Value,
+    ) -> Result<(), VaultError> {
+        let organization = self.get_organization(organization_id).await?;
+        let assigned = is_assigned(&response, organization.organization_user_id.as_deref());
+
+        let model: CollectionDetailsResponseModel = serde_json::from_value(response)
+            .map_err(|e| VaultError::ApiError(format!("Failed to parse collection: {}", e)))?;
+        let collection = Collection::try_from(model)
+            .map_err(|e| VaultError::ApiError(format!("Failed to parse collection: {}", e)))?;
+        let Some(id) = collection.id.as_ref().map(ToString::to_string) else {
+            return Ok(());
+        };
+        if !assigned {
+            return self.remove_collection_from_cache(&id).await;
+        }
+
+        let user_id = self.get_user_id().await?;
+        let key = StorageKey::UserCollections.format(Some(&user_id));
+        let mut storage = self.storage.lock().await;
+
+        let mut collections: HashMap<String, Collection> = storage
+            .get(&key)
+            .map_err(|e| VaultError::StorageError(e.to_string()))?
+            .unwrap_or_default();
+        collections.insert(id, collection);
+
+        storage
+            .set(&key, &collections)
+            .await
+            .map_err(|e| VaultError::StorageError(e.to_string()))
+    }
+
+    async fn remove_collection_from_cache(&self, id: &str) -> Result<(), VaultError> {
+        let user_id = self.get_user_id().await?;
+        let key = StorageKey::UserCollections.format(Some(&user_id));
+        let mut storage = self.storage.lock().await;
+
+        let mut collections: HashMap<String, Collection> = storage
+            .get(&key)
+            .map_err(|e| VaultError::StorageError(e.to_string()))?
+            .unwrap_or_default();
+        if collections.remove(id).is_none() {
+            return Ok(());
+        }
+
+        storage
+            .set(&key, &collections)
+            .await
+            .map_err(|e| VaultError::StorageError(e.to_string()))
+    }
+}
+
+/// Validate a collection view and encrypt it for the API
+fn build_request(
+    organization_id: &str,
+    view: OrgCollectionView,
+    org_key: &SymmetricCryptoKey,
+) -> Result<CollectionRequest, VaultError> {
+    if let Some(view_org_id) = &view.organization_id {
+        if !view_org_id.eq_ignore_ascii_case(organization_id) {
+            return Err(VaultError::InvalidInput(
+                "organizationId does not match --organizationid".to_string(),
+            ));
+        }
+    }
+    if view.name.trim().is_empty() {
+        return Err(VaultError::InvalidInput(
+            "Collection name is required".to_string(),
+        ));
+    }
+
+    Ok(CollectionRequest {
+        name: crypto::encrypt_string(&view.name, org_key)
+            .map_err(|e| VaultError::EncryptionError(e.to_string()))?,
+        external_id: view.external_id,
+        groups: view.groups.unwrap_or_default(),
+        users: view.users.unwrap_or_default(),
+    })
+}
+
+fn decrypt_response(
+    response: serde_json::Value,
+    org_key: &SymmetricCryptoKey,
+) -> Result<OrgCollectionView, VaultError> {
+    let collection: CollectionResponse = serde_json::from_value(response)
+        .map_err(|e| VaultError::ApiError(format!("Failed to parse collection: {}", e)))?;
+    decrypt_collection(&collection, org_key)
+}
+
+fn decrypt_collection(
+    collection: &CollectionResponse,
+    org_key: &SymmetricCryptoKey,
+) -> Result<OrgCollectionView, VaultError> {
+    Ok(OrgCollectionView {
+        id: Some(collection.id.clone()),
+        organization_id: Some(collection.organization_id.clone()),
+        name: crypto::decrypt_string(&collection.name, org_key, None)
+            .map_err(|e| VaultError::DecryptionError(e.to_string()))?,
+        external_id: collection.external_id.clone(),
+        groups: collection.groups.clone(),
+        users: collection.users.clone(),
+    })
+}
+
+/// Whether a collection response gives the member direct access
+fn is_assigned(response: &serde_json::Value, organization_user_id: Option<&str>) -> bool {
+    let Some(organization_user_id) = organization_user_id else {
+        return false;
+    };
+    response["users"].as_array().is_some_and(|users| {
+        users
+            .iter()
+            .any(|user| user["id"].as_str() == Some(organization_user_id))
+    })
+}
+
+fn map_collection_error(error: anyhow::Error) -> VaultError {
+    match error.downcast_ref::<ApiError>() {
+        Some(ApiError::NotFound { .. }) => VaultError::CollectionNotFound,
+        _ => VaultError::ApiError(error.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::vault::CollectionAccessSelection;
+
+    fn view(name: &str, organization_id: Option<&str>) -> OrgCollectionView {
+        OrgCollectionView {
+            id: None,
+            organization_id: organization_id.map(String::from),
+            name: name.to_string(),
+            external_id: None,
+            groups: Some(vec![CollectionAccessSelection {
+                id: "group-1".to_string(),
+                read_only: true,
+                hide_passwords: false,
+                manage: false,
+            }]),
+            users: None,
+        }
+    }
+
+    #[test]
+    fn test_build_request_encrypts_name() {
+        let key = SymmetricCryptoKey::make_aes256_cbc_hmac_key();
+
+        let request = build_request("org-1", view("Engineering", Some("org-1")), &key).unwrap();
+
+        assert_ne!(request.name, "Engineering");
+        assert_eq!(
+            crypto::decrypt_string(&request.name, &key, None).unwrap(),
+            "Engineering"
+        );
+        assert_eq!(request.groups.len(), 1);
+        assert!(request.users.is_empty());
+    }
+
+    #[test]
+    fn test_is_assigned_checks_member_access() {
+        let response = serde_json::json!({
+            "id": "collection-1",
+            "users": [{ "id": "org-user-1", "readOnly": false }]
+        });
+
+        assert!(is_assigned(&response, Some("org-user-1")));
+        assert!(!is_assigned(&response, Some("org-user-2")));
+        assert!(!is_assigned(&response, None));
+        assert!(!is_assigned(
+            &serde_json::json!({ "id": "collection-1" }),
+            Some("org-user-1")
+        ));
+    }
+
+    #[test]
+    fn test_build_request_rejects_mismatched_organization() {
+        let key = SymmetricCryptoKey::make_aes256_cbc_hmac_key();
+
+        let result = build_request("org-1", view("Engineering", Some("org-2")), &key);
+
+        assert!(matches!(result, Err(VaultError::InvalidInput(_))));
+    }
+}
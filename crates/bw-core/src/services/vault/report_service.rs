@@ -0,0 +1,342 @@
+//! Vault health reports
+//!
+//! Finds weak, reused, old and exposed login passwords. Every login in scope
+//! is decrypted, so reports require an unlocked vault.
+
+use super::{ItemFilters, ValidationService, VaultError, VaultService};
+use crate::models::vault::{CipherType, CipherView};
+use crate::services::hibp::HibpClient;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// A login found by a report
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportItem {
+    pub id: String,
+    pub name: String,
+    pub username: Option<String>,
+    pub organization_id: Option<String>,
+}
+
+/// A login with a weak password
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WeakPasswordItem {
+    #[serde(flatten)]
+    pub item: ReportItem,
+    /// Strength score from 0 to 4
+    pub score: u8,
+    pub crack_time: String,
+    pub warning: Option<String>,
+}
+
+/// Logins sharing the same password
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ReusedPasswordGroup {
+    pub count: usize,
+    pub items: Vec<ReportItem>,
+}
+
+/// A login whose password hasn't changed in a while
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct OldPasswordItem {
+    #[serde(flatten)]
+    pub item: ReportItem,
+    /// When the password was last changed, or the item created if never
+    pub password_revision_date: DateTime<Utc>,
+    pub days: i64,
+}
+
+/// A login whose password appears in known data breaches
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ExposedPasswordItem {
+    #[serde(flatten)]
+    pub item: ReportItem,
+    /// Number of times the password appears in breaches
+    pub count: u64,
+}
+
+/// A decrypted login with a password, as used by the reports
+#[derive(Debug, Clone)]
+pub struct ReportLogin {
+    pub item: ReportItem,
+    pub password: String,
+    pub password_revision_date: DateTime<Utc>,
+}
+
+impl ReportLogin {
+    /// Build from a decrypted cipher; `None` for non-logins and logins
+    /// without a password
+    pub fn from_cipher(cipher: &CipherView) -> Option<Self> {
+        if cipher.r#type != CipherType::Login || cipher.deleted_date.is_some() {
+            return None;
+        }
+        let login = cipher.login.as_ref()?;
+        let password = login.password.clone().filter(|p| !p.is_empty())?;
+
+        Some(Self {
+            item: ReportItem {
+                id: cipher
+                    .id
+                    .as_ref()
+                    .map(|id| id.to_string())
+                    .unwrap_or_default(),
+                name: cipher.name.clone(),
+                username: login.username.clone(),
+                organization_id: cipher.organization_id.as_ref().map(|id| id.to_string()),
+            },
+            password,
+            password_revision_date: login.password_revision_date.unwrap_or(cipher.creation_date),
+        })
+    }
+}
+
+/// Service for vault health reports
+pub struct ReportService {
+    vault_service: Arc<VaultService>,
+    validation_service: Arc<ValidationService>,
+}
+
+impl ReportService {
+    /// Create new report service
+    pub fn new(
+        vault_service: Arc<VaultService>,
+        validation_service: Arc<ValidationService>,
+    ) -> Self {
+        Self {
+            vault_service,
+            validation_service,
+        }
+    }
+
+    /// Report logins whose password strength score is below 3
+    ///
+    /// # Arguments
+    /// * `organization_id` - Optional organization ID to scope the report
+    /// * `email` - Account email, penalized like the item name and username
+    /// * `session` - BW_SESSION key for decryption
+    pub async fn weak_passwords(
+        &self,
+        organization_id: Option<&str>,
+        email: Option<&str>,
+        session: &str,
+    ) -> Result<Vec<WeakPasswordItem>, VaultError> {
+        let mut weak = Vec::new();
+        for cipher in self.list_logins(organization_id, session).await? {
+            let Some(login) = ReportLogin::from_cipher(&cipher) else {
+                continue;
+            };
+            let Some(strength) = self.validation_service.password_strength(&cipher, email) else {
+                continue;
+            };
+            if strength.is_weak() {
+                weak.push(WeakPasswordItem {
+                    item: login.item,
+                    score: strength.score,
+                    crack_time: strength.crack_time,
+                    warning: strength.warning,
+                });
+            }
+        }
+
+        // Weakest first
+        weak.sort_by(|a, b| {
+            a.score
+                .cmp(&b.score)
+                .then_with(|| a.item.name.cmp(&b.item.name))
+        });
+        Ok(weak)
+    }
+
+    /// Report passwords used by more than one login
+    pub async fn reused_passwords(
+        &self,
+        organization_id: Option<&str>,
+        session: &str,
+    ) -> Result<Vec<ReusedPasswordGroup>, VaultError> {
+        let logins = self.list_report_logins(organization_id, session).await?;
+        Ok(find_reused_passwords(logins))
+    }
+
+    /// Report passwords unchanged for more than `days` days
+    pub async fn old_passwords(
+        &self,
+        organization_id: Option<&str>,
+        days: u32,
+        session: &str,
+    ) -> Result<Vec<OldPasswordItem>, VaultError> {
+        let logins = self.list_report_logins(organization_id, session).await?;
+        Ok(find_old_passwords(logins, days, Utc::now()))
+    }
+
+    /// Report passwords found in Have I Been Pwned
+    ///
+    /// Only hash prefixes leave the machine, and each distinct prefix is
+    /// requested once.
+    pub async fn exposed_passwords(
+        &self,
+        organization_id: Option<&str>,
+        hibp_client: &HibpClient,
+        session: &str,
+    ) -> Result<Vec<ExposedPasswordItem>, VaultError> {
+        let logins = self.list_report_logins(organization_id, session).await?;
+        let passwords: Vec<&str> = logins.iter().map(|l| l.password.as_str()).collect();
+        let counts = hibp_client
+            .breach_counts(&passwords)
+            .await
+            .map_err(|e| VaultError::ApiError(e.to_string()))?;
+
+        let mut exposed: Vec<ExposedPasswordItem> = logins
+            .into_iter()
+            .zip(counts)
+            .filter(|(_, count)| *count > 0)
+            .map(|(login, count)| ExposedPasswordItem {
+                item: login.item,
+                count,
+            })
+            .collect();
+
+        // Most exposed first
+        exposed.sort_by(|a, b| b.count.cmp(&a.count));
+        Ok(exposed)
+    }
+
+    /// Decrypt every login in scope
+    async fn list_logins(
+        &self,
+        organization_id: Option<&str>,
+        session: &str,
+    ) -> Result<Vec<CipherView>, VaultError> {
+        let filters = ItemFilters {
+            organization_id: organization_id.map(str::to_string),
+            ..Default::default()
+        };
+
+        // List views don't include passwords, so logins are decrypted in full
+        self.vault_service.list_login_views(&filters, session).await
+    }
+
+    async fn list_report_logins(
+        &self,
+        organization_id: Option<&str>,
+        session: &str,
+    ) -> Result<Vec<ReportLogin>, VaultError> {
+        Ok(self
+            .list_logins(organization_id, session)
+            .await?
+            .iter()
+            .filter_map(ReportLogin::from_cipher)
+            .collect())
+    }
+}
+
+/// Group logins by password hash, keeping groups of two or more
+///
+/// Largest groups first.
+pub fn find_reused_passwords(logins: Vec<ReportLogin>) -> Vec<ReusedPasswordGroup> {
+    let mut groups: HashMap<Vec<u8>, Vec<ReportItem>> = HashMap::new();
+    for login in logins {
+        let hash = Sha256::digest(login.password.as_bytes()).to_vec();
+        groups.entry(hash).or_default().push(login.item);
+    }
+
+    let mut reused: Vec<ReusedPasswordGroup> = groups
+        .into_values()
+        .filter(|items| items.len() > 1)
+        .map(|mut items| {
+            items.sort_by(|a, b| a.name.cmp(&b.name));
+            ReusedPasswordGroup {
+                count: items.len(),
+                items,
+            }
+        })
+        .collect();
+    reused.sort_by(|a, b| {
+        b.count
+            .cmp(&a.count)
+            .then_with(|| a.items[0].name.cmp(&b.items[0].name))
+    });
+    reused
+}
+
+/// Find logins whose password is older than `days` days at `now`
+///
+/// Oldest first.
+pub fn find_old_passwords(
+    logins: Vec<ReportLogin>,
+    days: u32,
+    now: DateTime<Utc>,
+) -> Vec<OldPasswordItem> {
+    let mut old: Vec<OldPasswordItem> = logins
+        .into_iter()
+        .filter_map(|login| {
+            let age = (now - login.password_revision_date).num_days();
+            (age > i64::from(days)).then_some(OldPasswordItem {
+                item: login.item,
+                password_revision_date: login.password_revision_date,
+                days: age,
+            })
+        })
+        .collect();
+    old.sort_by(|a, b| b.days.cmp(&a.days));
+    old
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn login(name: &str, password: &str, changed: DateTime<Utc>) -> ReportLogin {
+        ReportLogin {
+            item: ReportItem {
+                id: format!("{}-id", name),
+                name: name.to_string(),
+                username: None,
+                organization_id: None,
+            },
+            password: password.to_string(),
+            password_revision_date: changed,
+        }
+    }
+
+    #[test]
+    fn test_find_reused_passwords() {
+        let now = Utc::now();
+        let logins = vec![
+            login("GitHub", "hunter2", now),
+            login("Email", "unique", now),
+            login("Bank", "hunter2", now),
+        ];
+
+        let reused = find_reused_passwords(logins);
+
+        assert_eq!(reused.len(), 1);
+        assert_eq!(reused[0].count, 2);
+        let names: Vec<&str> = reused[0].items.iter().map(|i| i.name.as_str()).collect();
+        assert_eq!(names, vec!["Bank", "GitHub"]);
+    }
+
+    #[test]
+    fn test_find_old_passwords() {
+        let now = Utc::now();
+        let logins = vec![
+            login("Recent", "a", now - Duration::days(10)),
+            login("Old", "b", now - Duration::days(200)),
+            login("Older", "c", now - Duration::days(400)),
+        ];
+
+        let old = find_old_passwords(logins, 90, now);
+
+        let names: Vec<&str> = old.iter().map(|i| i.item.name.as_str()).collect();
+        assert_eq!(names, vec!["Older", "Old"]);
+        assert_eq!(old[0].days, 400);
+    }
+}
@@ -4,12 +4,14 @@
 //! Uses TypeScript CLI compatible flat storage format with user-namespaced keys.
 
 use super::errors::VaultError;
-use crate::models::vault::{parse_sync_response, SyncResponseModel};
+use crate::models::send::Send;
+use crate::models::vault::{Organization, SyncResponseModel, parse_sync_response};
 use crate::services::api::{ApiClient, BitwardenApiClient, endpoints};
 use crate::services::storage::{AccountManager, JsonFileStorage, Storage, StorageKey};
 use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::Mutex;
+use tracing::warn;
 
 /// Service for vault synchronization operations
 pub struct SyncService {
@@ -47,13 +49,16 @@ impl SyncService {
             .ok_or(VaultError::NotAuthenticated)?;
 
         // Fetch vault data from API using SDK API model
-        let sync_response: SyncResponseModel = self
+        let mut sync_response: SyncResponseModel = self
             .api_client
             .get_with_auth(endpoints::api::SYNC)
             .await
             .map_err(|e| VaultError::ApiError(e.to_string()))?;
 
-        // Parse into SDK domain types
+        // Key material lives on the profile and Sends have their own models;
+        // the rest is parsed into SDK domain types
+        let profile = sync_response.profile.take();
+        let sends = sync_response.sends.take().unwrap_or_default();
         let sync_data = parse_sync_response(sync_response)
             .map_err(|e| VaultError::ApiError(format!("Failed to parse sync response: {}", e)))?;
 
@@ -104,6 +109,76 @@ impl SyncService {
             .await
             .map_err(|e| VaultError::StorageError(e.to_string()))?;
 
+        // Convert Sends to the CLI model, keyed by ID
+        let mut sends_map: HashMap<String, Send> = HashMap::new();
+        // One unreadable Send shouldn't fail the whole sync
+        for send in &sends {
+            match serde_json::to_value(send).and_then(serde_json::from_value::<Send>) {
+                Ok(send) => {
+                    sends_map.insert(send.id.clone(), send);
+                }
+                Err(e) => warn!(
+                    "Skipping Send {} that failed to parse: {}",
+                    send.id.map(|id| id.to_string()).unwrap_or_default(),
+                    e
+                ),
+            }
+        }
+        storage
+            .set(&StorageKey::UserSends.format(Some(&user_id)), &sends_map)
+            .await
+            .map_err(|e| VaultError::StorageError(e.to_string()))?;
+
+        // Store the encrypted private key and organization keys for org decryption
+        if let Some(profile) = profile {
+            if let Some(private_key) = profile.private_key {
+                storage
+                    .set(
+                        &StorageKey::UserPrivateKey.format(Some(&user_id)),
+                        &private_key,
+                    )
+                    .await
+                    .map_err(|e| VaultError::StorageError(e.to_string()))?;
+            }
+
+            let organizations = profile.organizations.unwrap_or_default();
+
+            // Convert organizations to the CLI model, keyed by ID
+            let mut organizations_map: HashMap<String, Organization> = HashMap::new();
+            // One unreadable organization shouldn't fail the whole sync
+            for org in &organizations {
+                match serde_json::to_value(org).and_then(serde_json::from_value::<Organization>) {
+                    Ok(org) => {
+                        organizations_map.insert(org.id.clone(), org);
+                    }
+                    Err(e) => warn!(
+                        "Skipping organization {} that failed to parse: {}",
+                        org.id.map(|id| id.to_string()).unwrap_or_default(),
+                        e
+                    ),
+                }
+            }
+            storage
+                .set(
+                    &StorageKey::UserOrganizations.format(Some(&user_id)),
+                    &organizations_map,
+                )
+                .await
+                .map_err(|e| VaultError::StorageError(e.to_string()))?;
+
+            let org_keys: HashMap<String, String> = organizations
+                .into_iter()
+                .filter_map(|o| Some((o.id?.to_string(), o.key?)))
+                .collect();
+            storage
+                .set(
+                    &StorageKey::UserOrganizationKeys.format(Some(&user_id)),
+                    &org_keys,
+                )
+                .await
+                .map_err(|e| VaultError::StorageError(e.to_string()))?;
+        }
+
         storage
             .set(&StorageKey::UserLastSync.format(Some(&user_id)), &now)
             .await
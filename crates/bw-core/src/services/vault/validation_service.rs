@@ -1,6 +1,7 @@
 //! Input validation service for vault write operations
 
 use crate::models::vault::{CipherType, CipherView, ValidationError};
+use crate::services::{PasswordStrength, estimate_strength};
 use regex::Regex;
 
 /// Field length limits for vault items
@@ -84,6 +85,27 @@ impl ValidationService {
         Ok(())
     }
 
+    /// Estimate the strength of a login's password
+    ///
+    /// The item name and username are penalized along with the account
+    /// email, as the web vault does. Returns `None` for items without a
+    /// password.
+    pub fn password_strength(
+        &self,
+        cipher: &CipherView,
+        email: Option<&str>,
+    ) -> Option<PasswordStrength> {
+        let login = cipher.login.as_ref()?;
+        let password = login.password.as_deref().filter(|p| !p.is_empty())?;
+
+        let mut inputs = vec![cipher.name.as_str()];
+        if let Some(username) = &login.username {
+            inputs.push(username);
+        }
+
+        Some(estimate_strength(password, email, &inputs))
+    }
+
     // Private validation helpers
 
     fn validate_required_fields(&self, cipher: &CipherView) -> Result<(), ValidationError> {
@@ -6,9 +6,13 @@
 //! NOTE: Write operations require the SDK Client to be initialized with keys.
 
 use super::{CipherService, ConfirmationService, ValidationService, VaultError};
+use crate::models::api::{
+    AttachmentResponse, AttachmentUploadDataResponse, AttachmentUploadRequest, CipherShareRequest,
+    FileUploadType,
+};
 use crate::models::vault::{
-    Cipher, CipherId, CipherRequestModel, CipherView, Folder, FolderId, FolderRequestModel,
-    FolderView,
+    AttachmentView, Cipher, CipherCollectionsRequestModel, CipherId, CipherRequestModel,
+    CipherView, Collection, Folder, FolderId, FolderRequestModel, FolderView, OrganizationId,
 };
 use crate::services::api::{ApiClient, BitwardenApiClient, endpoints};
 use crate::services::storage::{AccountManager, JsonFileStorage, Storage, StorageKey};
@@ -17,6 +21,9 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
+/// Largest attachment the server accepts (500 MB)
+pub const MAX_ATTACHMENT_SIZE: u64 = 500 * 1024 * 1024;
+
 /// Service for vault write operations (create, update, delete)
 pub struct WriteService {
     api_client: Arc<BitwardenApiClient>,
@@ -234,6 +241,344 @@ impl WriteService {
         self.update_cipher(cipher_id, cipher_view, session).await
     }
 
+    /// Replace the collections an organization cipher is assigned to
+    pub async fn update_cipher_collections(
+        &self,
+        cipher_id: &str,
+        collection_ids: Vec<String>,
+    ) -> Result<Cipher, VaultError> {
+        // 1. Only organization items can be assigned to collections
+        let cipher = self.get_cipher(cipher_id).await?;
+        if cipher.deleted_date.is_some() {
+            return Err(VaultError::InvalidInput(
+                "Cannot edit items in trash. Use 'bw restore' first.".to_string(),
+            ));
+        }
+        let organization_id = cipher
+            .organization_id
+            .map(|id| id.to_string())
+            .ok_or_else(|| {
+                VaultError::InvalidInput("Item does not belong to an organization".to_string())
+            })?;
+
+        // 2. Validate every target collection
+        self.validate_collections_writable(&organization_id, &collection_ids)
+            .await?;
+
+        // 3. Send to API
+        let request = CipherCollectionsRequestModel::new(collection_ids);
+        let updated: Cipher = self
+            .api_client
+            .put_with_auth(&endpoints::api::ciphers::collections(cipher_id), &request)
+            .await
+            .map_err(|e| VaultError::ApiError(e.to_string()))?;
+
+        // 4. Update cache
+        self.update_cipher_in_cache(&updated).await?;
+
+        Ok(updated)
+    }
+
+    /// Move a personal cipher into an organization
+    ///
+    /// Re-encrypts the cipher with the organization key and assigns it to the
+    /// given collections. Attachments without their own key are re-encrypted
+    /// and re-uploaded first.
+    pub async fn share_cipher(
+        &self,
+        cipher_id: &str,
+        organization_id: &str,
+        collection_ids: Vec<String>,
+        _session: &str,
+    ) -> Result<Cipher, VaultError> {
+        // 1. Only active personal items can be shared
+        let cipher = self.get_cipher(cipher_id).await?;
+        if cipher.deleted_date.is_some() {
+            return Err(VaultError::InvalidInput(
+                "Cannot share items in trash. Use 'bw restore' first.".to_string(),
+            ));
+        }
+        if cipher.organization_id.is_some() {
+            return Err(VaultError::InvalidInput(
+                "Item already belongs to an organization".to_string(),
+            ));
+        }
+        let org_id = organization_id
+            .parse::<OrganizationId>()
+            .map_err(|_| VaultError::OrganizationNotFound(organization_id.to_string()))?;
+
+        // 2. Validate the target collections
+        if collection_ids.is_empty() {
+            return Err(VaultError::InvalidInput(
+                "At least one collection is required".to_string(),
+            ));
+        }
+        self.validate_collections_writable(organization_id, &collection_ids)
+            .await?;
+
+        // 3. Give legacy attachments their own key so they can be re-keyed
+        let mut cipher_view = self.cipher_service.decrypt_cipher(cipher.clone())?;
+        let mut legacy_uploads = Vec::new();
+        for attachment in cipher_view
+            .attachments
+            .iter_mut()
+            .flatten()
+            .filter(|a| a.key.is_none())
+        {
+            let contents = self
+                .rekey_legacy_attachment(cipher_id, &cipher, attachment)
+                .await?;
+            legacy_uploads.push((attachment.id.clone().unwrap_or_default(), contents));
+        }
+
+        // 4. Re-encrypt with the organization key
+        let cipher_view = self
+            .cipher_service
+            .move_to_organization(cipher_view, org_id)?;
+        let encryption_context = self.cipher_service.encrypt_cipher(cipher_view)?;
+        let mut shared = encryption_context.cipher.clone();
+
+        // 5. Upload legacy attachments under their new key
+        for (attachment_id, contents) in legacy_uploads {
+            let attachment = shared
+                .attachments
+                .iter()
+                .flatten()
+                .find(|a| a.id.as_deref() == Some(attachment_id.as_str()))
+                .ok_or(VaultError::AttachmentNotFound)?;
+            let (Some(key), Some(file_name)) = (&attachment.key, &attachment.file_name) else {
+                return Err(VaultError::EncryptionError(
+                    "Attachment key or file name missing after encryption".to_string(),
+                ));
+            };
+
+            self.api_client
+                .post_multipart_fields_with_auth(
+                    &endpoints::api::ciphers::attachment_share(
+                        cipher_id,
+                        &attachment_id,
+                        organization_id,
+                    ),
+                    &[("key", key.to_string())],
+                    &file_name.to_string(),
+                    contents,
+                )
+                .await
+                .map_err(|e| VaultError::ApiError(format!("Attachment upload failed: {}", e)))?;
+        }
+
+        // 6. Send to API
+        let request = CipherShareRequest {
+            cipher: encryption_context.into(),
+            collection_ids: collection_ids.clone(),
+        };
+        let response: serde_json::Value = self
+            .api_client
+            .put_with_auth(&endpoints::api::ciphers::share(cipher_id), &request)
+            .await
+            .map_err(|e| VaultError::ApiError(e.to_string()))?;
+
+        // 7. Update cache with the new organization and collections
+        shared.collection_ids = collection_ids
+            .iter()
+            .filter_map(|id| id.parse().ok())
+            .collect();
+        if let Some(revision_date) = response
+            .get("revisionDate")
+            .and_then(|d| d.as_str())
+            .and_then(|d| d.parse().ok())
+        {
+            shared.revision_date = revision_date;
+        }
+        self.update_cipher_in_cache(&shared).await?;
+
+        Ok(shared)
+    }
+
+    // ========== Attachment Operations ==========
+
+    /// Encrypt and upload a file as an attachment on a cipher
+    ///
+    /// Returns the updated cipher including the new attachment.
+    pub async fn create_attachment(
+        &self,
+        cipher_id: &str,
+        file_name: &str,
+        contents: Vec<u8>,
+        _session: &str,
+    ) -> Result<Cipher, VaultError> {
+        // 1. Check size limit
+        let size = contents.len() as u64;
+        if size > MAX_ATTACHMENT_SIZE {
+            return Err(VaultError::AttachmentTooLarge {
+                size,
+                max: MAX_ATTACHMENT_SIZE,
+            });
+        }
+
+        // 2. Get current cipher
+        let cipher = self.get_cipher(cipher_id).await?;
+        if cipher.deleted_date.is_some() {
+            return Err(VaultError::InvalidInput(
+                "Cannot add attachments to items in trash".to_string(),
+            ));
+        }
+
+        // 3. Encrypt contents and file name with a new attachment key
+        let attachment_view: AttachmentView = serde_json::from_value(serde_json::json!({
+            "id": null,
+            "url": null,
+            "size": size.to_string(),
+            "sizeName": null,
+            "fileName": file_name,
+            "key": null,
+        }))
+        .map_err(|e| VaultError::InvalidInput(e.to_string()))?;
+        let encrypted =
+            self.cipher_service
+                .encrypt_attachment(cipher, attachment_view, &contents)?;
+        let (Some(key), Some(encrypted_name)) =
+            (encrypted.attachment.key, encrypted.attachment.file_name)
+        else {
+            return Err(VaultError::EncryptionError(
+                "Attachment key or file name missing after encryption".to_string(),
+            ));
+        };
+
+        // 4. Request an upload slot
+        let request = AttachmentUploadRequest {
+            key: key.to_string(),
+            file_name: encrypted_name.to_string(),
+            file_size: encrypted.contents.len() as u64,
+            admin_request: false,
+        };
+        let upload: AttachmentUploadDataResponse = self
+            .api_client
+            .post_with_auth(&endpoints::api::ciphers::attachment_v2(cipher_id), &request)
+            .await
+            .map_err(|e| VaultError::ApiError(e.to_string()))?;
+
+        // 5. Upload the encrypted contents
+        let uploaded = match (upload.file_upload_type, upload.url.as_deref()) {
+            (FileUploadType::Azure, Some(url)) => {
+                self.api_client.put_blob(url, encrypted.contents).await
+            }
+            (FileUploadType::Azure, None) => Err(anyhow::anyhow!("No upload URL for attachment")),
+            (FileUploadType::Direct, _) => {
+                self.api_client
+                    .post_multipart_with_auth(
+                        &endpoints::api::ciphers::attachment(cipher_id, &upload.attachment_id),
+                        &encrypted_name.to_string(),
+                        encrypted.contents,
+                    )
+                    .await
+            }
+        };
+
+        if let Err(e) = uploaded {
+            // Remove the pending attachment so the item isn't left with an empty file
+            let _ = self
+                .api_client
+                .delete_with_auth(&endpoints::api::ciphers::attachment(
+                    cipher_id,
+                    &upload.attachment_id,
+                ))
+                .await;
+            return Err(VaultError::ApiError(format!(
+                "Attachment upload failed: {}",
+                e
+            )));
+        }
+
+        // 6. Update cache
+        let updated = upload.cipher_response.ok_or_else(|| {
+            VaultError::ApiError("Server did not return the updated item".to_string())
+        })?;
+        self.update_cipher_in_cache(&updated).await?;
+
+        Ok(updated)
+    }
+
+    /// Delete an attachment from a cipher
+    pub async fn delete_attachment(
+        &self,
+        cipher_id: &str,
+        attachment_id: &str,
+    ) -> Result<(), VaultError> {
+        // 1. Validate attachment exists
+        let mut cipher = self.get_cipher(cipher_id).await?;
+        let attachments = cipher
+            .attachments
+            .as_mut()
+            .ok_or(VaultError::AttachmentNotFound)?;
+        let index = attachments
+            .iter()
+            .position(|a| a.id.as_deref() == Some(attachment_id))
+            .ok_or(VaultError::AttachmentNotFound)?;
+
+        // 2. Send delete to API
+        self.api_client
+            .delete_with_auth(&endpoints::api::ciphers::attachment(
+                cipher_id,
+                attachment_id,
+            ))
+            .await
+            .map_err(|e| VaultError::ApiError(e.to_string()))?;
+
+        // 3. Update cache
+        attachments.remove(index);
+        self.update_cipher_in_cache(&cipher).await
+    }
+
+    /// Re-encrypt an attachment that predates per-attachment keys
+    ///
+    /// Downloads and decrypts the contents, then encrypts them with a new
+    /// attachment key which is set on `attachment`. Returns the encrypted
+    /// contents to upload once the cipher has been re-keyed.
+    async fn rekey_legacy_attachment(
+        &self,
+        cipher_id: &str,
+        cipher: &Cipher,
+        attachment: &mut AttachmentView,
+    ) -> Result<Vec<u8>, VaultError> {
+        let attachment_id = attachment
+            .id
+            .clone()
+            .ok_or(VaultError::AttachmentNotFound)?;
+
+        // 1. Download and decrypt the current contents
+        let response: AttachmentResponse = self
+            .api_client
+            .get_with_auth(&endpoints::api::ciphers::attachment(
+                cipher_id,
+                &attachment_id,
+            ))
+            .await
+            .map_err(|e| VaultError::ApiError(e.to_string()))?;
+        let encrypted = self
+            .api_client
+            .download(&response.url)
+            .await
+            .map_err(|e| VaultError::ApiError(format!("Attachment download failed: {}", e)))?;
+        let contents = self.cipher_service.decrypt_attachment(
+            cipher.clone(),
+            attachment.clone(),
+            &encrypted,
+        )?;
+
+        // 2. Encrypt with a new attachment key
+        let encrypted = self.cipher_service.encrypt_attachment(
+            cipher.clone(),
+            attachment.clone(),
+            &contents,
+        )?;
+        attachment.key = Some(encrypted.attachment.key.ok_or_else(|| {
+            VaultError::EncryptionError("Attachment key missing after encryption".to_string())
+        })?);
+
+        Ok(encrypted.contents)
+    }
+
     // ========== Folder Operations ==========
 
     /// Create folder
@@ -593,6 +938,36 @@ impl WriteService {
         Ok(())
     }
 
+    /// Ensure each collection belongs to the organization and isn't read-only
+    async fn validate_collections_writable(
+        &self,
+        organization_id: &str,
+        collection_ids: &[String],
+    ) -> Result<(), VaultError> {
+        let user_id = self.get_user_id().await?;
+        let storage = self.storage.lock().await;
+        let collections: HashMap<String, Collection> = storage
+            .get(&StorageKey::UserCollections.format(Some(&user_id)))
+            .map_err(|e| VaultError::StorageError(e.to_string()))?
+            .ok_or(VaultError::NotSynced)?;
+
+        for id in collection_ids {
+            let collection = collections
+                .get(id)
+                .filter(|c| c.organization_id.to_string() == organization_id)
+                .ok_or(VaultError::CollectionNotFound)?;
+
+            if collection.read_only && !collection.manage {
+                return Err(VaultError::PermissionDenied(format!(
+                    "collection {} is read-only",
+                    id
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
     async fn get_cipher(&self, id: &str) -> Result<Cipher, VaultError> {
         let user_id = self.get_user_id().await?;
         let storage = self.storage.lock().await;
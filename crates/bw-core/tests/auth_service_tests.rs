@@ -3,9 +3,13 @@
 //! Tests the authentication service with mock HTTP server and real storage
 
 use bitwarden_crypto::{Kdf, MasterKey};
+use bw_core::models::auth::{TwoFactorData, TwoFactorMethod};
+use bw_core::models::state::{MAX_PIN_ATTEMPTS, PinState};
 use bw_core::services::{
     api::{BitwardenApiClient, Environment},
-    auth::{AuthError, AuthService},
+    auth::{
+        AuthError, AuthService, PkceParams, SsoCallbackListener, SsoDecryption, build_authorize_url,
+    },
     storage::{JsonFileStorage, Storage, StorageKey},
 };
 use secrecy::Secret;
@@ -15,7 +19,7 @@ use tempfile::tempdir;
 use tokio::sync::Mutex;
 use wiremock::{
     Mock, MockServer, ResponseTemplate,
-    matchers::{body_string_contains, method, path},
+    matchers::{body_string_contains, header, method, path, path_regex},
 };
 
 /// Test credentials - these are only used in tests, not real credentials
@@ -196,6 +200,128 @@ async fn test_login_with_password_invalid_credentials() {
     }
 }
 
+#[tokio::test]
+async fn test_login_with_password_two_factor_required() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/identity/accounts/prelogin"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "kdf": 0,
+            "kdfIterations": TEST_KDF_ITERATIONS,
+        })))
+        .mount(&mock_server)
+        .await;
+
+    // Identity server lists the account's 2FA providers
+    Mock::given(method("POST"))
+        .and(path("/identity/connect/token"))
+        .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+            "error": "invalid_grant",
+            "error_description": "Two factor required.",
+            "TwoFactorProviders": ["0", "1"],
+            "TwoFactorProviders2": {
+                "0": null,
+                "1": { "Email": "t***@example.com" }
+            }
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let (auth_service, _storage, _temp_dir) = setup_test_auth_service(mock_server.uri()).await;
+
+    let result = auth_service
+        .login_with_password(
+            TEST_EMAIL,
+            Secret::new(TEST_PASSWORD.to_string()),
+            None,
+            None,
+        )
+        .await;
+
+    match result.unwrap_err() {
+        AuthError::TwoFactorRequired { providers } => {
+            assert_eq!(
+                providers,
+                vec![TwoFactorMethod::Authenticator, TwoFactorMethod::Email]
+            );
+        }
+        other => panic!("Expected TwoFactorRequired error, got: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_login_with_password_remembers_two_factor_token() {
+    let encrypted_user_key =
+        generate_test_encrypted_user_key(TEST_PASSWORD, TEST_EMAIL, TEST_KDF_ITERATIONS);
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/identity/accounts/prelogin"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "kdf": 0,
+            "kdfIterations": TEST_KDF_ITERATIONS,
+        })))
+        .mount(&mock_server)
+        .await;
+
+    // Successful 2FA login with remember=1 returns a device token
+    Mock::given(method("POST"))
+        .and(path("/identity/connect/token"))
+        .and(body_string_contains("twoFactorRemember=1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "access_token": "test_access_token",
+            "expires_in": 3600,
+            "token_type": "Bearer",
+            "refresh_token": "test_refresh_token",
+            "Key": encrypted_user_key,
+            "Kdf": 0,
+            "KdfIterations": TEST_KDF_ITERATIONS,
+            "ResetMasterPassword": false,
+            "TwoFactorToken": "remember_token_123",
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/accounts/profile"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "user_id_123",
+            "name": "Test User",
+            "email": TEST_EMAIL,
+            "emailVerified": true,
+            "premium": false,
+            "securityStamp": "security_stamp_123",
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let (auth_service, storage, _temp_dir) = setup_test_auth_service(mock_server.uri()).await;
+
+    let result = auth_service
+        .login_with_password(
+            TEST_EMAIL,
+            Secret::new(TEST_PASSWORD.to_string()),
+            Some(TwoFactorData {
+                token: "123456".to_string(),
+                provider: TwoFactorMethod::Authenticator.to_provider_code(),
+                remember: true,
+            }),
+            None,
+        )
+        .await;
+    assert!(result.is_ok(), "Login should succeed: {:?}", result.err());
+
+    let storage = storage.lock().await;
+    let tokens: Option<std::collections::HashMap<String, String>> = storage
+        .get(&StorageKey::GlobalTwoFactorTokens.format(None))
+        .unwrap();
+    assert_eq!(
+        tokens.unwrap().get(TEST_EMAIL).map(String::as_str),
+        Some("remember_token_123")
+    );
+}
+
 #[tokio::test]
 async fn test_login_with_api_key_success() {
     let mock_server = MockServer::start().await;
@@ -252,6 +378,391 @@ async fn test_login_with_api_key_success() {
     assert!(!login_result.session_key.is_empty());
 }
 
+/// Mock the profile endpoint for SSO tests
+async fn setup_profile_mock(mock_server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path("/api/accounts/profile"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "sso_user_id",
+            "name": "SSO User",
+            "email": TEST_EMAIL,
+            "emailVerified": true,
+            "premium": false,
+            "securityStamp": "sso_security_stamp",
+        })))
+        .mount(mock_server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_sso_login_round_trip_with_master_password() {
+    let encrypted_user_key =
+        generate_test_encrypted_user_key(TEST_PASSWORD, TEST_EMAIL, TEST_KDF_ITERATIONS);
+    let mock_server = MockServer::start().await;
+
+    // 1. Start the loopback listener and build the authorize URL
+    let pkce = PkceParams::generate();
+    let listener = SsoCallbackListener::bind()
+        .await
+        .expect("Failed to bind listener");
+    let redirect_uri = listener.redirect_uri();
+    let authorize_url = build_authorize_url(&mock_server.uri(), &redirect_uri, &pkce, Some("acme"));
+    assert!(authorize_url.contains(&format!("codeChallenge={}", pkce.code_challenge)));
+    assert!(authorize_url.contains("identifier=acme"));
+
+    // Identity server only accepts the code with the matching verifier
+    Mock::given(method("POST"))
+        .and(path("/identity/connect/token"))
+        .and(body_string_contains("grant_type=authorization_code"))
+        .and(body_string_contains("code=test_auth_code"))
+        .and(body_string_contains(format!(
+            "code_verifier={}",
+            pkce.code_verifier
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "access_token": "sso_access_token",
+            "expires_in": 3600,
+            "token_type": "Bearer",
+            "refresh_token": "sso_refresh_token",
+            "Key": encrypted_user_key,
+            "Kdf": 0,
+            "KdfIterations": TEST_KDF_ITERATIONS,
+            "ResetMasterPassword": false,
+            "UserDecryptionOptions": {
+                "HasMasterPassword": true,
+                "Object": "userDecryptionOptions"
+            },
+        })))
+        .mount(&mock_server)
+        .await;
+    setup_profile_mock(&mock_server).await;
+
+    // 2. Simulate the browser redirect after the identity provider login
+    let callback = format!("{}/?code=test_auth_code&state={}", redirect_uri, pkce.state);
+    let browser = tokio::spawn(async move { reqwest::get(callback).await.unwrap().status() });
+
+    let code = listener
+        .wait_for_code(&pkce.state)
+        .await
+        .expect("Should receive authorization code");
+    assert_eq!(code, "test_auth_code");
+    assert!(browser.await.unwrap().is_success());
+
+    // 3. Exchange the code and unlock with the master password
+    let (auth_service, storage, _temp_dir) = setup_test_auth_service(mock_server.uri()).await;
+
+    let pending = auth_service
+        .login_with_sso(&code, &pkce.code_verifier, &redirect_uri, None)
+        .await
+        .expect("Code exchange should succeed");
+    assert!(pending.requires_master_password());
+    assert_eq!(pending.email(), TEST_EMAIL);
+
+    let result = auth_service
+        .complete_sso_login(pending, Some(Secret::new(TEST_PASSWORD.to_string())))
+        .await
+        .expect("SSO login should succeed");
+    assert_eq!(result.user_id, "sso_user_id");
+    assert!(!result.session_key.is_empty());
+
+    let storage = storage.lock().await;
+    let access_token: Option<String> = storage
+        .get(&StorageKey::UserAccessToken.format(Some("sso_user_id")))
+        .unwrap();
+    assert_eq!(access_token.as_deref(), Some("sso_access_token"));
+}
+
+#[tokio::test]
+async fn test_sso_login_with_key_connector() {
+    use base64::Engine;
+    use rand::RngCore;
+
+    // Key Connector users have a random master key rather than a password
+    let mut master_key_bytes = [0u8; 32];
+    rand::thread_rng().fill_bytes(&mut master_key_bytes);
+    let master_key_b64 = base64::engine::general_purpose::STANDARD.encode(master_key_bytes);
+    let master_key = MasterKey::try_from(master_key_bytes.to_vec().as_mut_slice()).unwrap();
+    let (_user_key, encrypted_user_key) = master_key.make_user_key().unwrap();
+
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/identity/connect/token"))
+        .and(body_string_contains("grant_type=authorization_code"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "access_token": "sso_access_token",
+            "expires_in": 3600,
+            "token_type": "Bearer",
+            "refresh_token": "sso_refresh_token",
+            "Key": encrypted_user_key.to_string(),
+            "Kdf": 0,
+            "KdfIterations": TEST_KDF_ITERATIONS,
+            "ResetMasterPassword": false,
+            "UserDecryptionOptions": {
+                "HasMasterPassword": false,
+                "KeyConnectorOption": {
+                    "KeyConnectorUrl": format!("{}/key-connector", mock_server.uri())
+                }
+            },
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/key-connector/user-keys"))
+        .and(header("Authorization", "Bearer sso_access_token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "key": master_key_b64,
+        })))
+        .mount(&mock_server)
+        .await;
+    setup_profile_mock(&mock_server).await;
+
+    let (auth_service, _storage, _temp_dir) = setup_test_auth_service(mock_server.uri()).await;
+
+    let pending = auth_service
+        .login_with_sso(
+            "test_auth_code",
+            "test_verifier",
+            "http://localhost:8065",
+            None,
+        )
+        .await
+        .expect("Code exchange should succeed");
+    assert!(!pending.requires_master_password());
+
+    let result = auth_service.complete_sso_login(pending, None).await;
+    assert!(
+        result.is_ok(),
+        "Key Connector login should succeed: {:?}",
+        result.err()
+    );
+}
+
+#[tokio::test]
+async fn test_sso_login_with_trusted_device() {
+    use bitwarden_crypto::{DeviceKey, SymmetricCryptoKey};
+
+    // Trusting a device encrypts the user key to a new device key pair
+    let user_key = SymmetricCryptoKey::make_aes256_cbc_hmac_key();
+    let trusted = DeviceKey::trust_device(&user_key).unwrap();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/identity/connect/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "access_token": "sso_access_token",
+            "expires_in": 3600,
+            "token_type": "Bearer",
+            "refresh_token": "sso_refresh_token",
+            "Kdf": 0,
+            "KdfIterations": TEST_KDF_ITERATIONS,
+            "ResetMasterPassword": false,
+            "UserDecryptionOptions": {
+                "HasMasterPassword": false,
+                "TrustedDeviceOption": {
+                    "HasAdminApproval": false,
+                    "EncryptedPrivateKey": trusted.protected_device_private_key.to_string(),
+                    "EncryptedUserKey": trusted.protected_user_key.to_string()
+                }
+            },
+        })))
+        .mount(&mock_server)
+        .await;
+    setup_profile_mock(&mock_server).await;
+
+    let (auth_service, storage, _temp_dir) = setup_test_auth_service(mock_server.uri()).await;
+    storage
+        .lock()
+        .await
+        .set(
+            &StorageKey::UserDeviceKey.format(Some("sso_user_id")),
+            &trusted.device_key.to_string(),
+        )
+        .await
+        .unwrap();
+
+    let pending = auth_service
+        .login_with_sso(
+            "test_auth_code",
+            "test_verifier",
+            "http://localhost:8065",
+            None,
+        )
+        .await
+        .expect("Code exchange should succeed");
+    assert_eq!(pending.decryption(), &SsoDecryption::TrustedDevice);
+
+    let result = auth_service.complete_sso_login(pending, None).await;
+    assert!(
+        result.is_ok(),
+        "Trusted device login should succeed: {:?}",
+        result.err()
+    );
+}
+
+#[tokio::test]
+async fn test_sso_login_trusted_device_without_device_key() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/identity/connect/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "access_token": "sso_access_token",
+            "expires_in": 3600,
+            "token_type": "Bearer",
+            "refresh_token": "sso_refresh_token",
+            "Kdf": 0,
+            "KdfIterations": TEST_KDF_ITERATIONS,
+            "ResetMasterPassword": false,
+            "UserDecryptionOptions": {
+                "HasMasterPassword": true,
+                "TrustedDeviceOption": {
+                    "HasAdminApproval": false,
+                    "EncryptedPrivateKey": "2.aaaa|bbbb|cccc",
+                    "EncryptedUserKey": "4.aaaa"
+                }
+            },
+        })))
+        .mount(&mock_server)
+        .await;
+    setup_profile_mock(&mock_server).await;
+
+    let (auth_service, _storage, _temp_dir) = setup_test_auth_service(mock_server.uri()).await;
+
+    // Without a device key on this machine, the master password is used
+    let pending = auth_service
+        .login_with_sso(
+            "test_auth_code",
+            "test_verifier",
+            "http://localhost:8065",
+            None,
+        )
+        .await
+        .expect("Code exchange should succeed");
+    assert!(pending.requires_master_password());
+}
+
+#[tokio::test]
+async fn test_sso_login_trusts_device_after_master_password() {
+    use bitwarden_crypto::{DeviceKey, EncString, UnsignedSharedKey};
+
+    let encrypted_user_key =
+        generate_test_encrypted_user_key(TEST_PASSWORD, TEST_EMAIL, TEST_KDF_ITERATIONS);
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/identity/connect/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "access_token": "sso_access_token",
+            "expires_in": 3600,
+            "token_type": "Bearer",
+            "refresh_token": "sso_refresh_token",
+            "Key": encrypted_user_key,
+            "Kdf": 0,
+            "KdfIterations": TEST_KDF_ITERATIONS,
+            "ResetMasterPassword": false,
+            "UserDecryptionOptions": {
+                "HasMasterPassword": true,
+                "TrustedDeviceOption": {
+                    "HasAdminApproval": false
+                }
+            },
+        })))
+        .mount(&mock_server)
+        .await;
+    setup_profile_mock(&mock_server).await;
+    Mock::given(method("PUT"))
+        .and(path_regex(r"^/api/devices/[^/]+/keys$"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let (auth_service, storage, _temp_dir) = setup_test_auth_service(mock_server.uri()).await;
+
+    let pending = auth_service
+        .login_with_sso(
+            "test_auth_code",
+            "test_verifier",
+            "http://localhost:8065",
+            None,
+        )
+        .await
+        .expect("Code exchange should succeed");
+    assert!(pending.requires_master_password());
+    auth_service
+        .complete_sso_login(pending, Some(Secret::new(TEST_PASSWORD.to_string())))
+        .await
+        .expect("SSO login should succeed");
+
+    // The device key kept locally opens the keys registered with the server
+    let device_key: String = storage
+        .lock()
+        .await
+        .get(&StorageKey::UserDeviceKey.format(Some("sso_user_id")))
+        .unwrap()
+        .expect("Device key should be stored");
+
+    let requests = mock_server.received_requests().await.unwrap();
+    let keys_request = requests
+        .iter()
+        .find(|r| r.method.as_str() == "PUT")
+        .expect("Device keys should be registered");
+    let body: serde_json::Value = serde_json::from_slice(&keys_request.body).unwrap();
+    assert!(body["encryptedPublicKey"].is_string());
+
+    let private_key: EncString = body["encryptedPrivateKey"]
+        .as_str()
+        .unwrap()
+        .parse()
+        .unwrap();
+    let user_key: UnsignedSharedKey = body["encryptedUserKey"].as_str().unwrap().parse().unwrap();
+    DeviceKey::try_from(device_key)
+        .unwrap()
+        .decrypt_user_key(private_key, user_key)
+        .expect("Device key should decrypt the user key");
+}
+
+#[tokio::test]
+async fn test_sso_login_untrusted_device_without_master_password() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/identity/connect/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "access_token": "sso_access_token",
+            "expires_in": 3600,
+            "token_type": "Bearer",
+            "refresh_token": "sso_refresh_token",
+            "Kdf": 0,
+            "KdfIterations": TEST_KDF_ITERATIONS,
+            "ResetMasterPassword": false,
+            "UserDecryptionOptions": {
+                "HasMasterPassword": false,
+                "TrustedDeviceOption": {
+                    "HasAdminApproval": true,
+                    "EncryptedPrivateKey": null,
+                    "EncryptedUserKey": null
+                }
+            },
+        })))
+        .mount(&mock_server)
+        .await;
+    setup_profile_mock(&mock_server).await;
+
+    let (auth_service, _storage, _temp_dir) = setup_test_auth_service(mock_server.uri()).await;
+
+    let result = auth_service
+        .login_with_sso(
+            "test_auth_code",
+            "test_verifier",
+            "http://localhost:8065",
+            None,
+        )
+        .await;
+
+    assert!(matches!(result, Err(AuthError::Other(_))));
+}
+
 #[tokio::test]
 async fn test_unlock_success() {
     // Generate a valid encrypted user key for our test credentials
@@ -435,6 +946,234 @@ async fn test_logout_success() {
     }
 }
 
+/// Log in with the test credentials, returning the session key
+async fn log_in_for_pin_tests(auth_service: &AuthService) -> String {
+    auth_service
+        .login_with_password(
+            TEST_EMAIL,
+            Secret::new(TEST_PASSWORD.to_string()),
+            None,
+            None,
+        )
+        .await
+        .expect("Login should succeed")
+        .session_key
+}
+
+#[tokio::test]
+async fn test_pin_unlock_round_trip() {
+    let encrypted_user_key =
+        generate_test_encrypted_user_key(TEST_PASSWORD, TEST_EMAIL, TEST_KDF_ITERATIONS);
+
+    let mock_server = MockServer::start().await;
+    setup_login_mocks(&mock_server, &encrypted_user_key).await;
+
+    let (auth_service, _storage, _temp_dir) = setup_test_auth_service(mock_server.uri()).await;
+    let session = log_in_for_pin_tests(&auth_service).await;
+
+    // Unlocking with a PIN needs one to be set
+    let result = auth_service
+        .unlock_with_pin(Secret::new("1234".to_string()))
+        .await;
+    assert!(matches!(result, Err(AuthError::PinNotSet)));
+
+    auth_service
+        .set_pin(Secret::new("1234".to_string()), false, &session)
+        .await
+        .expect("Setting the PIN should succeed");
+    auth_service.lock().await.unwrap();
+
+    let unlock_data = auth_service
+        .unlock_with_pin(Secret::new("1234".to_string()))
+        .await
+        .expect("PIN unlock should succeed");
+    assert!(!unlock_data.session_key.is_empty());
+}
+
+#[tokio::test]
+async fn test_pin_locked_out_after_failed_attempts() {
+    let encrypted_user_key =
+        generate_test_encrypted_user_key(TEST_PASSWORD, TEST_EMAIL, TEST_KDF_ITERATIONS);
+
+    let mock_server = MockServer::start().await;
+    setup_login_mocks(&mock_server, &encrypted_user_key).await;
+
+    let (auth_service, _storage, _temp_dir) = setup_test_auth_service(mock_server.uri()).await;
+    let session = log_in_for_pin_tests(&auth_service).await;
+    auth_service
+        .set_pin(Secret::new("1234".to_string()), false, &session)
+        .await
+        .unwrap();
+    auth_service.lock().await.unwrap();
+
+    // 1. Wrong PINs count down the remaining attempts
+    for expected_remaining in (1..MAX_PIN_ATTEMPTS).rev() {
+        let result = auth_service
+            .unlock_with_pin(Secret::new("0000".to_string()))
+            .await;
+        match result {
+            Err(AuthError::InvalidPin { remaining }) => assert_eq!(remaining, expected_remaining),
+            other => panic!("Expected InvalidPin error, got: {:?}", other),
+        }
+    }
+
+    // 2. The last attempt locks the PIN out, even for the right PIN
+    let result = auth_service
+        .unlock_with_pin(Secret::new("0000".to_string()))
+        .await;
+    assert!(matches!(result, Err(AuthError::PinLockedOut)));
+    let result = auth_service
+        .unlock_with_pin(Secret::new("1234".to_string()))
+        .await;
+    assert!(matches!(result, Err(AuthError::PinUnavailable)));
+
+    // 3. A master password unlock re-enables the PIN
+    auth_service
+        .unlock(Secret::new(TEST_PASSWORD.to_string()))
+        .await
+        .unwrap();
+    let pin_state = auth_service.get_active_pin_state().await.unwrap().unwrap();
+    assert!(pin_state.is_available());
+    assert_eq!(pin_state.failed_attempts, 0);
+    auth_service.lock().await.unwrap();
+
+    let result = auth_service
+        .unlock_with_pin(Secret::new("1234".to_string()))
+        .await;
+    assert!(
+        result.is_ok(),
+        "PIN unlock should succeed: {:?}",
+        result.err()
+    );
+}
+
+#[tokio::test]
+async fn test_pin_requires_master_password_after_lock() {
+    let encrypted_user_key =
+        generate_test_encrypted_user_key(TEST_PASSWORD, TEST_EMAIL, TEST_KDF_ITERATIONS);
+
+    let mock_server = MockServer::start().await;
+    setup_login_mocks(&mock_server, &encrypted_user_key).await;
+
+    let (auth_service, storage, _temp_dir) = setup_test_auth_service(mock_server.uri()).await;
+    let session = log_in_for_pin_tests(&auth_service).await;
+    auth_service
+        .set_pin(Secret::new("1234".to_string()), true, &session)
+        .await
+        .unwrap();
+
+    // 1. Locking drops the PIN-protected user key
+    auth_service.lock().await.unwrap();
+    let result = auth_service
+        .unlock_with_pin(Secret::new("1234".to_string()))
+        .await;
+    assert!(matches!(result, Err(AuthError::PinUnavailable)));
+
+    // 2. The master password brings it back
+    auth_service
+        .unlock(Secret::new(TEST_PASSWORD.to_string()))
+        .await
+        .unwrap();
+    let result = auth_service
+        .unlock_with_pin(Secret::new("1234".to_string()))
+        .await;
+    assert!(
+        result.is_ok(),
+        "PIN unlock should succeed: {:?}",
+        result.err()
+    );
+
+    // 3. Logging out removes the PIN
+    auth_service.logout().await.unwrap();
+    let pin_state: Option<PinState> = storage
+        .lock()
+        .await
+        .get(&StorageKey::UserPinState.format(Some("user_id_123")))
+        .unwrap();
+    assert!(pin_state.is_none());
+}
+
+#[tokio::test]
+async fn test_pin_requires_master_password_after_restart() {
+    let encrypted_user_key =
+        generate_test_encrypted_user_key(TEST_PASSWORD, TEST_EMAIL, TEST_KDF_ITERATIONS);
+
+    let mock_server = MockServer::start().await;
+    setup_login_mocks(&mock_server, &encrypted_user_key).await;
+
+    let (auth_service, storage, _temp_dir) = setup_test_auth_service(mock_server.uri()).await;
+    let session = log_in_for_pin_tests(&auth_service).await;
+    auth_service
+        .set_pin(Secret::new("1234".to_string()), true, &session)
+        .await
+        .unwrap();
+
+    // 1. Pretend the PIN-protected user key was created before a restart
+    let pin_state_key = StorageKey::UserPinState.format(Some("user_id_123"));
+    {
+        let mut storage = storage.lock().await;
+        let mut pin_state: PinState = storage.get(&pin_state_key).unwrap().unwrap();
+        assert!(pin_state.boot_id.is_some());
+        pin_state.boot_id = Some("previous-boot".to_string());
+        storage.set(&pin_state_key, &pin_state).await.unwrap();
+    }
+    let result = auth_service
+        .unlock_with_pin(Secret::new("1234".to_string()))
+        .await;
+    assert!(matches!(result, Err(AuthError::PinUnavailable)));
+
+    // 2. The master password brings it back for the current boot
+    auth_service
+        .unlock(Secret::new(TEST_PASSWORD.to_string()))
+        .await
+        .unwrap();
+    let result = auth_service
+        .unlock_with_pin(Secret::new("1234".to_string()))
+        .await;
+    assert!(
+        result.is_ok(),
+        "PIN unlock should succeed: {:?}",
+        result.err()
+    );
+}
+
+#[tokio::test]
+async fn test_pin_unlock_with_corrupt_key_is_not_a_wrong_pin() {
+    let encrypted_user_key =
+        generate_test_encrypted_user_key(TEST_PASSWORD, TEST_EMAIL, TEST_KDF_ITERATIONS);
+
+    let mock_server = MockServer::start().await;
+    setup_login_mocks(&mock_server, &encrypted_user_key).await;
+
+    let (auth_service, storage, _temp_dir) = setup_test_auth_service(mock_server.uri()).await;
+    let session = log_in_for_pin_tests(&auth_service).await;
+    auth_service
+        .set_pin(Secret::new("1234".to_string()), false, &session)
+        .await
+        .unwrap();
+
+    let pin_state_key = StorageKey::UserPinState.format(Some("user_id_123"));
+    {
+        let mut storage = storage.lock().await;
+        let mut pin_state: PinState = storage.get(&pin_state_key).unwrap().unwrap();
+        pin_state.pin_key_encrypted_user_key = Some("not-an-enc-string".to_string());
+        storage.set(&pin_state_key, &pin_state).await.unwrap();
+    }
+
+    let result = auth_service
+        .unlock_with_pin(Secret::new("1234".to_string()))
+        .await;
+    assert!(
+        matches!(result, Err(AuthError::CryptoOperationFailed { .. })),
+        "Expected CryptoOperationFailed error, got: {:?}",
+        result
+    );
+
+    // The failure doesn't use up an attempt
+    let pin_state = auth_service.get_active_pin_state().await.unwrap().unwrap();
+    assert_eq!(pin_state.failed_attempts, 0);
+}
+
 #[tokio::test]
 async fn test_session_key_format() {
     // Generate a valid encrypted user key for our test credentials
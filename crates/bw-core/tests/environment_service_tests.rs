@@ -0,0 +1,257 @@
+//! Integration tests for EnvironmentService
+//!
+//! Tests saving the configured server, checking it against a mock server's
+//! `/api/config`, refusing changes while logged in and resolving the
+//! environment of the active account.
+
+use bw_core::models::state::EnvironmentUrls;
+use bw_core::services::api::EnvironmentService;
+use bw_core::services::storage::{AccountManager, JsonFileStorage, Storage, StorageKey};
+use std::sync::Arc;
+use tempfile::TempDir;
+use tokio::sync::Mutex;
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{method, path},
+};
+
+const TEST_USER_ID: &str = "test-user-12345";
+
+/// Create an environment service with empty storage
+///
+/// Returns the storage, the account manager and the TempDir to keep it alive
+/// for the duration of the test.
+fn setup_environment_service() -> (
+    EnvironmentService,
+    Arc<Mutex<JsonFileStorage>>,
+    Arc<AccountManager>,
+    TempDir,
+) {
+    let temp_dir = TempDir::new().unwrap();
+    let storage = Arc::new(Mutex::new(
+        JsonFileStorage::new(Some(temp_dir.path().to_path_buf())).unwrap(),
+    ));
+    let account_manager = Arc::new(AccountManager::new(Arc::clone(&storage)));
+
+    (
+        EnvironmentService::new(Arc::clone(&storage), Arc::clone(&account_manager)),
+        storage,
+        account_manager,
+        temp_dir,
+    )
+}
+
+fn self_hosted(base: &str) -> EnvironmentUrls {
+    EnvironmentUrls {
+        region: None,
+        base: Some(base.to_string()),
+        api: None,
+        identity: None,
+        web_vault: None,
+        icons: None,
+        notifications: None,
+        events: None,
+    }
+}
+
+/// Log in by storing an access token for the active account
+async fn log_in(storage: &Mutex<JsonFileStorage>, account_manager: &AccountManager) {
+    account_manager
+        .register_account(TEST_USER_ID, "test@example.com")
+        .await
+        .unwrap();
+    account_manager
+        .set_active_user_id(TEST_USER_ID)
+        .await
+        .unwrap();
+    storage
+        .lock()
+        .await
+        .set(
+            &StorageKey::UserAccessToken.format(Some(TEST_USER_ID)),
+            &"access-token".to_string(),
+        )
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_default_cloud_without_configuration() {
+    let (service, storage, _, _temp_dir) = setup_environment_service();
+
+    assert!(service.get_urls().await.unwrap().is_none());
+    let environment = EnvironmentService::load(&*storage.lock().await).unwrap();
+    assert!(environment.is_default_cloud());
+}
+
+#[tokio::test]
+async fn test_set_urls_saves_server() {
+    let (service, storage, _, _temp_dir) = setup_environment_service();
+
+    let environment = service
+        .set_urls(Some(self_hosted("https://vault.example.com")))
+        .await
+        .unwrap();
+
+    assert_eq!(environment.api_url(), "https://vault.example.com/api");
+    let urls = service.get_urls().await.unwrap().unwrap();
+    assert_eq!(urls.server_url(), Some("https://vault.example.com"));
+    let loaded = EnvironmentService::load(&*storage.lock().await).unwrap();
+    assert_eq!(loaded, environment);
+
+    // Resetting restores the default cloud
+    service.set_urls(None).await.unwrap();
+    assert!(service.get_urls().await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_set_urls_rejects_invalid_url() {
+    let (service, _, _, _temp_dir) = setup_environment_service();
+
+    let result = service
+        .set_urls(Some(self_hosted("http://vault.example.com")))
+        .await;
+
+    assert!(result.is_err());
+    assert!(service.get_urls().await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_set_urls_refused_while_logged_in() {
+    let (service, storage, account_manager, _temp_dir) = setup_environment_service();
+    log_in(&storage, &account_manager).await;
+
+    let result = service
+        .set_urls(Some(self_hosted("https://vault.example.com")))
+        .await;
+
+    let error = result.unwrap_err().to_string();
+    assert!(error.contains("Logout required"));
+}
+
+#[tokio::test]
+async fn test_configure_fetches_server_config() {
+    let (service, _, _, _temp_dir) = setup_environment_service();
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/config"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "version": "2025.1.0",
+            "gitHash": "abc123",
+            "featureStates": { "pm-1234-flag": true }
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let server_config = service
+        .configure(Some(self_hosted(&mock_server.uri())))
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(server_config.version, "2025.1.0");
+    assert_eq!(
+        server_config.feature_states.get("pm-1234-flag"),
+        Some(&serde_json::json!(true))
+    );
+    let urls = service.get_urls().await.unwrap().unwrap();
+    assert_eq!(urls.server_url(), Some(mock_server.uri().as_str()));
+}
+
+#[tokio::test]
+async fn test_configure_rejects_unknown_server() {
+    let (service, _, _, _temp_dir) = setup_environment_service();
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/config"))
+        .respond_with(ResponseTemplate::new(404))
+        .mount(&mock_server)
+        .await;
+
+    let result = service
+        .configure(Some(self_hosted(&mock_server.uri())))
+        .await;
+
+    let error = format!("{:#}", result.unwrap_err());
+    assert!(error.contains("Unable to fetch the server configuration"));
+    assert!(service.get_urls().await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_configure_default_cloud_skips_server_check() {
+    let (service, _, _, _temp_dir) = setup_environment_service();
+    service
+        .set_urls(Some(self_hosted("https://vault.example.com")))
+        .await
+        .unwrap();
+
+    // No mock server: the default cloud must not be contacted
+    let server_config = service.configure(None).await.unwrap();
+
+    assert!(server_config.is_none());
+    assert!(service.get_urls().await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_account_keeps_server_from_login() {
+    let (service, storage, account_manager, _temp_dir) = setup_environment_service();
+    service
+        .set_urls(Some(self_hosted("https://vault.example.com")))
+        .await
+        .unwrap();
+
+    // 1. Log in, copying the configured server to the account
+    log_in(&storage, &account_manager).await;
+    EnvironmentService::save_for_user(&mut *storage.lock().await, TEST_USER_ID)
+        .await
+        .unwrap();
+
+    // 2. Change the global server behind the account's back
+    storage
+        .lock()
+        .await
+        .set(
+            &StorageKey::GlobalEnvironment.format(None),
+            &self_hosted("https://other.example.com"),
+        )
+        .await
+        .unwrap();
+
+    // 3. The active account still uses its own server
+    let environment = EnvironmentService::load(&*storage.lock().await).unwrap();
+    assert_eq!(environment.base_url(), "https://vault.example.com");
+}
+
+#[tokio::test]
+async fn test_set_urls_for_scoped_account_keeps_other_account_server() {
+    let (service, storage, account_manager, _temp_dir) = setup_environment_service();
+
+    // 1. The active account logged in to the default cloud
+    log_in(&storage, &account_manager).await;
+    EnvironmentService::save_for_user(&mut *storage.lock().await, TEST_USER_ID)
+        .await
+        .unwrap();
+
+    // 2. Configure a server for another, logged out account with --account
+    account_manager
+        .register_account("other-user", "other@example.com")
+        .await
+        .unwrap();
+    storage
+        .lock()
+        .await
+        .scope_to_account(Some("other-user".to_string()));
+    service
+        .set_urls(Some(self_hosted("https://vault.example.com")))
+        .await
+        .unwrap();
+
+    // 3. The scoped account uses the new server, the active one its own
+    let environment = EnvironmentService::load(&*storage.lock().await).unwrap();
+    assert_eq!(environment.base_url(), "https://vault.example.com");
+
+    storage.lock().await.scope_to_account(None);
+    let environment = EnvironmentService::load(&*storage.lock().await).unwrap();
+    assert!(environment.is_default_cloud());
+}
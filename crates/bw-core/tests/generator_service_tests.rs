@@ -0,0 +1,490 @@
+//! Integration tests for GeneratorService
+//!
+//! Tests saved generator defaults, custom wordlist passphrases and username
+//! generation, including forwarded aliases against mock forwarding services.
+
+use bw_core::services::KeyService;
+use bw_core::services::generator::{
+    ForwarderService, ForwarderSettings, GeneratorError, GeneratorOptions, GeneratorService,
+    GeneratorType, UsernameRequest,
+};
+use bw_core::services::storage::{
+    AccountManager, JsonFileStorage, Storage, StorageKey, format_session_key, generate_session_key,
+};
+use std::sync::Arc;
+use tempfile::TempDir;
+use tokio::sync::Mutex;
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{header, method, path},
+};
+
+const TEST_USER_ID: &str = "test-user-12345";
+
+/// Create a generator service with empty storage and no logged in account
+///
+/// Returns the account manager and the TempDir to keep it alive for the
+/// duration of the test.
+fn setup_generator_service() -> (GeneratorService, Arc<AccountManager>, TempDir) {
+    let temp_dir = TempDir::new().unwrap();
+    let storage = Arc::new(Mutex::new(
+        JsonFileStorage::new(Some(temp_dir.path().to_path_buf())).unwrap(),
+    ));
+    let account_manager = Arc::new(AccountManager::new(Arc::clone(&storage)));
+
+    (
+        GeneratorService::new(storage, Arc::clone(&account_manager)),
+        account_manager,
+        temp_dir,
+    )
+}
+
+/// Create a generator service for an unlocked account
+///
+/// Also returns the storage, the session key string and the TempDir to keep
+/// it alive for the duration of the test.
+async fn setup_unlocked_generator_service() -> (
+    GeneratorService,
+    Arc<Mutex<JsonFileStorage>>,
+    String,
+    TempDir,
+) {
+    let temp_dir = TempDir::new().unwrap();
+    let storage = Arc::new(Mutex::new(
+        JsonFileStorage::new(Some(temp_dir.path().to_path_buf())).unwrap(),
+    ));
+    let account_manager = Arc::new(AccountManager::new(Arc::clone(&storage)));
+    log_in(&account_manager, TEST_USER_ID).await;
+
+    // Unlock: store a user key protected by the session key
+    let session_key = generate_session_key();
+    KeyService::new(Arc::clone(&storage), Arc::clone(&account_manager))
+        .store_user_key(TEST_USER_ID, &generate_session_key(), &session_key)
+        .await
+        .unwrap();
+
+    (
+        GeneratorService::new(Arc::clone(&storage), account_manager),
+        storage,
+        format_session_key(&session_key),
+        temp_dir,
+    )
+}
+
+async fn log_in(account_manager: &AccountManager, user_id: &str) {
+    account_manager
+        .register_account(user_id, "test@example.com")
+        .await
+        .unwrap();
+    account_manager.set_active_user_id(user_id).await.unwrap();
+}
+
+fn settings(api_token: &str, domain: Option<&str>, base_url: Option<&str>) -> ForwarderSettings {
+    ForwarderSettings {
+        api_token: api_token.to_string(),
+        domain: domain.map(str::to_string),
+        base_url: base_url.map(str::to_string),
+    }
+}
+
+#[tokio::test]
+async fn test_options_default_without_account() {
+    let (service, _, _temp) = setup_generator_service();
+
+    let options = service.get_options().await.unwrap();
+
+    assert_eq!(options, GeneratorOptions::default());
+    assert_eq!(service.generate(&options).unwrap().len(), 16);
+}
+
+#[tokio::test]
+async fn test_set_options_requires_account() {
+    let (service, _, _temp) = setup_generator_service();
+
+    let result = service.set_options(&GeneratorOptions::default()).await;
+
+    assert!(matches!(result, Err(GeneratorError::NotAuthenticated)));
+}
+
+#[tokio::test]
+async fn test_options_are_saved_per_account() {
+    let (service, account_manager, _temp) = setup_generator_service();
+    log_in(&account_manager, TEST_USER_ID).await;
+
+    let options = GeneratorOptions {
+        generator_type: GeneratorType::Passphrase,
+        num_words: 5,
+        word_separator: "_".to_string(),
+        avoid_ambiguous: true,
+        ..GeneratorOptions::default()
+    };
+    service.set_options(&options).await.unwrap();
+
+    assert_eq!(service.get_options().await.unwrap(), options);
+    let passphrase = service.generate(&options).unwrap();
+    assert_eq!(passphrase.split('_').count(), 5);
+
+    // Another account starts from the built-in defaults
+    log_in(&account_manager, "other-user").await;
+    assert_eq!(
+        service.get_options().await.unwrap(),
+        GeneratorOptions::default()
+    );
+}
+
+#[tokio::test]
+async fn test_set_options_rejects_unusable_options() {
+    let (service, account_manager, _temp) = setup_generator_service();
+    log_in(&account_manager, TEST_USER_ID).await;
+
+    let options = GeneratorOptions {
+        num_words: 1,
+        ..GeneratorOptions::default()
+    };
+    let result = service.set_options(&options).await;
+
+    assert!(matches!(
+        result,
+        Err(GeneratorError::InvalidNumWords { .. })
+    ));
+    assert_eq!(
+        service.get_options().await.unwrap(),
+        GeneratorOptions::default()
+    );
+}
+
+/// Write a wordlist of `count` distinct words into the temp directory
+fn write_wordlist(temp_dir: &TempDir, count: usize) -> String {
+    let path = temp_dir.path().join("words.txt");
+    let words: Vec<String> = (0..count).map(|i| format!("word{}", i)).collect();
+    std::fs::write(&path, words.join("\n")).unwrap();
+    path.to_string_lossy().into_owned()
+}
+
+#[tokio::test]
+async fn test_passphrase_from_custom_wordlist() {
+    let (service, _, temp) = setup_generator_service();
+
+    let options = GeneratorOptions {
+        generator_type: GeneratorType::Passphrase,
+        num_words: 4,
+        wordlist: Some(write_wordlist(&temp, 2048)),
+        ..GeneratorOptions::default()
+    };
+    let generated = service.generate_passphrase(&options).unwrap();
+
+    let words: Vec<&str> = generated.passphrase.split('-').collect();
+    assert_eq!(words.len(), 4);
+    assert!(words.iter().all(|w| w.starts_with("word")));
+    assert!((generated.entropy - 44.0).abs() < 1e-9);
+}
+
+#[tokio::test]
+async fn test_passphrase_rejects_small_wordlist() {
+    let (service, _, temp) = setup_generator_service();
+
+    // 3 words from 256 gives 24 bits, below the default minimum
+    let mut options = GeneratorOptions {
+        generator_type: GeneratorType::Passphrase,
+        wordlist: Some(write_wordlist(&temp, 256)),
+        ..GeneratorOptions::default()
+    };
+    let result = service.generate_passphrase(&options);
+    assert!(matches!(
+        result,
+        Err(GeneratorError::InsufficientEntropy { .. })
+    ));
+
+    // Enough words make up for the smaller list
+    options.num_words = 5;
+    assert!(service.generate_passphrase(&options).is_ok());
+
+    // An explicit minimum also applies to the built-in list
+    let options = GeneratorOptions {
+        min_entropy: Some(60.0),
+        ..GeneratorOptions::default()
+    };
+    assert!(matches!(
+        service.generate_passphrase(&options),
+        Err(GeneratorError::InsufficientEntropy { .. })
+    ));
+}
+
+#[tokio::test]
+async fn test_passphrase_reports_missing_wordlist() {
+    let (service, _, temp) = setup_generator_service();
+
+    let options = GeneratorOptions {
+        wordlist: Some(
+            temp.path()
+                .join("missing.txt")
+                .to_string_lossy()
+                .into_owned(),
+        ),
+        ..GeneratorOptions::default()
+    };
+
+    assert!(matches!(
+        service.generate_passphrase(&options),
+        Err(GeneratorError::Wordlist(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_generate_word_username() {
+    let (service, _, _temp) = setup_generator_service();
+
+    let username = service
+        .generate_username(
+            UsernameRequest::Word {
+                capitalize: true,
+                include_number: true,
+            },
+            None,
+        )
+        .await
+        .unwrap();
+
+    assert!(username.chars().next().unwrap().is_uppercase());
+    assert!(username.chars().any(|c| c.is_ascii_digit()));
+}
+
+#[tokio::test]
+async fn test_generate_subaddress_username() {
+    let (service, _, _temp) = setup_generator_service();
+
+    let username = service
+        .generate_username(
+            UsernameRequest::Subaddress {
+                email: "user@example.com".to_string(),
+                website: Some("github.com".to_string()),
+            },
+            None,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(username, "user+github.com@example.com");
+}
+
+fn forwarded(service: ForwarderService, website: Option<&str>) -> UsernameRequest {
+    UsernameRequest::Forwarded {
+        service,
+        website: website.map(str::to_string),
+    }
+}
+
+#[tokio::test]
+async fn test_generate_simplelogin_alias() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/api/alias/random/new"))
+        .and(header("Authentication", "sl-key"))
+        .respond_with(
+            ResponseTemplate::new(201)
+                .set_body_json(serde_json::json!({ "alias": "random.alias@simplelogin.com" })),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let (service, _, session, _temp) = setup_unlocked_generator_service().await;
+    service
+        .set_forwarder(
+            ForwarderService::SimpleLogin,
+            settings("sl-key", None, Some(&mock_server.uri())),
+            &session,
+        )
+        .await
+        .unwrap();
+
+    let alias = service
+        .generate_username(
+            forwarded(ForwarderService::SimpleLogin, Some("example.com")),
+            Some(&session),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(alias, "random.alias@simplelogin.com");
+}
+
+#[tokio::test]
+async fn test_generate_addyio_alias() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/api/v1/aliases"))
+        .and(header("Authorization", "Bearer addy-token"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "data": { "email": "alias@anonaddy.me" }
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let (service, _, session, _temp) = setup_unlocked_generator_service().await;
+    service
+        .set_forwarder(
+            ForwarderService::AddyIo,
+            settings("addy-token", Some("anonaddy.me"), Some(&mock_server.uri())),
+            &session,
+        )
+        .await
+        .unwrap();
+
+    let alias = service
+        .generate_username(forwarded(ForwarderService::AddyIo, None), Some(&session))
+        .await
+        .unwrap();
+
+    assert_eq!(alias, "alias@anonaddy.me");
+}
+
+#[tokio::test]
+async fn test_forwarder_rejects_invalid_token() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/api/alias/random/new"))
+        .respond_with(ResponseTemplate::new(401))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/api/v1/aliases"))
+        .respond_with(ResponseTemplate::new(401))
+        .mount(&mock_server)
+        .await;
+
+    let (service, _, session, _temp) = setup_unlocked_generator_service().await;
+    for forwarder in [ForwarderService::SimpleLogin, ForwarderService::AddyIo] {
+        service
+            .set_forwarder(
+                forwarder,
+                settings("wrong", Some("anonaddy.me"), Some(&mock_server.uri())),
+                &session,
+            )
+            .await
+            .unwrap();
+
+        let result = service
+            .generate_username(forwarded(forwarder, None), Some(&session))
+            .await;
+
+        assert!(
+            matches!(result, Err(GeneratorError::InvalidApiToken { service }) if service == forwarder)
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_forwarder_requires_configuration() {
+    let (service, _, session, _temp) = setup_unlocked_generator_service().await;
+
+    let result = service
+        .generate_username(forwarded(ForwarderService::Fastmail, None), Some(&session))
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(GeneratorError::ForwarderNotConfigured(
+            ForwarderService::Fastmail
+        ))
+    ));
+}
+
+#[tokio::test]
+async fn test_forwarder_requires_unlocked_vault() {
+    let (service, _, _session, _temp) = setup_unlocked_generator_service().await;
+
+    let result = service
+        .generate_username(forwarded(ForwarderService::DuckDuckGo, None), None)
+        .await;
+
+    assert!(matches!(result, Err(GeneratorError::Locked)));
+}
+
+#[tokio::test]
+async fn test_set_forwarder_requires_domain() {
+    let (service, _, session, _temp) = setup_unlocked_generator_service().await;
+
+    let result = service
+        .set_forwarder(
+            ForwarderService::AddyIo,
+            settings("token", None, None),
+            &session,
+        )
+        .await;
+
+    assert!(matches!(result, Err(GeneratorError::InvalidInput(_))));
+    assert!(
+        service
+            .get_forwarder(ForwarderService::AddyIo, &session)
+            .await
+            .unwrap()
+            .is_none()
+    );
+}
+
+#[tokio::test]
+async fn test_set_forwarder_rejects_url_for_hosted_services() {
+    let (service, _, session, _temp) = setup_unlocked_generator_service().await;
+
+    // The SDK only calls a custom API URL for SimpleLogin and addy.io
+    let result = service
+        .set_forwarder(
+            ForwarderService::Firefox,
+            settings("token", None, Some("https://relay.example.com")),
+            &session,
+        )
+        .await;
+
+    assert!(matches!(result, Err(GeneratorError::InvalidInput(_))));
+    assert!(
+        service
+            .get_forwarder(ForwarderService::Firefox, &session)
+            .await
+            .unwrap()
+            .is_none()
+    );
+}
+
+#[tokio::test]
+async fn test_forwarder_token_is_encrypted_per_account() {
+    let (service, storage, session, _temp) = setup_unlocked_generator_service().await;
+    service
+        .set_forwarder(
+            ForwarderService::DuckDuckGo,
+            settings("ddg-token", None, None),
+            &session,
+        )
+        .await
+        .unwrap();
+
+    // The stored token is an EncString, decrypted on read
+    let stored: serde_json::Value = storage
+        .lock()
+        .await
+        .get(&StorageKey::UserForwarders.format(Some(TEST_USER_ID)))
+        .unwrap()
+        .unwrap();
+    let stored_token = stored["duckduckgo"]["apiToken"].as_str().unwrap();
+    assert_ne!(stored_token, "ddg-token");
+    assert!(stored_token.starts_with("2."));
+    assert_eq!(
+        service
+            .get_forwarder(ForwarderService::DuckDuckGo, &session)
+            .await
+            .unwrap(),
+        Some(settings("ddg-token", None, None))
+    );
+
+    // Another account doesn't see it
+    let account_manager = AccountManager::new(Arc::clone(&storage));
+    log_in(&account_manager, "other-user").await;
+    assert!(
+        service
+            .get_forwarder(ForwarderService::DuckDuckGo, &session)
+            .await
+            .unwrap()
+            .is_none()
+    );
+}
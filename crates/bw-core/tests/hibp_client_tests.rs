@@ -0,0 +1,84 @@
+//! Integration tests for HibpClient
+//!
+//! Runs the Pwned Passwords range lookups against a local stub server.
+
+use bw_core::services::hibp::{HibpClient, HibpError};
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{header, method, path},
+};
+
+// SHA-1("password") = 5BAA6 1E4C9B93F3F0682250B6CF8331B7EE68FD8
+const PASSWORD_RANGE: &str = "1E4C9B93F3F0682250B6CF8331B7EE68FD8:3730471\r\n\
+                              0018A45C4D1DEF81644B54AB7F969B88D65:0\r\n\
+                              00D4F6E8FA6EECAD2A3AA415EEC418D38EC:2";
+
+// SHA-1("123456") = 7C4A8 D09CA3762AF61E59520943DC26494F8941B
+const NUMBERS_RANGE: &str = "D09CA3762AF61E59520943DC26494F8941B:37359195\r\n\
+                             0029F2E6B7A5FF2E1FB7A8C0E5CB2F7AE4A:0";
+
+async fn mount_range(server: &MockServer, prefix: &str, body: &str, expected: u64) {
+    Mock::given(method("GET"))
+        .and(path(format!("/range/{}", prefix)))
+        .and(header("Add-Padding", "true"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(body))
+        .expect(expected)
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_breach_count_for_exposed_password() {
+    let server = MockServer::start().await;
+    mount_range(&server, "5BAA6", PASSWORD_RANGE, 1).await;
+
+    let client = HibpClient::new(Some(&server.uri())).unwrap();
+
+    assert_eq!(client.breach_count("password").await.unwrap(), 3730471);
+}
+
+#[tokio::test]
+async fn test_breach_count_ignores_padding() {
+    let server = MockServer::start().await;
+    // Only a padding entry matches the suffix
+    mount_range(
+        &server,
+        "5BAA6",
+        "1E4C9B93F3F0682250B6CF8331B7EE68FD8:0\r\n00D4F6E8FA6EECAD2A3AA415EEC418D38EC:2",
+        1,
+    )
+    .await;
+
+    let client = HibpClient::new(Some(&server.uri())).unwrap();
+
+    assert_eq!(client.breach_count("password").await.unwrap(), 0);
+}
+
+#[tokio::test]
+async fn test_breach_counts_request_each_prefix_once() {
+    let server = MockServer::start().await;
+    mount_range(&server, "5BAA6", PASSWORD_RANGE, 1).await;
+    mount_range(&server, "7C4A8", NUMBERS_RANGE, 1).await;
+
+    let client = HibpClient::new(Some(&format!("{}/", server.uri()))).unwrap();
+    let counts = client
+        .breach_counts(&["password", "123456", "password"])
+        .await
+        .unwrap();
+
+    assert_eq!(counts, vec![3730471, 37359195, 3730471]);
+}
+
+#[tokio::test]
+async fn test_breach_count_server_error() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(503))
+        .mount(&server)
+        .await;
+
+    let client = HibpClient::new(Some(&server.uri())).unwrap();
+    let result = client.breach_count("password").await;
+
+    assert!(matches!(result, Err(HibpError::Server { status: 503 })));
+}
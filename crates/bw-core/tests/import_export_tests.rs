@@ -8,10 +8,12 @@
 //! - Error handling
 //! - Edge cases and boundary conditions
 
+use bw_core::models::state::{KdfConfig, KdfType};
 use bw_core::models::vault::{
     CipherCardView, CipherIdentityView, CipherLoginUriView, CipherLoginView, CipherSecureNote,
     CipherType, CipherView, FolderView,
 };
+use bw_core::services::import_export::errors::ImportError;
 use bw_core::services::import_export::{
     ExportData, ExportOptions, ExportService, ImportOptions, ImportService,
 };
@@ -165,6 +167,9 @@ fn create_test_export_data() -> ExportData {
     ExportData {
         folders: vec![folder1, folder2],
         ciphers: vec![cipher1, cipher2, cipher3, cipher4, cipher5],
+        collections: vec![],
+        encrypted_folders: vec![],
+        encrypted_ciphers: vec![],
     }
 }
 
@@ -259,6 +264,9 @@ async fn test_export_empty_vault() {
     let data = ExportData {
         folders: vec![],
         ciphers: vec![],
+        collections: vec![],
+        encrypted_folders: vec![],
+        encrypted_ciphers: vec![],
     };
     let options = ExportOptions::default();
 
@@ -306,19 +314,44 @@ async fn test_export_encrypted_json_without_password_fails() {
 }
 
 #[tokio::test]
-async fn test_export_encrypted_json_with_password_placeholder() {
+async fn test_export_encrypted_json_with_password() {
+    let temp_dir = TempDir::new().unwrap();
+    let output_path = temp_dir.path().join("export.json");
+
     let service = ExportService::new();
     let data = create_test_export_data();
     let options = ExportOptions {
         password: Some(Secret::new("test-password".to_string())),
-        organization_id: None,
+        kdf: Some(KdfConfig {
+            kdf_type: KdfType::PBKDF2SHA256,
+            iterations: Some(5_000),
+            memory: None,
+            parallelism: None,
+        }),
+        ..Default::default()
     };
 
-    // This should fail with SDK integration needed message
-    let result = service.export("encrypted_json", None, data, options).await;
+    let result = service
+        .export(
+            "encrypted_json",
+            Some(output_path.to_str().unwrap()),
+            data,
+            options,
+        )
+        .await
+        .unwrap();
 
-    assert!(result.is_err());
-    // The encrypted JSON formatter returns an error indicating SDK is needed
+    assert!(result.encrypted);
+    assert_eq!(result.item_count, 5);
+
+    let content = fs::read_to_string(&output_path).unwrap();
+    let json: serde_json::Value = serde_json::from_str(&content).unwrap();
+    assert_eq!(json["encrypted"], true);
+    assert_eq!(json["passwordProtected"], true);
+    assert_eq!(json["kdfType"], 0);
+    assert_eq!(json["kdfIterations"], 5_000);
+    assert!(json["salt"].is_string());
+    assert!(json["data"].as_str().unwrap().starts_with("2."));
 }
 
 // ============================================================================
@@ -651,6 +684,95 @@ async fn test_round_trip_json_export_import() {
     assert_eq!(import_result.items_created, export_data.ciphers.len());
 }
 
+fn fast_kdf() -> KdfConfig {
+    KdfConfig {
+        kdf_type: KdfType::PBKDF2SHA256,
+        iterations: Some(5_000),
+        memory: None,
+        parallelism: None,
+    }
+}
+
+#[tokio::test]
+async fn test_round_trip_password_protected_export_import() {
+    let temp_dir = TempDir::new().unwrap();
+    let export_path = temp_dir.path().join("export.json");
+
+    // Step 1: Export with a file password
+    let export_data = create_test_export_data();
+    let item_count = export_data.ciphers.len();
+    ExportService::new()
+        .export(
+            "encrypted_json",
+            Some(export_path.to_str().unwrap()),
+            export_data,
+            ExportOptions {
+                password: Some(Secret::new("file-password".to_string())),
+                kdf: Some(fast_kdf()),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+    // Step 2: Import with the same password
+    let import_result = ImportService::new()
+        .import(
+            "bitwardenjson",
+            export_path.to_str().unwrap(),
+            ImportOptions {
+                password: Some(Secret::new("file-password".to_string())),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(import_result.items_created, item_count);
+}
+
+#[tokio::test]
+async fn test_import_password_protected_with_wrong_password() {
+    let temp_dir = TempDir::new().unwrap();
+    let export_path = temp_dir.path().join("export.json");
+
+    ExportService::new()
+        .export(
+            "encrypted_json",
+            Some(export_path.to_str().unwrap()),
+            create_test_export_data(),
+            ExportOptions {
+                password: Some(Secret::new("file-password".to_string())),
+                kdf: Some(fast_kdf()),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+    let service = ImportService::new();
+    let path = export_path.to_str().unwrap();
+
+    // Missing password
+    let result = service
+        .import("bitwardenjson", path, ImportOptions::default())
+        .await;
+    assert!(matches!(result, Err(ImportError::PasswordRequired)));
+
+    // Wrong password
+    let result = service
+        .import(
+            "bitwardenjson",
+            path,
+            ImportOptions {
+                password: Some(Secret::new("wrong-password".to_string())),
+                ..Default::default()
+            },
+        )
+        .await;
+    assert!(matches!(result, Err(ImportError::InvalidPassword)));
+}
+
 // ============================================================================
 // Data Validation Tests
 // ============================================================================
@@ -0,0 +1,484 @@
+//! Integration tests for organization items
+//!
+//! Tests assigning items to organization collections and sharing personal
+//! items with an organization against a mock HTTP server with real storage
+//! and real keys.
+
+use base64::{Engine, engine::general_purpose::STANDARD};
+use bitwarden_core::key_management::crypto::{
+    InitOrgCryptoRequest, InitUserCryptoMethod, InitUserCryptoRequest,
+};
+use bitwarden_crypto::{
+    AsymmetricCryptoKey, EncString, Kdf, KeyEncryptable, PublicKeyEncryptionAlgorithm,
+    SymmetricCryptoKey,
+};
+use bw_core::models::vault::{Cipher, Collection, SyncResponseModel, parse_sync_response};
+use bw_core::services::api::{BitwardenApiClient, Environment};
+use bw_core::services::storage::{AccountManager, JsonFileStorage, Storage, StorageKey};
+use bw_core::services::vault::{
+    CipherService, ConfirmationService, ValidationService, VaultError, WriteService,
+};
+use bw_core::services::{Client, create_sdk_client, decrypt_string, encapsulate_key};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tempfile::TempDir;
+use tokio::sync::Mutex;
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{body_json, method, path, query_param},
+};
+
+const TEST_USER_ID: &str = "test-user-12345";
+const TEST_ORG_ID: &str = "5c1f4a3e-2b6d-4e8f-9a0b-1c2d3e4f5a6b";
+const ORG_ITEM_ID: &str = "0f9e8d7c-6b5a-4c3d-8e2f-1a0b9c8d7e6f";
+const PERSONAL_ITEM_ID: &str = "7e6d5c4b-3a2f-4e1d-9c0b-8a7f6e5d4c3b";
+const ATTACHMENT_ID: &str = "att-1";
+const WRITABLE_COLLECTION_ID: &str = "3a4b5c6d-7e8f-4a0b-9c1d-2e3f4a5b6c7d";
+const READ_ONLY_COLLECTION_ID: &str = "9d8c7b6a-5f4e-4d3c-8b2a-1f0e9d8c7b6a";
+
+/// Placeholder name; these tests never decrypt it
+const ENCRYPTED_NAME: &str =
+    "2.AAAAAAAAAAAAAAAAAAAAAA==|AAAA|AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=";
+
+/// Create a write service for a synced member of `TEST_ORG_ID`
+///
+/// The vault holds `ciphers` (sync API models) and one writable and one
+/// read-only collection. Also returns the storage to inspect, the SDK client
+/// to load keys into and the TempDir to keep it alive for the duration of the
+/// test.
+async fn setup_write_service(
+    api_url: &str,
+    ciphers: serde_json::Value,
+) -> (
+    WriteService,
+    Arc<Mutex<JsonFileStorage>>,
+    Arc<Client>,
+    TempDir,
+) {
+    let temp_dir = TempDir::new().unwrap();
+    let storage = Arc::new(Mutex::new(
+        JsonFileStorage::new(Some(temp_dir.path().to_path_buf())).unwrap(),
+    ));
+    let account_manager = Arc::new(AccountManager::new(Arc::clone(&storage)));
+
+    account_manager
+        .register_account(TEST_USER_ID, "test@example.com")
+        .await
+        .unwrap();
+    account_manager
+        .set_active_user_id(TEST_USER_ID)
+        .await
+        .unwrap();
+
+    // Cache the vault the way sync does
+    let sync_response: SyncResponseModel = serde_json::from_value(serde_json::json!({
+        "ciphers": ciphers,
+        "collections": [
+            {
+                "id": WRITABLE_COLLECTION_ID,
+                "organizationId": TEST_ORG_ID,
+                "name": ENCRYPTED_NAME,
+                "type": 0,
+                "readOnly": false,
+                "hidePasswords": false,
+                "manage": false
+            },
+            {
+                "id": READ_ONLY_COLLECTION_ID,
+                "organizationId": TEST_ORG_ID,
+                "name": ENCRYPTED_NAME,
+                "type": 0,
+                "readOnly": true,
+                "hidePasswords": false,
+                "manage": false
+            }
+        ]
+    }))
+    .unwrap();
+    let sync_data = parse_sync_response(sync_response).unwrap();
+    let ciphers: HashMap<String, Cipher> = sync_data
+        .ciphers
+        .into_iter()
+        .map(|c| (c.id.unwrap().to_string(), c))
+        .collect();
+    let collections: HashMap<String, Collection> = sync_data
+        .collections
+        .into_iter()
+        .map(|c| (c.id.unwrap().to_string(), c))
+        .collect();
+
+    {
+        let mut storage = storage.lock().await;
+        storage
+            .set(
+                &StorageKey::UserAccessToken.format(Some(TEST_USER_ID)),
+                &"test-access-token".to_string(),
+            )
+            .await
+            .unwrap();
+        storage
+            .set(
+                &StorageKey::UserCiphers.format(Some(TEST_USER_ID)),
+                &ciphers,
+            )
+            .await
+            .unwrap();
+        storage
+            .set(
+                &StorageKey::UserCollections.format(Some(TEST_USER_ID)),
+                &collections,
+            )
+            .await
+            .unwrap();
+    }
+
+    let environment = Environment::from_base_url(api_url).unwrap();
+    let api_client =
+        Arc::new(BitwardenApiClient::new(environment, Arc::clone(&storage), None).unwrap());
+    let sdk_client = Arc::new(create_sdk_client(None, None).unwrap());
+
+    (
+        WriteService::new(
+            api_client,
+            Arc::clone(&storage),
+            Arc::new(CipherService::new(Arc::clone(&sdk_client))),
+            Arc::new(ValidationService::new()),
+            Arc::new(ConfirmationService::new(true)),
+            account_manager,
+        ),
+        storage,
+        sdk_client,
+        temp_dir,
+    )
+}
+
+/// Load the user key and the `TEST_ORG_ID` key into the SDK
+async fn init_sdk_crypto(
+    sdk_client: &Client,
+    user_key: &SymmetricCryptoKey,
+    org_key: &SymmetricCryptoKey,
+) {
+    let private_key = AsymmetricCryptoKey::make(PublicKeyEncryptionAlgorithm::RsaOaepSha1);
+    let encrypted_private_key: EncString = private_key
+        .to_der()
+        .unwrap()
+        .as_ref()
+        .encrypt_with_key(user_key)
+        .unwrap();
+    sdk_client
+        .crypto()
+        .initialize_user_crypto(InitUserCryptoRequest {
+            user_id: None,
+            kdf_params: Kdf::default(),
+            email: "test@example.com".to_string(),
+            private_key: encrypted_private_key,
+            signing_key: None,
+            security_state: None,
+            method: InitUserCryptoMethod::DecryptedKey {
+                decrypted_user_key: user_key.to_base64().to_string(),
+            },
+        })
+        .await
+        .unwrap();
+
+    // The organization key is shared to the account's public key
+    let public_key = STANDARD.encode(private_key.to_public_key().to_der().unwrap());
+    let shared_org_key = encapsulate_key(org_key, &public_key).unwrap();
+    sdk_client
+        .crypto()
+        .initialize_org_crypto(InitOrgCryptoRequest {
+            organization_keys: HashMap::from([(
+                TEST_ORG_ID.parse().unwrap(),
+                shared_org_key.parse().unwrap(),
+            )]),
+        })
+        .await
+        .unwrap();
+}
+
+/// Sync API model of a login owned by `TEST_ORG_ID`, in no collections
+fn org_item() -> serde_json::Value {
+    serde_json::json!({
+        "id": ORG_ITEM_ID,
+        "organizationId": TEST_ORG_ID,
+        "type": 1,
+        "name": ENCRYPTED_NAME,
+        "login": {},
+        "favorite": false,
+        "reprompt": 0,
+        "edit": true,
+        "viewPassword": true,
+        "organizationUseTotp": false,
+        "collectionIds": [],
+        "creationDate": "2025-01-01T00:00:00Z",
+        "revisionDate": "2025-01-01T00:00:00Z"
+    })
+}
+
+/// Sync API model of a personal login encrypted with `user_key`
+///
+/// Its attachment predates attachment keys, so sharing has to re-upload it.
+fn personal_item(user_key: &SymmetricCryptoKey) -> serde_json::Value {
+    let encrypt = |value: &str| value.encrypt_with_key(user_key).unwrap().to_string();
+    serde_json::json!({
+        "id": PERSONAL_ITEM_ID,
+        "type": 1,
+        "name": encrypt("Team login"),
+        "login": { "username": encrypt("admin") },
+        "attachments": [{
+            "id": ATTACHMENT_ID,
+            "fileName": encrypt("notes.txt"),
+            "size": "5",
+            "sizeName": "5 Bytes"
+        }],
+        "favorite": false,
+        "reprompt": 0,
+        "edit": true,
+        "viewPassword": true,
+        "organizationUseTotp": false,
+        "collectionIds": [],
+        "creationDate": "2025-01-01T00:00:00Z",
+        "revisionDate": "2025-01-01T00:00:00Z"
+    })
+}
+
+/// Read the cached cipher `id`
+async fn cached_cipher(storage: &Arc<Mutex<JsonFileStorage>>, id: &str) -> Cipher {
+    let ciphers: HashMap<String, Cipher> = storage
+        .lock()
+        .await
+        .get(&StorageKey::UserCiphers.format(Some(TEST_USER_ID)))
+        .unwrap()
+        .unwrap();
+    ciphers[id].clone()
+}
+
+#[tokio::test]
+async fn test_update_cipher_collections_updates_cache() {
+    let mock_server = MockServer::start().await;
+    let (service, storage, _, _temp) =
+        setup_write_service(&mock_server.uri(), serde_json::json!([org_item()])).await;
+
+    // The server answers with the item in its new collections
+    let mut updated = cached_cipher(&storage, ORG_ITEM_ID).await;
+    updated.collection_ids = vec![WRITABLE_COLLECTION_ID.parse().unwrap()];
+    Mock::given(method("PUT"))
+        .and(path(format!("/api/ciphers/{}/collections", ORG_ITEM_ID)))
+        .and(body_json(serde_json::json!({
+            "collectionIds": [WRITABLE_COLLECTION_ID]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&updated))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let result = service
+        .update_cipher_collections(ORG_ITEM_ID, vec![WRITABLE_COLLECTION_ID.to_string()])
+        .await
+        .unwrap();
+
+    assert_eq!(result.collection_ids, updated.collection_ids);
+    let cached = cached_cipher(&storage, ORG_ITEM_ID).await;
+    assert_eq!(cached.collection_ids, updated.collection_ids);
+}
+
+#[tokio::test]
+async fn test_update_cipher_collections_rejects_read_only_collection() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("PUT"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let (service, storage, _, _temp) =
+        setup_write_service(&mock_server.uri(), serde_json::json!([org_item()])).await;
+
+    let result = service
+        .update_cipher_collections(ORG_ITEM_ID, vec![READ_ONLY_COLLECTION_ID.to_string()])
+        .await;
+
+    assert!(matches!(result, Err(VaultError::PermissionDenied(_))));
+    assert!(
+        cached_cipher(&storage, ORG_ITEM_ID)
+            .await
+            .collection_ids
+            .is_empty()
+    );
+}
+
+#[tokio::test]
+async fn test_update_cipher_collections_returns_error_when_item_not_found() {
+    let mock_server = MockServer::start().await;
+    let (service, _, _, _temp) =
+        setup_write_service(&mock_server.uri(), serde_json::json!([org_item()])).await;
+
+    let result = service
+        .update_cipher_collections("non-existent-id", vec![WRITABLE_COLLECTION_ID.to_string()])
+        .await;
+
+    assert!(matches!(result, Err(VaultError::ItemNotFound)));
+}
+
+#[tokio::test]
+async fn test_share_cipher_moves_item_into_organization() {
+    let mock_server = MockServer::start().await;
+    let user_key = SymmetricCryptoKey::make_aes256_cbc_hmac_key();
+    let org_key = SymmetricCryptoKey::make_aes256_cbc_hmac_key();
+    let (service, storage, sdk_client, _temp) = setup_write_service(
+        &mock_server.uri(),
+        serde_json::json!([personal_item(&user_key)]),
+    )
+    .await;
+    init_sdk_crypto(&sdk_client, &user_key, &org_key).await;
+
+    // The legacy attachment is downloaded, then re-uploaded under its own key
+    let legacy_contents = b"hello"
+        .as_slice()
+        .encrypt_with_key(&user_key)
+        .unwrap()
+        .to_buffer()
+        .unwrap();
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/api/ciphers/{}/attachment/{}",
+            PERSONAL_ITEM_ID, ATTACHMENT_ID
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": ATTACHMENT_ID,
+            "url": format!("{}/attachments/{}", mock_server.uri(), ATTACHMENT_ID)
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!("/attachments/{}", ATTACHMENT_ID)))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(legacy_contents))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(format!(
+            "/api/ciphers/{}/attachment/{}/share",
+            PERSONAL_ITEM_ID, ATTACHMENT_ID
+        )))
+        .and(query_param("organizationId", TEST_ORG_ID))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path(format!("/api/ciphers/{}/share", PERSONAL_ITEM_ID)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": PERSONAL_ITEM_ID,
+            "revisionDate": "2025-02-01T00:00:00Z"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let shared = service
+        .share_cipher(
+            PERSONAL_ITEM_ID,
+            TEST_ORG_ID,
+            vec![WRITABLE_COLLECTION_ID.to_string()],
+            "dummy-session",
+        )
+        .await
+        .unwrap();
+
+    let requests = mock_server.received_requests().await.unwrap();
+
+    // The attachment upload carries a new key and no plaintext
+    let upload = requests
+        .iter()
+        .find(|r| r.method.as_str() == "POST")
+        .unwrap();
+    let upload_body = String::from_utf8_lossy(&upload.body);
+    assert!(upload_body.contains("name=\"key\""));
+    assert!(upload_body.contains("name=\"data\""));
+    assert!(!upload_body.contains("hello"));
+
+    // The item is re-encrypted with the organization key
+    let share = requests
+        .iter()
+        .find(|r| r.method.as_str() == "PUT")
+        .unwrap();
+    let body: serde_json::Value = serde_json::from_slice(&share.body).unwrap();
+    assert_eq!(
+        body["collectionIds"],
+        serde_json::json!([WRITABLE_COLLECTION_ID])
+    );
+    assert_eq!(body["cipher"]["organizationId"], TEST_ORG_ID);
+    let name = body["cipher"]["name"].as_str().unwrap();
+    assert_eq!(decrypt_string(name, &org_key, None).unwrap(), "Team login");
+    assert!(decrypt_string(name, &user_key, None).is_err());
+
+    // The cache holds the item in its organization and collection
+    let cached = cached_cipher(&storage, PERSONAL_ITEM_ID).await;
+    assert_eq!(
+        cached.organization_id.map(|id| id.to_string()).as_deref(),
+        Some(TEST_ORG_ID)
+    );
+    assert_eq!(cached.collection_ids, shared.collection_ids);
+    assert_eq!(
+        cached
+            .collection_ids
+            .iter()
+            .map(|id| id.to_string())
+            .collect::<Vec<_>>(),
+        vec![WRITABLE_COLLECTION_ID]
+    );
+}
+
+#[tokio::test]
+async fn test_share_cipher_rejects_read_only_collection() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("PUT"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let user_key = SymmetricCryptoKey::make_aes256_cbc_hmac_key();
+    let (service, storage, _, _temp) = setup_write_service(
+        &mock_server.uri(),
+        serde_json::json!([personal_item(&user_key)]),
+    )
+    .await;
+
+    let result = service
+        .share_cipher(
+            PERSONAL_ITEM_ID,
+            TEST_ORG_ID,
+            vec![READ_ONLY_COLLECTION_ID.to_string()],
+            "dummy-session",
+        )
+        .await;
+
+    assert!(matches!(result, Err(VaultError::PermissionDenied(_))));
+    assert!(
+        cached_cipher(&storage, PERSONAL_ITEM_ID)
+            .await
+            .organization_id
+            .is_none()
+    );
+}
+
+#[tokio::test]
+async fn test_share_cipher_returns_error_when_item_not_found() {
+    let mock_server = MockServer::start().await;
+    let (service, _, _, _temp) =
+        setup_write_service(&mock_server.uri(), serde_json::json!([])).await;
+
+    let result = service
+        .share_cipher(
+            "non-existent-id",
+            TEST_ORG_ID,
+            vec![WRITABLE_COLLECTION_ID.to_string()],
+            "dummy-session",
+        )
+        .await;
+
+    assert!(matches!(result, Err(VaultError::ItemNotFound)));
+}
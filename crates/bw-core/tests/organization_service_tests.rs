@@ -0,0 +1,211 @@
+//! Integration tests for OrganizationService
+//!
+//! Tests organization collection and member management against a mock HTTP
+//! server with real storage.
+
+use bw_core::services::api::{BitwardenApiClient, Environment};
+use bw_core::services::storage::{AccountManager, JsonFileStorage, Storage, StorageKey};
+use bw_core::services::vault::{OrganizationService, VaultError};
+use std::sync::Arc;
+use tempfile::TempDir;
+use tokio::sync::Mutex;
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{method, path},
+};
+
+const TEST_USER_ID: &str = "test-user-12345";
+const TEST_ORG_ID: &str = "org-1";
+
+/// Create an organization service for a synced member of `TEST_ORG_ID`
+///
+/// Returns the TempDir to keep it alive for the duration of the test.
+async fn setup_organization_service(api_url: &str) -> (OrganizationService, TempDir) {
+    let temp_dir = TempDir::new().unwrap();
+    let storage = Arc::new(Mutex::new(
+        JsonFileStorage::new(Some(temp_dir.path().to_path_buf())).unwrap(),
+    ));
+    let account_manager = Arc::new(AccountManager::new(Arc::clone(&storage)));
+
+    account_manager
+        .register_account(TEST_USER_ID, "test@example.com")
+        .await
+        .unwrap();
+    account_manager
+        .set_active_user_id(TEST_USER_ID)
+        .await
+        .unwrap();
+
+    {
+        let mut storage = storage.lock().await;
+        storage
+            .set(
+                &StorageKey::UserAccessToken.format(Some(TEST_USER_ID)),
+                &"test-access-token".to_string(),
+            )
+            .await
+            .unwrap();
+        storage
+            .set(
+                &StorageKey::UserOrganizations.format(Some(TEST_USER_ID)),
+                &serde_json::json!({
+                    TEST_ORG_ID: {
+                        "id": TEST_ORG_ID,
+                        "name": "Acme",
+                        "status": 2,
+                        "type": 1,
+                        "enabled": true
+                    }
+                }),
+            )
+            .await
+            .unwrap();
+    }
+
+    let environment = Environment::from_base_url(api_url).unwrap();
+    let api_client =
+        Arc::new(BitwardenApiClient::new(environment, Arc::clone(&storage), None).unwrap());
+
+    (
+        OrganizationService::new(api_client, storage, account_manager),
+        temp_dir,
+    )
+}
+
+#[tokio::test]
+async fn test_delete_collection() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("DELETE"))
+        .and(path("/api/organizations/org-1/collections/collection-1"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let (service, _temp) = setup_organization_service(&mock_server.uri()).await;
+
+    let result = service.delete_collection(TEST_ORG_ID, "collection-1").await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_delete_collection_not_found() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("DELETE"))
+        .and(path("/api/organizations/org-1/collections/missing"))
+        .respond_with(ResponseTemplate::new(404))
+        .mount(&mock_server)
+        .await;
+
+    let (service, _temp) = setup_organization_service(&mock_server.uri()).await;
+
+    let result = service.delete_collection(TEST_ORG_ID, "missing").await;
+
+    assert!(matches!(result, Err(VaultError::CollectionNotFound)));
+}
+
+#[tokio::test]
+async fn test_unknown_organization_is_rejected_before_api_call() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("DELETE"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let (service, _temp) = setup_organization_service(&mock_server.uri()).await;
+
+    let result = service.delete_collection("other-org", "collection-1").await;
+
+    assert!(matches!(result, Err(VaultError::OrganizationNotFound(_))));
+}
+
+#[tokio::test]
+async fn test_list_members() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/organizations/org-1/users"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "data": [
+                {
+                    "id": "member-1",
+                    "userId": "user-1",
+                    "email": "alice@example.com",
+                    "name": "Alice",
+                    "status": 2,
+                    "type": 0,
+                    "twoFactorEnabled": true
+                },
+                {
+                    "id": "member-2",
+                    "userId": null,
+                    "email": "bob@example.com",
+                    "status": 0,
+                    "type": 2
+                }
+            ],
+            "continuationToken": null
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let (service, _temp) = setup_organization_service(&mock_server.uri()).await;
+
+    let members = service.list_members(TEST_ORG_ID).await.unwrap();
+
+    assert_eq!(members.len(), 2);
+    assert_eq!(members[0].email.as_deref(), Some("alice@example.com"));
+    assert!(members[0].two_factor_enabled);
+    assert_eq!(members[1].status, 0);
+    assert!(members[1].user_id.is_none());
+}
+
+#[tokio::test]
+async fn test_confirm_requires_accepted_member() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/organizations/org-1/users/member-2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "member-2",
+            "userId": null,
+            "email": "bob@example.com",
+            "status": 0,
+            "type": 2
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/api/users/user-2/public-key"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let (service, _temp) = setup_organization_service(&mock_server.uri()).await;
+
+    let result = service
+        .prepare_member_confirmation(TEST_ORG_ID, "member-2")
+        .await;
+
+    assert!(matches!(result, Err(VaultError::MemberNotAccepted)));
+}
+
+#[tokio::test]
+async fn test_confirm_unknown_member() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/organizations/org-1/users/missing"))
+        .respond_with(ResponseTemplate::new(404))
+        .mount(&mock_server)
+        .await;
+
+    let (service, _temp) = setup_organization_service(&mock_server.uri()).await;
+
+    let result = service
+        .prepare_member_confirmation(TEST_ORG_ID, "missing")
+        .await;
+
+    assert!(matches!(result, Err(VaultError::MemberNotFound)));
+}
@@ -0,0 +1,237 @@
+//! Integration tests for SendService
+//!
+//! Tests Send encryption and caching against a mock HTTP server with real
+//! storage and a real user key.
+
+use bw_core::models::send::{SendTextView, SendType, SendView};
+use bw_core::services::KeyService;
+use bw_core::services::api::{BitwardenApiClient, Environment};
+use bw_core::services::send::{SendAccessService, SendError, SendService};
+use bw_core::services::storage::{
+    AccountManager, JsonFileStorage, Storage, StorageKey, format_session_key, generate_session_key,
+};
+use std::sync::Arc;
+use tempfile::TempDir;
+use tokio::sync::Mutex;
+use wiremock::{
+    Mock, MockServer, Request, Respond, ResponseTemplate,
+    matchers::{body_json, method, path},
+};
+
+const TEST_USER_ID: &str = "test-user-12345";
+
+/// Responds to a Send create/edit with the request plus server-assigned fields
+struct EchoSend;
+
+impl Respond for EchoSend {
+    fn respond(&self, request: &Request) -> ResponseTemplate {
+        let mut body: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+        body["id"] = serde_json::json!("send-1");
+        body["accessId"] = serde_json::json!("access-1");
+        body["accessCount"] = serde_json::json!(0);
+        body["revisionDate"] = serde_json::json!("2025-01-01T00:00:00.000Z");
+        body["object"] = serde_json::json!("send");
+        ResponseTemplate::new(200).set_body_json(body)
+    }
+}
+
+/// Create a Send service for an unlocked account
+///
+/// Also returns a recipient-side access service, the session key string and
+/// the TempDir to keep it alive for the duration of the test.
+async fn setup_send_service(api_url: &str) -> (SendService, SendAccessService, String, TempDir) {
+    let temp_dir = TempDir::new().unwrap();
+    let storage = Arc::new(Mutex::new(
+        JsonFileStorage::new(Some(temp_dir.path().to_path_buf())).unwrap(),
+    ));
+    let account_manager = Arc::new(AccountManager::new(Arc::clone(&storage)));
+
+    account_manager
+        .register_account(TEST_USER_ID, "test@example.com")
+        .await
+        .unwrap();
+    account_manager
+        .set_active_user_id(TEST_USER_ID)
+        .await
+        .unwrap();
+    storage
+        .lock()
+        .await
+        .set(
+            &StorageKey::UserAccessToken.format(Some(TEST_USER_ID)),
+            &"test-access-token".to_string(),
+        )
+        .await
+        .unwrap();
+
+    // Unlock: store a user key protected by the session key
+    let session_key = generate_session_key();
+    KeyService::new(Arc::clone(&storage), Arc::clone(&account_manager))
+        .store_user_key(TEST_USER_ID, &generate_session_key(), &session_key)
+        .await
+        .unwrap();
+
+    let environment = Environment::from_base_url(api_url).unwrap();
+    let api_client =
+        Arc::new(BitwardenApiClient::new(environment, Arc::clone(&storage), None).unwrap());
+
+    (
+        SendService::new(Arc::clone(&api_client), storage, account_manager),
+        SendAccessService::new(api_client),
+        format_session_key(&session_key),
+        temp_dir,
+    )
+}
+
+fn text_send(name: &str, text: &str) -> SendView {
+    serde_json::from_value(serde_json::json!({
+        "type": 0,
+        "name": name,
+        "text": { "text": text, "hidden": false },
+        "password": "hunter2"
+    }))
+    .unwrap()
+}
+
+#[tokio::test]
+async fn test_create_text_send_encrypts_and_caches() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/api/sends"))
+        .respond_with(EchoSend)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let (service, _, session, _temp) = setup_send_service(&mock_server.uri()).await;
+
+    let created = service
+        .create_send(text_send("Wifi", "correct horse"), None, &session)
+        .await
+        .unwrap();
+
+    assert_eq!(created.name, "Wifi");
+    assert_eq!(created.send_type, SendType::Text);
+    assert_eq!(
+        created.text.as_ref().and_then(|t| t.text.as_deref()),
+        Some("correct horse")
+    );
+    assert!(created.password_set);
+    assert!(created.deletion_date.is_some());
+    let url_key = created.key.clone().unwrap();
+    assert_eq!(
+        created.access_url.as_deref(),
+        Some(format!("{}/#/send/access-1/{}", mock_server.uri(), url_key).as_str())
+    );
+
+    // Nothing readable leaves the machine
+    let requests = mock_server.received_requests().await.unwrap();
+    let body = String::from_utf8_lossy(&requests[0].body);
+    assert!(!body.contains("Wifi"));
+    assert!(!body.contains("correct horse"));
+    assert!(!body.contains("hunter2"));
+
+    // The created Send is cached for list/get
+    let sends = service.list_sends(&session).await.unwrap();
+    assert_eq!(sends.len(), 1);
+    let found = service.get_send("wifi", &session).await.unwrap();
+    assert_eq!(found.id.as_deref(), Some("send-1"));
+}
+
+#[tokio::test]
+async fn test_create_text_send_requires_text() {
+    let mock_server = MockServer::start().await;
+    let (service, _, session, _temp) = setup_send_service(&mock_server.uri()).await;
+
+    let mut view = text_send("Empty", "");
+    view.text = None::<SendTextView>;
+
+    let result = service.create_send(view, None, &session).await;
+
+    assert!(matches!(result, Err(SendError::InvalidInput(_))));
+}
+
+#[tokio::test]
+async fn test_list_sends_requires_sync() {
+    let mock_server = MockServer::start().await;
+    let (service, _, session, _temp) = setup_send_service(&mock_server.uri()).await;
+
+    let result = service.list_sends(&session).await;
+
+    assert!(matches!(result, Err(SendError::NotSynced)));
+}
+
+#[tokio::test]
+async fn test_receive_text_send_with_password() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/api/sends"))
+        .respond_with(EchoSend)
+        .mount(&mock_server)
+        .await;
+
+    let (service, access_service, session, _temp) = setup_send_service(&mock_server.uri()).await;
+    let created = service
+        .create_send(text_send("Wifi", "correct horse"), None, &session)
+        .await
+        .unwrap();
+
+    // The access endpoint answers with the Send as encrypted at creation
+    let requests = mock_server.received_requests().await.unwrap();
+    let sent: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+    Mock::given(method("POST"))
+        .and(path("/api/sends/access/access-1"))
+        .and(body_json(
+            serde_json::json!({ "password": sent["password"] }),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "send-1",
+            "type": 0,
+            "name": sent["name"],
+            "text": sent["text"],
+            "expirationDate": null,
+            "creatorIdentifier": "sender@example.com",
+            "object": "send-access"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let send_url = access_service
+        .parse_url(created.access_url.as_deref().unwrap())
+        .unwrap();
+    let received = access_service
+        .access_send(&send_url, Some("hunter2"))
+        .await
+        .unwrap();
+
+    assert_eq!(received.name, "Wifi");
+    assert_eq!(
+        received.text.and_then(|t| t.text).as_deref(),
+        Some("correct horse")
+    );
+    assert_eq!(
+        received.creator_identifier.as_deref(),
+        Some("sender@example.com")
+    );
+}
+
+#[tokio::test]
+async fn test_receive_password_protected_send_without_password() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/api/sends/access/access-1"))
+        .respond_with(ResponseTemplate::new(401))
+        .mount(&mock_server)
+        .await;
+
+    let (_, access_service, _, _temp) = setup_send_service(&mock_server.uri()).await;
+    let url = format!(
+        "{}/#/send/access-1/AAAAAAAAAAAAAAAAAAAAAA",
+        mock_server.uri()
+    );
+    let send_url = access_service.parse_url(&url).unwrap();
+
+    let result = access_service.access_send(&send_url, None).await;
+
+    assert!(matches!(result, Err(SendError::PasswordRequired)));
+}
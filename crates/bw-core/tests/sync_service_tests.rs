@@ -0,0 +1,114 @@
+//! Integration tests for SyncService
+//!
+//! Tests vault sync against a mock HTTP server with real storage.
+
+use bw_core::models::send::Send;
+use bw_core::services::api::{BitwardenApiClient, Environment};
+use bw_core::services::storage::{AccountManager, JsonFileStorage, Storage, StorageKey};
+use bw_core::services::vault::SyncService;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tempfile::TempDir;
+use tokio::sync::Mutex;
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{method, path},
+};
+
+const TEST_USER_ID: &str = "test-user-12345";
+const GOOD_SEND_ID: &str = "6f2a3c1e-8a4b-4d3e-9f10-2b7c5d6e7f80";
+const BAD_SEND_ID: &str = "0b1c2d3e-4f50-4617-8293-a4b5c6d7e8f9";
+
+/// Create a sync service for a logged-in account
+///
+/// Also returns the storage to inspect and the TempDir to keep it alive for
+/// the duration of the test.
+async fn setup_sync_service(api_url: &str) -> (SyncService, Arc<Mutex<JsonFileStorage>>, TempDir) {
+    let temp_dir = TempDir::new().unwrap();
+    let storage = Arc::new(Mutex::new(
+        JsonFileStorage::new(Some(temp_dir.path().to_path_buf())).unwrap(),
+    ));
+    let account_manager = AccountManager::new(Arc::clone(&storage));
+
+    account_manager
+        .register_account(TEST_USER_ID, "test@example.com")
+        .await
+        .unwrap();
+    account_manager
+        .set_active_user_id(TEST_USER_ID)
+        .await
+        .unwrap();
+    storage
+        .lock()
+        .await
+        .set(
+            &StorageKey::UserAccessToken.format(Some(TEST_USER_ID)),
+            &"test-access-token".to_string(),
+        )
+        .await
+        .unwrap();
+
+    let environment = Environment::from_base_url(api_url).unwrap();
+    let api_client =
+        Arc::new(BitwardenApiClient::new(environment, Arc::clone(&storage), None).unwrap());
+
+    (
+        SyncService::new(api_client, Arc::clone(&storage)),
+        storage,
+        temp_dir,
+    )
+}
+
+#[tokio::test]
+async fn test_sync_skips_malformed_send() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/sync"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "object": "sync",
+            "ciphers": [],
+            "folders": [],
+            "collections": [],
+            "sends": [
+                {
+                    "object": "send",
+                    "id": GOOD_SEND_ID,
+                    "accessId": "access-1",
+                    "type": 0,
+                    "name": "2.name",
+                    "text": { "text": "2.text", "hidden": false },
+                    "key": "2.key",
+                    "accessCount": 0,
+                    "disabled": false,
+                    "revisionDate": "2025-01-01T00:00:00.000Z",
+                    "deletionDate": "2025-01-08T00:00:00.000Z"
+                },
+                {
+                    // No name or key, so the CLI model can't hold it
+                    "object": "send",
+                    "id": BAD_SEND_ID,
+                    "accessId": "access-2",
+                    "type": 0,
+                    "revisionDate": "2025-01-01T00:00:00.000Z",
+                    "deletionDate": "2025-01-08T00:00:00.000Z"
+                }
+            ]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let (service, storage, _temp) = setup_sync_service(&mock_server.uri()).await;
+
+    service.sync(false).await.unwrap();
+
+    let sends: HashMap<String, Send> = storage
+        .lock()
+        .await
+        .get(&StorageKey::UserSends.format(Some(TEST_USER_ID)))
+        .unwrap()
+        .unwrap();
+    assert_eq!(sends.len(), 1);
+    assert_eq!(sends[GOOD_SEND_ID].access_id, "access-1");
+    assert!(!sends.contains_key(BAD_SEND_ID));
+}
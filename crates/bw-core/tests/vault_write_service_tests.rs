@@ -17,7 +17,8 @@ use bw_core::services::api::{BitwardenApiClient, Environment};
 use bw_core::services::create_sdk_client;
 use bw_core::services::storage::{AccountManager, JsonFileStorage, Storage, StorageKey};
 use bw_core::services::vault::{
-    CipherService, ConfirmationService, ValidationService, VaultError, WriteService,
+    CipherService, ConfirmationService, MAX_ATTACHMENT_SIZE, ValidationService, VaultError,
+    WriteService,
 };
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -553,6 +554,69 @@ async fn test_create_identity_without_identity_data_fails() {
     ));
 }
 
+// ============================================================================
+// Attachment Tests
+// ============================================================================
+
+#[tokio::test]
+async fn test_create_attachment_rejects_oversized_file() {
+    let (
+        api_client,
+        storage,
+        cipher_service,
+        validation_service,
+        confirmation_service,
+        account_manager,
+    ) = setup_test_environment().await;
+
+    let write_service = WriteService::new(
+        api_client,
+        storage,
+        cipher_service,
+        validation_service,
+        confirmation_service,
+        account_manager,
+    );
+
+    // Zeroed allocation is lazy, so this doesn't touch 500 MB of memory
+    let contents = vec![0u8; MAX_ATTACHMENT_SIZE as usize + 1];
+    let result = write_service
+        .create_attachment("some-item-id", "large.bin", contents, "dummy")
+        .await;
+
+    assert!(matches!(
+        result.unwrap_err(),
+        VaultError::AttachmentTooLarge { .. }
+    ));
+}
+
+#[tokio::test]
+async fn test_delete_attachment_returns_error_when_item_not_found() {
+    let (
+        api_client,
+        storage,
+        cipher_service,
+        validation_service,
+        confirmation_service,
+        account_manager,
+    ) = setup_test_environment().await;
+
+    let write_service = WriteService::new(
+        api_client,
+        storage,
+        cipher_service,
+        validation_service,
+        confirmation_service,
+        account_manager,
+    );
+
+    let result = write_service
+        .delete_attachment("non-existent-id", "attachment-id")
+        .await;
+
+    assert!(matches!(result.unwrap_err(), VaultError::ItemNotFound));
+}
+
 // ============================================================================
 // Test Summary
 // ============================================================================